@@ -151,7 +151,7 @@ pub fn run() -> sc_cli::Result<()> {
 						return service::new_full::<
 							service::ulas_runtime::RuntimeApi,
 							service::UlasExecutor,
-						>(config, rpc_config)
+						>(config, rpc_config, service::rpc::extend_with_nothing)
 						.map_err(sc_cli::Error::Service);
 					}
 					#[cfg(not(feature = "with-ulas-runtime"))]
@@ -162,7 +162,7 @@ pub fn run() -> sc_cli::Result<()> {
 						return service::new_full::<
 							service::hyper_runtime::RuntimeApi,
 							service::HyperExecutor,
-						>(config, rpc_config)
+						>(config, rpc_config, service::rpc::extend_with_nothing)
 						.map_err(sc_cli::Error::Service);
 					}
 					#[cfg(not(feature = "with-hyper-runtime"))]
@@ -173,7 +173,7 @@ pub fn run() -> sc_cli::Result<()> {
 						return service::new_full::<
 							service::phoenix_runtime::RuntimeApi,
 							service::PhoenixExecutor,
-						>(config, rpc_config)
+						>(config, rpc_config, service::rpc::extend_with_dex)
 						.map_err(sc_cli::Error::Service);
 					}
 					#[cfg(not(feature = "with-phoenix-runtime"))]
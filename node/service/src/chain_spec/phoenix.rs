@@ -279,6 +279,7 @@ fn testnet_genesis(
 		tokens: TokensConfig { balances: vec![] },
 		bholdus_support_nft: BholdusSupportNFTConfig { tokens: vec![] },
 		bridge_native_transfer: Default::default(),
+		dex: Default::default(),
 		evm: EVMConfig {
 			accounts: {
 				// Prefund the "Gerald" account
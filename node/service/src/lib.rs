@@ -555,6 +555,7 @@ fn remote_keystore(_url: &String) -> Result<Arc<LocalKeystore>, &'static str> {
 pub fn new_full_base<RuntimeApi, Executor>(
 	mut config: Configuration,
 	rpc_config: rpc::RpcConfig,
+	extend_rpc: rpc::ExtendRpc<FullClient<RuntimeApi, Executor>>,
 ) -> Result<NewFullBase<RuntimeApi, Executor>, ServiceError>
 where
 	RuntimeApi:
@@ -753,6 +754,7 @@ where
 				};
 
 				let mut io = rpc::create_full(deps)?;
+				extend_rpc(&mut io, client.clone())?;
 
 				// Ethereum Tracing RPC
 				// if ethapi_cmd.contains(&EthApiCmd::Debug) ||
@@ -938,6 +940,7 @@ where
 pub fn new_full_base<RuntimeApi, Executor>(
 	mut config: Configuration,
 	rpc_config: rpc::RpcConfig,
+	extend_rpc: rpc::ExtendRpc<FullClient<RuntimeApi, Executor>>,
 ) -> Result<NewFullBase<RuntimeApi, Executor>, ServiceError>
 where
 	RuntimeApi:
@@ -1028,6 +1031,7 @@ where
 				};
 
 				let mut io = rpc::create_full(deps)?;
+				extend_rpc(&mut io, client.clone())?;
 
 				Ok(io)
 			};
@@ -1106,10 +1110,12 @@ where
 	Ok(NewFullBase { task_manager, client: client.clone(), network, transaction_pool })
 }
 
-/// Builds a new service for a full client.
+/// Builds a new service for a full client, `extend_rpc` merges the RPC extensions that only
+/// its runtime serves.
 pub fn new_full<RuntimeApi, Executor>(
 	config: Configuration,
 	rpc_config: rpc::RpcConfig,
+	extend_rpc: rpc::ExtendRpc<FullClient<RuntimeApi, Executor>>,
 ) -> Result<TaskManager, ServiceError>
 where
 	RuntimeApi:
@@ -1118,7 +1124,7 @@ where
 		RuntimeApiCollection<StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>>,
 	Executor: NativeExecutionDispatch + 'static,
 {
-	new_full_base::<RuntimeApi, Executor>(config, rpc_config)
+	new_full_base::<RuntimeApi, Executor>(config, rpc_config, extend_rpc)
 		.map(|NewFullBase { task_manager, .. }| task_manager)
 }
 
@@ -5,7 +5,9 @@
 
 #![allow(missing_docs)]
 
-use crate::{client::RuntimeApiCollection, Block, BlockNumber, Hash, TransactionConverters};
+use crate::{
+	client::RuntimeApiCollection, AccountId, Block, BlockNumber, Hash, TransactionConverters,
+};
 use fc_rpc::{
 	EthBlockDataCacheTask, OverrideHandle, RuntimeApiStorageOverride, SchemaV1Override,
 	SchemaV2Override, SchemaV3Override, StorageOverride,
@@ -44,6 +46,9 @@ use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 /// RPC result.
 pub type RpcResult = Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>;
+/// Merges the RPC extensions served by a single runtime only.
+pub type ExtendRpc<C> =
+	fn(&mut RpcExtension, Arc<C>) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
 /// Ethereum Tracing
 // pub mod tracing;
@@ -407,3 +412,26 @@ where
 
 	Ok(io)
 }
+
+/// Merges the DEX RPC extensions, for the runtimes that deploy the DEX pallet.
+pub fn extend_with_dex<C>(
+	io: &mut RpcExtension,
+	client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: bholdus_dex_rpc::DexRuntimeApi<Block, AccountId>,
+{
+	use bholdus_dex_rpc::{Dex, DexApiServer};
+
+	io.merge(Dex::new(client).into_rpc())?;
+	Ok(())
+}
+
+/// Merges no RPC extension, for the runtimes that serve the common ones only.
+pub fn extend_with_nothing<C>(
+	_io: &mut RpcExtension,
+	_client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+	Ok(())
+}
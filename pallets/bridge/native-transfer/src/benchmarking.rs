@@ -4,61 +4,151 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as BridgeNativeTransfer;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_system::RawOrigin;
+use sp_runtime::traits::StaticLookup;
 
+const SEED: u32 = 0;
 const UNIT: u128 = 10_u128.pow(18);
+/// Bridged token, clear of the tokens created at genesis
+const TOKEN_ID: TokenId = 1_000;
+const CHAIN: ChainId = 1;
 
 fn funded_account<T: Config>(caller: &T::AccountId, amount: u128) -> T::AccountId {
 	T::Currency::make_free_balance_be(caller, amount);
 	caller.clone()
 }
 
+fn fund_token<T: Config>(who: &T::AccountId, amount: Balance) {
+	assert!(T::MultiCurrency::deposit(TOKEN_ID, who, amount).is_ok());
+}
+
+fn foreign_asset() -> Bytes {
+	vec![
+		0x55, 0xd3, 0x98, 0x32, 0x6f, 0x99, 0x05, 0x9f, 0xF7, 0x75, 0x48, 0x52, 0x46, 0x99, 0x90,
+		0x27, 0xB3, 0x19, 0x79, 0x55,
+	]
+}
+
+/// Register `CHAIN` and bridge a new BHC20 token with it, with limits set. Token transfers are
+/// the heaviest, moving both the native fees and the tokens, and accruing the token volumes.
+fn bridged_token<T>() -> TokenSymbol
+where
+	T: Config + bholdus_tokens::Config<AssetId = TokenId, Balance = Balance>,
+{
+	let owner: T::AccountId = account("owner", 0, SEED);
+	assert!(bholdus_tokens::Pallet::<T>::force_create(
+		<T as bholdus_tokens::Config>::ForceOrigin::successful_origin(),
+		TOKEN_ID,
+		T::Lookup::unlookup(owner),
+		true,
+		1,
+	)
+	.is_ok());
+	assert!(Pallet::<T>::force_register_chain(RawOrigin::Root.into(), CHAIN).is_ok());
+	assert!(Pallet::<T>::force_register_asset(
+		RawOrigin::Root.into(),
+		CHAIN,
+		foreign_asset(),
+		TOKEN_ID,
+		AssetBridgeMode::LockUnlock,
+	)
+	.is_ok());
+	let asset = TokenSymbol::Token(TokenInfo { id: TOKEN_ID });
+	let limits = ChainLimits {
+		max_transfer: UNIT * 1_000_000,
+		window: 10u32.into(),
+		max_outbound_per_window: UNIT * 1_000_000,
+		max_inbound_per_window: UNIT * 1_000_000,
+		max_inbound_per_day: UNIT * 1_000_000,
+	};
+	assert!(Pallet::<T>::force_set_chain_limits(
+		RawOrigin::Root.into(),
+		CHAIN,
+		asset,
+		Some(limits)
+	)
+	.is_ok());
+	asset
+}
+
 benchmarks! {
+	where_clause {
+		where T: bholdus_tokens::Config<AssetId = TokenId, Balance = Balance>
+	}
+
 	initiate_transfer {
 		let s in 1 .. 1000;
 		let caller: T::AccountId = whitelisted_caller();
 		let to = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
 		let amount = UNIT.checked_mul(s.into()).unwrap();
-		Pallet::<T>::force_register_chain(RawOrigin::Root.into(), 1)?;
-		funded_account::<T>(&caller, amount.checked_mul(10).unwrap());
+		let asset = bridged_token::<T>();
+		Pallet::<T>::force_set_service_fee(RawOrigin::Root.into(), UNIT)?;
+		funded_account::<T>(&caller, UNIT.checked_mul(10).unwrap());
+		fund_token::<T>(&caller, amount.checked_mul(10).unwrap());
 
-	}: _(RawOrigin::Signed(caller), to.clone(), amount, 1)
+	}: _(RawOrigin::Signed(caller), to.clone(), amount, CHAIN, asset)
 
 	confirm_transfer {
-		let s in 1 .. 1000;
+		let r in 1 .. T::MaxRelayers::get();
 		let caller: T::AccountId = whitelisted_caller();
 		let to = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
-		let amount = UNIT.checked_mul(s.into()).unwrap();
-		Pallet::<T>::force_register_chain(RawOrigin::Root.into(), 1)?;
+		let amount = UNIT;
+		let asset = bridged_token::<T>();
 		Pallet::<T>::force_register_relayer(RawOrigin::Root.into(), caller.clone())?;
+		Pallet::<T>::force_set_service_fee(RawOrigin::Root.into(), UNIT)?;
 		funded_account::<T>(&caller, amount.checked_mul(10).unwrap());
 		funded_account::<T>(&Pallet::<T>::pallet_account_id(), amount.checked_mul(10).unwrap());
-		Pallet::<T>::initiate_transfer(RawOrigin::Signed(caller.clone()).into(),to.clone(),amount,1)?;
-
-	}: _(RawOrigin::Signed(caller), 0)
+		fund_token::<T>(&caller, amount.checked_mul(10).unwrap());
+		Pallet::<T>::initiate_transfer(RawOrigin::Signed(caller.clone()).into(), to.clone(), amount, CHAIN, asset)?;
+		let relayers: Vec<T::AccountId> = (1 .. r).map(|i| account("relayer", i, SEED)).collect();
+		for relayer in &relayers {
+			Pallet::<T>::force_register_relayer(RawOrigin::Root.into(), relayer.clone())?;
+		}
+		Pallet::<T>::force_set_relayer_threshold(RawOrigin::Root.into(), r)?;
+		// The caller casts the last of `r` votes, refunding the transfer
+		for relayer in relayers {
+			Pallet::<T>::confirm_transfer(RawOrigin::Signed(relayer).into(), 0, OutboundTransferConfirmStatus::Failed)?;
+		}
+
+	}: _(RawOrigin::Signed(caller), 0, OutboundTransferConfirmStatus::Failed)
+	verify {
+		assert_eq!(Pallet::<T>::next_confirm_outbound_transfer_id(), 1);
+	}
 
 	release_tokens {
-		let s in 1 .. 1000;
+		let r in 1 .. T::MaxRelayers::get();
 		let caller: T::AccountId = whitelisted_caller();
 		let from = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
-		let amount = UNIT.checked_mul(s.into()).unwrap();
-		Pallet::<T>::force_register_chain(RawOrigin::Root.into(), 1)?;
+		let amount = UNIT;
+		bridged_token::<T>();
 		Pallet::<T>::force_register_relayer(RawOrigin::Root.into(), caller.clone())?;
-		funded_account::<T>(&caller, amount.checked_mul(10).unwrap());
 		funded_account::<T>(&Pallet::<T>::pallet_account_id(), amount.checked_mul(10).unwrap());
-
-	}: _(RawOrigin::Signed(caller.clone()), 0, from.clone(), caller.clone(), amount)
+		fund_token::<T>(&Pallet::<T>::pallet_account_id(), amount.checked_mul(10).unwrap());
+		let relayers: Vec<T::AccountId> = (1 .. r).map(|i| account("relayer", i, SEED)).collect();
+		for relayer in &relayers {
+			Pallet::<T>::force_register_relayer(RawOrigin::Root.into(), relayer.clone())?;
+		}
+		Pallet::<T>::force_set_relayer_threshold(RawOrigin::Root.into(), r)?;
+		// The caller casts the last of `r` votes, releasing the tokens
+		for relayer in relayers {
+			Pallet::<T>::release_tokens(RawOrigin::Signed(relayer).into(), 0, CHAIN, from.clone(), caller.clone(), amount, Some(foreign_asset()))?;
+		}
+
+	}: _(RawOrigin::Signed(caller.clone()), 0, CHAIN, from.clone(), caller.clone(), amount, Some(foreign_asset()))
+	verify {
+		assert_eq!(Pallet::<T>::next_inbound_transfer_id(), 1);
+	}
 
 	force_register_relayer {
 		let caller: T::AccountId = whitelisted_caller();
 
-	}: _(RawOrigin::Root, caller)
+	}: _(RawOrigin::Root, caller, None)
 
 	force_unregister_relayer {
 		let caller: T::AccountId = whitelisted_caller();
 
-	}: _(RawOrigin::Root, caller)
+	}: _(RawOrigin::Root, caller, None)
 
 	force_register_chain {
 		let caller: T::AccountId = whitelisted_caller();
@@ -83,13 +173,58 @@ benchmarks! {
 	force_withdraw {
 		let caller: T::AccountId = whitelisted_caller();
 
-	}: _(RawOrigin::Root, caller)
+	}: _(RawOrigin::Root, caller, None)
 
 	force_freeze {
 	}: _(RawOrigin::Root)
 
 	force_unfreeze {}: _(RawOrigin::Root)
 
+	force_set_relayer_threshold {
+		Pallet::<T>::force_register_relayer(RawOrigin::Root.into(), account("relayer", 0, SEED))?;
+		Pallet::<T>::force_register_relayer(RawOrigin::Root.into(), account("relayer", 1, SEED))?;
+
+	}: _(RawOrigin::Root, 2)
+
+	force_set_chain_limits {
+		let limits = ChainLimits {
+			max_transfer: UNIT,
+			window: 10u32.into(),
+			max_outbound_per_window: UNIT,
+			max_inbound_per_window: UNIT,
+			max_inbound_per_day: UNIT,
+		};
+
+	}: _(RawOrigin::Root, 1, TokenSymbol::Native, Some(limits))
+
+	force_approve_release {
+		let caller: T::AccountId = whitelisted_caller();
+		let from = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
+		funded_account::<T>(&Pallet::<T>::pallet_account_id(), UNIT.checked_mul(10).unwrap());
+		PendingReleases::<T>::insert(0, PendingRelease { source_chain: 1, from, to: caller, amount: UNIT, asset: TokenSymbol::Native });
+
+	}: _(RawOrigin::Root, 0)
+
+	force_reject_release {
+		let caller: T::AccountId = whitelisted_caller();
+		let from = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
+		PendingReleases::<T>::insert(0, PendingRelease { source_chain: 1, from, to: caller, amount: UNIT, asset: TokenSymbol::Native });
+
+	}: _(RawOrigin::Root, 0)
+
+	force_register_asset {
+		let foreign_asset = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
+		Pallet::<T>::force_register_chain(RawOrigin::Root.into(), 1)?;
+
+	}: _(RawOrigin::Root, 1, foreign_asset, 1, AssetBridgeMode::LockUnlock)
+
+	force_unregister_asset {
+		let foreign_asset = vec![0x2e, 0x86, 0x88, 0x27, 0xCC, 0xb7, 0xB0, 0x15, 0x55, 0x2a, 0x98, 0x17, 0xca, 0x3E, 0x9E, 0xE3, 0xa0, 0x8A, 0xe5, 0x96];
+		Pallet::<T>::force_register_chain(RawOrigin::Root.into(), 1)?;
+		Pallet::<T>::force_register_asset(RawOrigin::Root.into(), 1, foreign_asset.clone(), 1, AssetBridgeMode::LockUnlock)?;
+
+	}: _(RawOrigin::Root, 1, foreign_asset)
+
 }
 
 impl_benchmark_test_suite!(
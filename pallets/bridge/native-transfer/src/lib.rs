@@ -1,7 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use bholdus_support::MultiCurrency;
 use codec::{Decode, Encode};
-use common_primitives::Balance;
+use common_primitives::{Balance, TokenId, TokenInfo, TokenSymbol};
 use frame_support::{
 	dispatch::DispatchResult,
 	pallet_prelude::*,
@@ -10,7 +11,10 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
-use sp_runtime::{traits::AccountIdConversion, ArithmeticError};
+use sp_runtime::{
+	traits::{AccountIdConversion, Hash, Saturating, Zero},
+	ArithmeticError, PerThing, Perbill,
+};
 use sp_std::prelude::*;
 
 pub use pallet::*;
@@ -34,13 +38,16 @@ type TransferId = u128;
 type Bytes = Vec<u8>;
 type ChainId = u16;
 
-#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct OutboundTransferInfo<AccountId, Balance, ChainId> {
 	from: AccountId,
 	to: Bytes,
 	amount: Balance,
 	target_chain: ChainId,
 	service_fee: Balance,
+	asset: TokenSymbol,
+	/// Mode the BHC20 token was sent in, `None` for the native currency
+	mode: Option<AssetBridgeMode>,
 }
 
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -59,6 +66,10 @@ pub enum InboundTransferResultStatus {
 	NotExist,
 	Ok,
 	Err(DispatchError),
+	/// Exceeded the limits of its chain, waiting for admin approval
+	Pending,
+	/// Rejected by admin after exceeding the limits of its chain
+	Rejected,
 }
 impl Default for InboundTransferResultStatus {
 	fn default() -> Self {
@@ -71,8 +82,60 @@ pub struct InboundTransferResult {
 	status: InboundTransferResultStatus,
 }
 
+/// Transfer limits of an asset bridged with a chain, denominated in that asset
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ChainLimits<Balance, BlockNumber> {
+	/// Maximum amount of a single transfer
+	pub max_transfer: Balance,
+	/// Number of blocks over which the window volumes decay
+	pub window: BlockNumber,
+	/// Maximum outbound volume, freed up linearly over a window
+	pub max_outbound_per_window: Balance,
+	/// Maximum inbound volume, freed up linearly over a window
+	pub max_inbound_per_window: Balance,
+	/// Maximum inbound volume, freed up linearly over a day
+	pub max_inbound_per_day: Balance,
+}
+
+/// Volume transferred from or to a chain as of `last_update`, decaying linearly over time
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+pub struct TransferVolume<Balance, BlockNumber> {
+	last_update: BlockNumber,
+	amount: Balance,
+}
+
+/// Inbound transfer exceeding the limits of its chain, waiting for admin approval
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PendingRelease<AccountId, Balance, ChainId> {
+	source_chain: ChainId,
+	from: Bytes,
+	to: AccountId,
+	amount: Balance,
+	asset: TokenSymbol,
+	/// Mode the BHC20 token is released in, `None` for the native currency
+	mode: Option<AssetBridgeMode>,
+}
+
+/// How a BHC20 token is moved across the bridge
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AssetBridgeMode {
+	/// Tokens are locked in the pallet account when sent and unlocked when received
+	LockUnlock,
+	/// Tokens are burnt when sent and minted when received
+	MintBurn,
+}
+
+/// BHC20 token bridged with a foreign asset of a chain
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BridgedAsset {
+	pub token_id: TokenId,
+	pub mode: AssetBridgeMode,
+}
+
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type ChainLimitsOf<T> = ChainLimits<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+type TransferVolumeOf<T> = TransferVolume<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -85,16 +148,32 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// Native Currency trait
 		type Currency: Currency<Self::AccountId, Balance = Balance>;
+		/// BHC20 tokens
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = TokenId, Balance = Balance>;
 		/// Admin Origin
 		type AdminOrigin: EnsureOrigin<Self::Origin>;
 		/// Minimum amount to transfer. This should match `ExistentialDeposit` of `pallet_balance`
 		#[pallet::constant]
 		type MinimumDeposit: Get<BalanceOf<Self>>;
+		/// Number of blocks per day, the period of inbound daily limits
+		#[pallet::constant]
+		type BlocksPerDay: Get<Self::BlockNumber>;
+		/// Whether the service fee is refunded along with the amount when an outbound transfer
+		/// fails on the target chain. Otherwise the relayer keeps the fee.
+		#[pallet::constant]
+		type RefundServiceFee: Get<bool>;
+		/// Number of transfers, starting from the oldest unprocessed one, that can be processed
+		/// in any order
+		#[pallet::constant]
+		type TransferWindow: Get<u32>;
+		/// Maximum number of registered relayers, bounding the votes read when releasing tokens
+		#[pallet::constant]
+		type MaxRelayers: Get<u32>;
 		/// Weight info
 		type WeightInfo: weights::WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
@@ -161,6 +240,10 @@ pub mod pallet {
 	pub(super) fn DefaultNextInboundTransferId() -> TransferId {
 		0
 	}
+	#[pallet::type_value]
+	pub(super) fn DefaultRelayerThreshold() -> u32 {
+		1
+	}
 
 	/// Next outbound transfer id for the next transfer initiated by user.
 	#[pallet::storage]
@@ -185,6 +268,18 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Final state of confirmed outbound transfers
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_transfer_status)]
+	pub(super) type OutboundTransferStatuses<T> =
+		StorageMap<_, Blake2_128Concat, TransferId, OutboundTransferConfirmStatus, OptionQuery>;
+
+	/// Results of processed inbound transfers
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_transfer_results)]
+	pub(super) type InboundTransferResults<T> =
+		StorageMap<_, Blake2_128Concat, TransferId, InboundTransferResult, ValueQuery>;
+
 	/// The service fee to charge users
 	#[pallet::storage]
 	#[pallet::getter(fn service_fee)]
@@ -208,6 +303,11 @@ pub mod pallet {
 	pub(super) type RegisteredRelayers<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
+	/// Number of registered relayers
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_count)]
+	pub(super) type RelayerCount<T> = StorageValue<_, u32, ValueQuery>;
+
 	/// Registered Chains
 	/// Only registered chains is supported for crosschain transfer
 	#[pallet::storage]
@@ -215,6 +315,136 @@ pub mod pallet {
 	pub(super) type RegisteredChains<T: Config> =
 		StorageMap<_, Blake2_128Concat, ChainId, bool, ValueQuery>;
 
+	/// Number of registered relayers that must vote for the same inbound transfer payload before
+	/// the tokens are released
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_threshold)]
+	pub(super) type RelayerThreshold<T> = StorageValue<_, u32, ValueQuery, DefaultRelayerThreshold>;
+
+	/// Relayers that voted for an inbound transfer, by transfer id and payload hash.
+	/// Cleared once the transfer is released
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_transfer_votes)]
+	pub(super) type InboundTransferVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TransferId,
+		Blake2_128Concat,
+		T::Hash,
+		Vec<T::AccountId>,
+		ValueQuery,
+	>;
+
+	/// Relayers that voted for the status of an outbound transfer, by transfer id and status.
+	/// Cleared once the transfer is confirmed
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_transfer_votes)]
+	pub(super) type OutboundTransferVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TransferId,
+		Blake2_128Concat,
+		OutboundTransferConfirmStatus,
+		Vec<T::AccountId>,
+		ValueQuery,
+	>;
+
+	/// Transfer limits of each asset bridged with each chain, no limits if not set
+	#[pallet::storage]
+	#[pallet::getter(fn chain_limits)]
+	pub(super) type ChainTransferLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChainId,
+		Blake2_128Concat,
+		TokenSymbol,
+		ChainLimitsOf<T>,
+		OptionQuery,
+	>;
+
+	/// Outbound volume of each asset to each chain, decaying over its window
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_volume)]
+	pub(super) type OutboundVolume<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChainId,
+		Blake2_128Concat,
+		TokenSymbol,
+		TransferVolumeOf<T>,
+		ValueQuery,
+	>;
+
+	/// Inbound volume of each asset from each chain, decaying over its window
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_volume)]
+	pub(super) type InboundVolume<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChainId,
+		Blake2_128Concat,
+		TokenSymbol,
+		TransferVolumeOf<T>,
+		ValueQuery,
+	>;
+
+	/// Inbound volume of each asset from each chain, decaying over a day
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_daily_volume)]
+	pub(super) type InboundDailyVolume<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChainId,
+		Blake2_128Concat,
+		TokenSymbol,
+		TransferVolumeOf<T>,
+		ValueQuery,
+	>;
+
+	/// Inbound transfers exceeding the limits of their chain, waiting for admin approval
+	#[pallet::storage]
+	#[pallet::getter(fn pending_releases)]
+	pub(super) type PendingReleases<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TransferId,
+		PendingRelease<T::AccountId, BalanceOf<T>, ChainId>,
+		OptionQuery,
+	>;
+
+	/// BHC20 tokens bridged with the foreign assets of each chain
+	#[pallet::storage]
+	#[pallet::getter(fn bridged_assets)]
+	pub(super) type BridgedAssets<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChainId,
+		Blake2_128Concat,
+		Bytes,
+		BridgedAsset,
+		OptionQuery,
+	>;
+
+	/// Foreign assets of each chain bridged with BHC20 tokens
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_assets)]
+	pub(super) type ForeignAssets<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChainId,
+		Blake2_128Concat,
+		TokenId,
+		Bytes,
+		OptionQuery,
+	>;
+
+	/// Number of outbound transfers not confirmed yet and of inbound transfers waiting for admin
+	/// approval, for each BHC20 token bridged with a chain
+	#[pallet::storage]
+	#[pallet::getter(fn unsettled_transfers)]
+	pub(super) type UnsettledTransfers<T> =
+		StorageDoubleMap<_, Blake2_128Concat, ChainId, Blake2_128Concat, TokenId, u32, ValueQuery>;
+
 	/// Indicating the bridge is frozen by admin
 	#[pallet::storage]
 	#[pallet::getter(fn is_frozen)]
@@ -224,10 +454,44 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// User initiated a crosschain transfer successfully. [outbound_transfer_id, from, to,
-		/// amount]
-		OutboundTransferInitiated(TransferId, T::AccountId, Bytes, BalanceOf<T>),
-		/// Inbound Token release succeeded. [inbound_transfer_id, from, to, amount]
-		InboundTokenReleased(TransferId, Bytes, T::AccountId, BalanceOf<T>),
+		/// amount, asset]
+		OutboundTransferInitiated(TransferId, T::AccountId, Bytes, BalanceOf<T>, TokenSymbol),
+		/// Inbound Token release succeeded. [inbound_transfer_id, from, to, amount, asset]
+		InboundTokenReleased(TransferId, Bytes, T::AccountId, BalanceOf<T>, TokenSymbol),
+		/// Relayer voted for an inbound transfer. [inbound_transfer_id, relayer, payload_hash]
+		InboundTransferVoted(TransferId, T::AccountId, T::Hash),
+		/// Relayer voted for an inbound transfer payload that differs from the payload other
+		/// relayers voted for. [inbound_transfer_id, relayer, payload_hash]
+		ConflictingInboundTransferVote(TransferId, T::AccountId, T::Hash),
+		/// Relayer threshold updated. [threshold]
+		RelayerThresholdSet(u32),
+		/// Transfer limits of an asset bridged with a chain updated, `None` if removed. [chain,
+		/// asset, limits]
+		ChainLimitsSet(ChainId, TokenSymbol, Option<ChainLimitsOf<T>>),
+		/// Inbound transfer exceeded the limits of its chain. The bridge is frozen and the
+		/// release waits for admin approval. [inbound_transfer_id, source_chain, amount]
+		InboundTransferLimitExceeded(TransferId, ChainId, BalanceOf<T>),
+		/// Admin rejected a pending release. [inbound_transfer_id]
+		PendingReleaseRejected(TransferId),
+		/// Relayer voted for the status of an outbound transfer. [outbound_transfer_id, relayer,
+		/// status]
+		OutboundTransferVoted(TransferId, T::AccountId, OutboundTransferConfirmStatus),
+		/// Relayer voted for a status of an outbound transfer that differs from the status other
+		/// relayers voted for. [outbound_transfer_id, relayer, status]
+		ConflictingOutboundTransferVote(TransferId, T::AccountId, OutboundTransferConfirmStatus),
+		/// Outbound transfer confirmed by the relayer casting the deciding vote.
+		/// [outbound_transfer_id, relayer, status]
+		OutboundTransferConfirmed(TransferId, T::AccountId, OutboundTransferConfirmStatus),
+		/// Failed outbound transfer refunded to the sender. [outbound_transfer_id, from, asset,
+		/// amount, service_fee]
+		OutboundTransferRefunded(TransferId, T::AccountId, TokenSymbol, BalanceOf<T>, BalanceOf<T>),
+		/// Releasing tokens of an inbound transfer failed. Relayers can vote for it again to
+		/// retry. [inbound_transfer_id, error]
+		InboundTransferFailed(TransferId, DispatchError),
+		/// BHC20 token bridged with a foreign asset. [chain, foreign_asset, token_id, mode]
+		AssetRegistered(ChainId, Bytes, TokenId, AssetBridgeMode),
+		/// BHC20 token no longer bridged with a foreign asset. [chain, foreign_asset, token_id]
+		AssetUnregistered(ChainId, Bytes, TokenId),
 	}
 
 	#[pallet::error]
@@ -236,6 +500,12 @@ pub mod pallet {
 		MustBeRegisteredRelayer,
 		/// Only registered chains are supported
 		MustBeRegisteredChain,
+		/// Transfer exceeds the limits of the chain
+		TransferLimitExceeded,
+		/// Chain limits window must be greater than 0
+		InvalidChainLimits,
+		/// Pending release not found
+		PendingReleaseNotFound,
 		/// Invalid Service Fee Rate
 		InvalidServiceFeeRate,
 		/// Outbound transfer is already confirmed or the transfer doesn't exists
@@ -250,6 +520,21 @@ pub mod pallet {
 		MinimumDepositRequired,
 		/// Bridge is freezed
 		Frozen,
+		/// Relayer already voted for this transfer
+		AlreadyVoted,
+		/// Relayer threshold must be greater than 0 and at most the number of registered relayers
+		InvalidRelayerThreshold,
+		/// Too many registered relayers
+		TooManyRelayers,
+		/// Transfer is too far ahead of the oldest unprocessed transfer
+		TransferOutsideWindow,
+		/// Asset is not bridged with the chain
+		AssetNotRegistered,
+		/// Foreign asset or token is already bridged with the chain
+		AssetAlreadyRegistered,
+		/// Asset has outbound transfers not confirmed yet or inbound transfers waiting for admin
+		/// approval
+		AssetHasUnsettledTransfers,
 	}
 
 	#[pallet::call]
@@ -259,6 +544,8 @@ pub mod pallet {
 		/// User will be charged with additional service fee.
 		/// This service fee is rewarded to the relayer if the execution on the target chain
 		/// succeeds.
+		/// `asset` is either the native currency or a BHC20 token bridged with `target_chain`.
+		/// The service fee is always charged in the native currency.
 		#[pallet::weight(T::WeightInfo::initiate_transfer(1))]
 		#[transactional]
 		pub fn initiate_transfer(
@@ -266,6 +553,7 @@ pub mod pallet {
 			to: Bytes,
 			amount: BalanceOf<T>,
 			target_chain: ChainId,
+			asset: TokenSymbol,
 		) -> DispatchResult {
 			ensure!(!Self::is_frozen(), Error::<T>::Frozen);
 
@@ -274,13 +562,33 @@ pub mod pallet {
 			// Only registered chains can receive the fund
 			ensure!(RegisteredChains::<T>::get(target_chain), Error::<T>::MustBeRegisteredChain);
 
-			ensure!(amount >= T::MinimumDeposit::get(), Error::<T>::MinimumDepositRequired);
+			let minimum_deposit = match asset {
+				TokenSymbol::Native => T::MinimumDeposit::get(),
+				TokenSymbol::Token(TokenInfo { id }) =>
+					T::MultiCurrency::minimum_balance(id).max(1),
+			};
+			ensure!(amount >= minimum_deposit, Error::<T>::MinimumDepositRequired);
+
+			if let Some(limits) = Self::chain_limits(target_chain, asset) {
+				ensure!(amount <= limits.max_transfer, Error::<T>::TransferLimitExceeded);
+				let volume = Self::accrue_volume(
+					Self::outbound_volume(target_chain, asset),
+					amount,
+					limits.window,
+					limits.max_outbound_per_window,
+				)
+				.ok_or(Error::<T>::TransferLimitExceeded)?;
+				OutboundVolume::<T>::insert(target_chain, asset, volume);
+			}
 
 			let fee = Self::service_fee()
 				.checked_add(Self::platform_fee())
 				.ok_or(ArithmeticError::Overflow)?;
 
-			let total_charge = fee.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			let total_charge = match asset {
+				TokenSymbol::Native => fee.checked_add(amount).ok_or(ArithmeticError::Overflow)?,
+				TokenSymbol::Token(_) => fee,
+			};
 
 			// Lock user tokens
 			T::Currency::transfer(
@@ -290,6 +598,13 @@ pub mod pallet {
 				ExistenceRequirement::KeepAlive,
 			)?;
 
+			let mode = match asset {
+				TokenSymbol::Native => None,
+				TokenSymbol::Token(TokenInfo { id }) =>
+					Some(Self::send_token(&who, target_chain, id, amount)?),
+			};
+			Self::add_unsettled(target_chain, asset);
+
 			let transfer_id = Self::next_outbound_transfer_id();
 
 			OutboundTransfers::<T>::insert(
@@ -300,6 +615,8 @@ pub mod pallet {
 					amount,
 					service_fee: fee,
 					target_chain,
+					asset,
+					mode,
 				},
 			);
 
@@ -313,16 +630,29 @@ pub mod pallet {
 				who.clone(),
 				to.clone(),
 				amount,
+				asset,
 			));
 
 			Ok(())
 		}
 
 		/// Outbound
-		/// Relayer will call this function to confirm the transfer and claim their rewards
-		#[pallet::weight(T::WeightInfo::confirm_transfer(0))]
+		/// Relayer will call this function to vote for the status of the transfer on the target
+		/// chain.
+		/// The transfer is confirmed once `RelayerThreshold` registered relayers voted for the
+		/// same status, and the relayer casting the deciding vote claims the rewards.
+		/// If the transfer failed on the target chain, the amount is refunded to the sender,
+		/// along with the service fee if `RefundServiceFee` is set.
+		#[pallet::weight(
+			T::WeightInfo::confirm_transfer(T::MaxRelayers::get())
+				.saturating_add(Pallet::<T>::window_scan_weight())
+		)]
 		#[transactional]
-		pub fn confirm_transfer(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResult {
+		pub fn confirm_transfer(
+			origin: OriginFor<T>,
+			transfer_id: TransferId,
+			status: OutboundTransferConfirmStatus,
+		) -> DispatchResult {
 			ensure!(!Self::is_frozen(), Error::<T>::Frozen);
 
 			let who = ensure_signed(origin)?;
@@ -330,48 +660,133 @@ pub mod pallet {
 			// Only registered relayers are allowed
 			ensure!(Self::registered_relayers(who.clone()), Error::<T>::MustBeRegisteredRelayer);
 
+			let next_confirm_outbound_transfer_id = Self::next_confirm_outbound_transfer_id();
+
 			// Ignore if all outbound transfers are confirmed
 			ensure!(
-				Self::next_confirm_outbound_transfer_id() < Self::next_outbound_transfer_id(),
+				next_confirm_outbound_transfer_id < Self::next_outbound_transfer_id(),
 				Error::<T>::AllOutboundTransfersConfirmed
 			);
 
-			// Ignore if this transfer is already confirmed or doesn't exist
+			// Ignore if this transfer is already confirmed
 			ensure!(
-				Self::next_confirm_outbound_transfer_id() == transfer_id,
+				transfer_id >= next_confirm_outbound_transfer_id &&
+					!OutboundTransferStatuses::<T>::contains_key(transfer_id),
 				Error::<T>::UnexpectedOutboundTransferConfirmation
 			);
 
+			Self::ensure_in_window(next_confirm_outbound_transfer_id, transfer_id)?;
+
 			let outbound_transfer_info = Self::outbound_transfers(transfer_id)
 				.ok_or(Error::<T>::OutboundTransferNotFound)?;
 
+			// Each relayer votes once per transfer, whatever the status
+			ensure!(
+				!OutboundTransferVotes::<T>::iter_prefix_values(transfer_id)
+					.any(|voters| voters.contains(&who)),
+				Error::<T>::AlreadyVoted
+			);
+
+			if OutboundTransferVotes::<T>::iter_key_prefix(transfer_id).any(|s| s != status) {
+				Self::deposit_event(Event::ConflictingOutboundTransferVote(
+					transfer_id,
+					who.clone(),
+					status.clone(),
+				));
+			}
+
+			let voters = OutboundTransferVotes::<T>::mutate(transfer_id, &status, |voters| {
+				voters.push(who.clone());
+				voters.clone()
+			});
+
+			Self::deposit_event(Event::OutboundTransferVoted(
+				transfer_id,
+				who.clone(),
+				status.clone(),
+			));
+
+			// Votes of relayers unregistered since they voted don't count
+			let votes = voters.iter().filter(|r| Self::registered_relayers(r)).count() as u32;
+			if votes < Self::relayer_threshold() {
+				return Ok(());
+			}
+
+			OutboundTransferVotes::<T>::remove_prefix(transfer_id, None);
+
+			let OutboundTransferInfo {
+				from, amount, service_fee, target_chain, asset, mode, ..
+			} = outbound_transfer_info;
+			let (relayer_reward, fee_refund) = match status {
+				OutboundTransferConfirmStatus::Successful => (service_fee, Zero::zero()),
+				OutboundTransferConfirmStatus::Failed if T::RefundServiceFee::get() =>
+					(Zero::zero(), service_fee),
+				OutboundTransferConfirmStatus::Failed => (service_fee, Zero::zero()),
+			};
+
 			T::Currency::transfer(
 				&Self::pallet_account_id(),
 				&who,
-				outbound_transfer_info.service_fee,
+				relayer_reward,
 				ExistenceRequirement::AllowDeath,
 			)?;
 
-			let next_confirm_outbound_transfer_id = Self::next_confirm_outbound_transfer_id()
-				.checked_add(1)
-				.ok_or(ArithmeticError::Overflow)?;
+			let refunded = status == OutboundTransferConfirmStatus::Failed;
+			if refunded {
+				Self::release_asset(&from, asset, mode, amount)?;
+				T::Currency::transfer(
+					&Self::pallet_account_id(),
+					&from,
+					fee_refund,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+
+			Self::remove_unsettled(target_chain, asset);
+			OutboundTransferStatuses::<T>::insert(transfer_id, status.clone());
+			NextConfirmOutboundTransferId::<T>::put(Self::first_unprocessed(
+				next_confirm_outbound_transfer_id,
+				|id| OutboundTransferStatuses::<T>::contains_key(id),
+			));
 
-			NextConfirmOutboundTransferId::<T>::put(next_confirm_outbound_transfer_id);
+			Self::deposit_event(Event::OutboundTransferConfirmed(transfer_id, who, status));
+			if refunded {
+				Self::deposit_event(Event::OutboundTransferRefunded(
+					transfer_id,
+					from,
+					asset,
+					amount,
+					fee_refund,
+				));
+			}
 
 			Ok(())
 		}
 
 		/// Inbound
-		/// Relayer call this to release tokens to user corresponding to transfer sent from other
-		/// chains.
-		#[pallet::weight(T::WeightInfo::release_tokens(0))]
+		/// Relayer call this to vote for the release of tokens to user corresponding to transfer
+		/// sent from other chains.
+		/// Tokens are released once `RelayerThreshold` registered relayers voted for the same
+		/// payload.
+		/// Transfers within `TransferWindow` of the oldest unprocessed one can be processed in any
+		/// order. Failed transfers can be voted for again to retry them.
+		/// If the transfer exceeds the limits of its asset bridged with `source_chain`, the bridge
+		/// is frozen and the release waits for admin approval.
+		/// `asset` is the foreign asset bridged with a BHC20 token, `None` for the native
+		/// currency.
+		#[pallet::weight(
+			T::WeightInfo::release_tokens(T::MaxRelayers::get())
+				.saturating_add(Pallet::<T>::window_scan_weight())
+		)]
 		#[transactional]
 		pub fn release_tokens(
 			origin: OriginFor<T>,
 			transfer_id: TransferId,
+			source_chain: ChainId,
 			from: Bytes,
 			to: T::AccountId,
 			amount: BalanceOf<T>,
+			asset: Option<Bytes>,
 		) -> DispatchResult {
 			ensure!(!Self::is_frozen(), Error::<T>::Frozen);
 
@@ -380,26 +795,126 @@ pub mod pallet {
 			// Only registered relayers are allowed
 			ensure!(Self::registered_relayers(who.clone()), Error::<T>::MustBeRegisteredRelayer);
 
-			// Ignore if this transfer is already executed or the transfer_id doesn't exists
+			// Only registered chains can send the fund
+			ensure!(RegisteredChains::<T>::get(source_chain), Error::<T>::MustBeRegisteredChain);
+
+			let (local_asset, mode) = match &asset {
+				None => (TokenSymbol::Native, None),
+				Some(foreign_asset) => {
+					let BridgedAsset { token_id, mode } =
+						Self::bridged_assets(source_chain, foreign_asset)
+							.ok_or(Error::<T>::AssetNotRegistered)?;
+					(TokenSymbol::Token(TokenInfo { id: token_id }), Some(mode))
+				},
+			};
+
+			match Self::inbound_transfer_results(transfer_id).status {
+				InboundTransferResultStatus::NotExist => {
+					// Ignore if this transfer is already executed
+					ensure!(
+						transfer_id >= Self::next_inbound_transfer_id(),
+						Error::<T>::UnexpectedInboundTransfer
+					);
+					Self::ensure_in_window(Self::next_inbound_transfer_id(), transfer_id)?;
+				},
+				InboundTransferResultStatus::Err(_) => {},
+				_ => return Err(Error::<T>::UnexpectedInboundTransfer.into()),
+			}
+
+			// Each relayer votes once per transfer, whatever the payload
 			ensure!(
-				Self::next_inbound_transfer_id() == transfer_id,
-				Error::<T>::UnexpectedInboundTransfer
+				!InboundTransferVotes::<T>::iter_prefix_values(transfer_id)
+					.any(|voters| voters.contains(&who)),
+				Error::<T>::AlreadyVoted
 			);
 
-			T::Currency::transfer(
-				&Self::pallet_account_id(),
-				&to,
-				amount,
-				ExistenceRequirement::AllowDeath,
-			)?;
+			let payload_hash =
+				T::Hashing::hash_of(&(transfer_id, source_chain, &from, &to, amount, &asset));
+
+			if InboundTransferVotes::<T>::iter_key_prefix(transfer_id).any(|h| h != payload_hash) {
+				Self::deposit_event(Event::ConflictingInboundTransferVote(
+					transfer_id,
+					who.clone(),
+					payload_hash,
+				));
+			}
+
+			let voters = InboundTransferVotes::<T>::mutate(transfer_id, payload_hash, |voters| {
+				voters.push(who.clone());
+				voters.clone()
+			});
+
+			Self::deposit_event(Event::InboundTransferVoted(transfer_id, who, payload_hash));
+
+			// Votes of relayers unregistered since they voted don't count
+			let votes = voters.iter().filter(|r| Self::registered_relayers(r)).count() as u32;
+			if votes < Self::relayer_threshold() {
+				return Ok(());
+			}
+
+			InboundTransferVotes::<T>::remove_prefix(transfer_id, None);
+
+			let volumes = match Self::accrue_inbound_volume(source_chain, local_asset, amount) {
+				Ok(volumes) => volumes,
+				Err(_) => {
+					Frozen::<T>::put(true);
+					PendingReleases::<T>::insert(
+						transfer_id,
+						PendingRelease { source_chain, from, to, amount, asset: local_asset, mode },
+					);
+					Self::add_unsettled(source_chain, local_asset);
+					Self::record_inbound_result(transfer_id, InboundTransferResultStatus::Pending);
+					Self::deposit_event(Event::InboundTransferLimitExceeded(
+						transfer_id,
+						source_chain,
+						amount,
+					));
+					return Ok(());
+				},
+			};
+
+			let status = match Self::release_asset(&to, local_asset, mode, amount) {
+				Ok(()) => {
+					// Failed releases are retried, so only released amounts count
+					if let Some((volume, daily_volume)) = volumes {
+						InboundVolume::<T>::insert(source_chain, local_asset, volume);
+						InboundDailyVolume::<T>::insert(source_chain, local_asset, daily_volume);
+					}
+					Self::deposit_event(Event::InboundTokenReleased(
+						transfer_id,
+						from,
+						to,
+						amount,
+						local_asset,
+					));
+					InboundTransferResultStatus::Ok
+				},
+				Err(error) => {
+					let error = error.stripped();
+					Self::deposit_event(Event::InboundTransferFailed(transfer_id, error));
+					InboundTransferResultStatus::Err(error)
+				},
+			};
+			Self::record_inbound_result(transfer_id, status);
 
-			let next_inbound_transfer_id = Self::next_inbound_transfer_id()
-				.checked_add(1)
-				.ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		}
+
+		/// Set the number of registered relayers that must vote for the same inbound transfer
+		/// payload before the tokens are released, at most the number of registered relayers
+		/// Only `AdminOrigin` can access this operation
+		#[pallet::weight(T::WeightInfo::force_set_relayer_threshold())]
+		pub fn force_set_relayer_threshold(origin: OriginFor<T>, threshold: u32) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			NextInboundTransferId::<T>::put(next_inbound_transfer_id);
+			ensure!(
+				threshold > 0 && threshold <= Self::relayer_count(),
+				Error::<T>::InvalidRelayerThreshold
+			);
 
-			Self::deposit_event(Event::InboundTokenReleased(transfer_id, from, to, amount));
+			RelayerThreshold::<T>::put(threshold);
+
+			Self::deposit_event(Event::RelayerThresholdSet(threshold));
 
 			Ok(())
 		}
@@ -414,12 +929,21 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
+			if !Self::registered_relayers(&relayer) {
+				RelayerCount::<T>::try_mutate(|count| -> DispatchResult {
+					ensure!(*count < T::MaxRelayers::get(), Error::<T>::TooManyRelayers);
+					*count += 1;
+					Ok(())
+				})?;
+			}
 			RegisteredRelayers::<T>::insert(relayer, true);
 
 			Ok(())
 		}
 
 		/// Unregister relayer account
+		/// The relayer threshold is left unchanged, so that a compromised relayer can always be
+		/// removed.
 		/// Only `AdminOrigin` can access this operation
 		#[pallet::weight(T::WeightInfo::force_unregister_relayer())]
 		pub fn force_unregister_relayer(
@@ -428,6 +952,9 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
+			if Self::registered_relayers(&relayer) {
+				RelayerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			}
 			RegisteredRelayers::<T>::insert(relayer, false);
 
 			Ok(())
@@ -485,20 +1012,159 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Withdraw tokens locked in this pallet to some account
+		/// Withdraw tokens locked in this pallet to some account, the native currency or the
+		/// BHC20 token `token_id`
 		/// This operation is mainly used for any migration in the future
 		/// Only `AdminOrigin` can access this operation
 		#[pallet::weight(T::WeightInfo::force_withdraw())]
-		pub fn force_withdraw(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+		pub fn force_withdraw(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			token_id: Option<TokenId>,
+		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin.clone())?;
 
-			let locked_tokens = T::Currency::total_balance(&Self::pallet_account_id());
-			T::Currency::transfer(
-				&Self::pallet_account_id(),
-				&to,
-				locked_tokens,
-				ExistenceRequirement::AllowDeath,
-			)?;
+			match token_id {
+				None => {
+					let locked_tokens = T::Currency::total_balance(&Self::pallet_account_id());
+					T::Currency::transfer(
+						&Self::pallet_account_id(),
+						&to,
+						locked_tokens,
+						ExistenceRequirement::AllowDeath,
+					)?;
+				},
+				Some(id) => {
+					let locked_tokens =
+						T::MultiCurrency::free_balance(id, &Self::pallet_account_id());
+					T::MultiCurrency::transfer(id, &Self::pallet_account_id(), &to, locked_tokens)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Set transfer limits of `asset` bridged with a chain, `None` to remove them.
+		/// Limits are denominated in `asset`, either the native currency or a BHC20 token.
+		/// Only `AdminOrigin` can access this operation
+		#[pallet::weight(T::WeightInfo::force_set_chain_limits())]
+		pub fn force_set_chain_limits(
+			origin: OriginFor<T>,
+			chain: ChainId,
+			asset: TokenSymbol,
+			limits: Option<ChainLimitsOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			if let Some(limits) = &limits {
+				ensure!(!limits.window.is_zero(), Error::<T>::InvalidChainLimits);
+			}
+
+			ChainTransferLimits::<T>::set(chain, asset, limits.clone());
+
+			Self::deposit_event(Event::ChainLimitsSet(chain, asset, limits));
+
+			Ok(())
+		}
+
+		/// Release tokens of an inbound transfer that exceeded the limits of its chain
+		/// Only `AdminOrigin` can access this operation
+		#[pallet::weight(T::WeightInfo::force_approve_release())]
+		#[transactional]
+		pub fn force_approve_release(
+			origin: OriginFor<T>,
+			transfer_id: TransferId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let PendingRelease { source_chain, from, to, amount, asset, mode } =
+				PendingReleases::<T>::take(transfer_id)
+					.ok_or(Error::<T>::PendingReleaseNotFound)?;
+
+			Self::release_asset(&to, asset, mode, amount)?;
+			Self::remove_unsettled(source_chain, asset);
+
+			InboundTransferResults::<T>::insert(
+				transfer_id,
+				InboundTransferResult { status: InboundTransferResultStatus::Ok },
+			);
+
+			Self::deposit_event(Event::InboundTokenReleased(transfer_id, from, to, amount, asset));
+
+			Ok(())
+		}
+
+		/// Discard an inbound transfer that exceeded the limits of its chain
+		/// Only `AdminOrigin` can access this operation
+		#[pallet::weight(T::WeightInfo::force_reject_release())]
+		pub fn force_reject_release(
+			origin: OriginFor<T>,
+			transfer_id: TransferId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let PendingRelease { source_chain, asset, .. } =
+				PendingReleases::<T>::take(transfer_id)
+					.ok_or(Error::<T>::PendingReleaseNotFound)?;
+			Self::remove_unsettled(source_chain, asset);
+
+			InboundTransferResults::<T>::insert(
+				transfer_id,
+				InboundTransferResult { status: InboundTransferResultStatus::Rejected },
+			);
+
+			Self::deposit_event(Event::PendingReleaseRejected(transfer_id));
+
+			Ok(())
+		}
+
+		/// Bridge a BHC20 token with a foreign asset of a registered chain
+		/// Only `AdminOrigin` can access this operation
+		#[pallet::weight(T::WeightInfo::force_register_asset())]
+		pub fn force_register_asset(
+			origin: OriginFor<T>,
+			chain: ChainId,
+			foreign_asset: Bytes,
+			token_id: TokenId,
+			mode: AssetBridgeMode,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(RegisteredChains::<T>::get(chain), Error::<T>::MustBeRegisteredChain);
+			ensure!(
+				!BridgedAssets::<T>::contains_key(chain, &foreign_asset) &&
+					!ForeignAssets::<T>::contains_key(chain, token_id),
+				Error::<T>::AssetAlreadyRegistered
+			);
+
+			BridgedAssets::<T>::insert(chain, &foreign_asset, BridgedAsset { token_id, mode });
+			ForeignAssets::<T>::insert(chain, token_id, &foreign_asset);
+
+			Self::deposit_event(Event::AssetRegistered(chain, foreign_asset, token_id, mode));
+
+			Ok(())
+		}
+
+		/// Stop bridging a BHC20 token with a foreign asset, once all its transfers are settled
+		/// Only `AdminOrigin` can access this operation
+		#[pallet::weight(T::WeightInfo::force_unregister_asset())]
+		pub fn force_unregister_asset(
+			origin: OriginFor<T>,
+			chain: ChainId,
+			foreign_asset: Bytes,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let BridgedAsset { token_id, .. } = Self::bridged_assets(chain, &foreign_asset)
+				.ok_or(Error::<T>::AssetNotRegistered)?;
+			ensure!(
+				Self::unsettled_transfers(chain, token_id) == 0,
+				Error::<T>::AssetHasUnsettledTransfers
+			);
+			BridgedAssets::<T>::remove(chain, &foreign_asset);
+			ForeignAssets::<T>::remove(chain, token_id);
+
+			Self::deposit_event(Event::AssetUnregistered(chain, foreign_asset, token_id));
 
 			Ok(())
 		}
@@ -523,3 +1189,158 @@ pub mod pallet {
 		}
 	}
 }
+
+impl<T: Config> Pallet<T> {
+	/// Ensure `transfer_id` is within `TransferWindow` of the oldest unprocessed transfer
+	fn ensure_in_window(first_unprocessed: TransferId, transfer_id: TransferId) -> DispatchResult {
+		ensure!(
+			transfer_id < first_unprocessed.saturating_add(T::TransferWindow::get().into()),
+			Error::<T>::TransferOutsideWindow
+		);
+		Ok(())
+	}
+
+	/// First transfer from `transfer_id` that is not processed yet.
+	/// Reads at most `TransferWindow` transfers as transfers are only processed within the
+	/// window.
+	fn first_unprocessed(
+		mut transfer_id: TransferId,
+		is_processed: impl Fn(TransferId) -> bool,
+	) -> TransferId {
+		for _ in 0..T::TransferWindow::get() {
+			if !is_processed(transfer_id) {
+				break;
+			}
+			transfer_id = transfer_id.saturating_add(1);
+		}
+		transfer_id
+	}
+
+	/// Weight of reading a whole `TransferWindow` to find the first unprocessed transfer
+	fn window_scan_weight() -> Weight {
+		T::DbWeight::get().reads(T::TransferWindow::get().into())
+	}
+
+	/// Mode `token_id` is bridged with `chain` in
+	fn bridge_mode(chain: ChainId, token_id: TokenId) -> Result<AssetBridgeMode, DispatchError> {
+		Self::foreign_assets(chain, token_id)
+			.and_then(|foreign_asset| Self::bridged_assets(chain, foreign_asset))
+			.map(|asset| asset.mode)
+			.ok_or_else(|| Error::<T>::AssetNotRegistered.into())
+	}
+
+	/// Lock or burn `amount` of BHC20 token `token_id` sent by `who` to `chain`, returning the
+	/// mode it was sent in
+	fn send_token(
+		who: &T::AccountId,
+		chain: ChainId,
+		token_id: TokenId,
+		amount: BalanceOf<T>,
+	) -> Result<AssetBridgeMode, DispatchError> {
+		let mode = Self::bridge_mode(chain, token_id)?;
+		match mode {
+			AssetBridgeMode::LockUnlock =>
+				T::MultiCurrency::transfer(token_id, who, &Self::pallet_account_id(), amount)?,
+			AssetBridgeMode::MintBurn => T::MultiCurrency::withdraw(token_id, who, amount)?,
+		}
+		Ok(mode)
+	}
+
+	/// Release `amount` of `asset` to `to`, unlocking or minting BHC20 tokens depending on the
+	/// `mode` they were bridged in
+	fn release_asset(
+		to: &T::AccountId,
+		asset: TokenSymbol,
+		mode: Option<AssetBridgeMode>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match (asset, mode) {
+			(TokenSymbol::Native, _) => T::Currency::transfer(
+				&Self::pallet_account_id(),
+				to,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			),
+			(TokenSymbol::Token(TokenInfo { id }), Some(AssetBridgeMode::LockUnlock)) =>
+				T::MultiCurrency::transfer(id, &Self::pallet_account_id(), to, amount),
+			(TokenSymbol::Token(TokenInfo { id }), Some(AssetBridgeMode::MintBurn)) =>
+				T::MultiCurrency::deposit(id, to, amount),
+			(TokenSymbol::Token(_), None) => Err(Error::<T>::AssetNotRegistered.into()),
+		}
+	}
+
+	/// Count a transfer of `asset` with `chain` that is not settled yet
+	fn add_unsettled(chain: ChainId, asset: TokenSymbol) {
+		if let TokenSymbol::Token(TokenInfo { id }) = asset {
+			UnsettledTransfers::<T>::mutate(chain, id, |count| *count = count.saturating_add(1));
+		}
+	}
+
+	/// Stop counting a settled transfer of `asset` with `chain`
+	fn remove_unsettled(chain: ChainId, asset: TokenSymbol) {
+		if let TokenSymbol::Token(TokenInfo { id }) = asset {
+			UnsettledTransfers::<T>::mutate_exists(chain, id, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0)
+			});
+		}
+	}
+
+	/// Record the result of an inbound transfer and move past the processed transfers
+	fn record_inbound_result(transfer_id: TransferId, status: InboundTransferResultStatus) {
+		InboundTransferResults::<T>::insert(transfer_id, InboundTransferResult { status });
+		NextInboundTransferId::<T>::put(Self::first_unprocessed(
+			Self::next_inbound_transfer_id(),
+			|id| InboundTransferResults::<T>::contains_key(id),
+		));
+	}
+
+	/// Add `amount` to `volume`, which decays by `max` every `period` blocks rather than being
+	/// reset at fixed period boundaries. Bursts are capped at `max` and the sustained volume at
+	/// `max` per period. Returns `None` if the volume would exceed `max`.
+	fn accrue_volume(
+		mut volume: TransferVolumeOf<T>,
+		amount: BalanceOf<T>,
+		period: T::BlockNumber,
+		max: BalanceOf<T>,
+	) -> Option<TransferVolumeOf<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let elapsed = now.saturating_sub(volume.last_update);
+		// A whole period or more releases the whole volume
+		let released = Perbill::from_rational(elapsed, period).mul_floor(max);
+		volume.amount = volume.amount.saturating_sub(released);
+		volume.last_update = now;
+		volume.amount = volume.amount.checked_add(amount).filter(|amount| *amount <= max)?;
+		Some(volume)
+	}
+
+	/// Window and daily volumes of `asset` from `chain` with an inbound transfer of `amount`,
+	/// `None` if `asset` has no limits. Nothing is recorded, the volumes are stored once the
+	/// tokens are released. Fails if the transfer exceeds the limits of `asset`.
+	fn accrue_inbound_volume(
+		chain: ChainId,
+		asset: TokenSymbol,
+		amount: BalanceOf<T>,
+	) -> Result<Option<(TransferVolumeOf<T>, TransferVolumeOf<T>)>, DispatchError> {
+		let limits = match Self::chain_limits(chain, asset) {
+			Some(limits) => limits,
+			None => return Ok(None),
+		};
+		ensure!(amount <= limits.max_transfer, Error::<T>::TransferLimitExceeded);
+		let volume = Self::accrue_volume(
+			Self::inbound_volume(chain, asset),
+			amount,
+			limits.window,
+			limits.max_inbound_per_window,
+		);
+		let daily_volume = Self::accrue_volume(
+			Self::inbound_daily_volume(chain, asset),
+			amount,
+			T::BlocksPerDay::get(),
+			limits.max_inbound_per_day,
+		);
+		match (volume, daily_volume) {
+			(Some(volume), Some(daily_volume)) => Ok(Some((volume, daily_volume))),
+			_ => Err(Error::<T>::TransferLimitExceeded.into()),
+		}
+	}
+}
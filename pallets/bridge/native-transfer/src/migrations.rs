@@ -12,6 +12,22 @@ pub fn migrate<T: crate::Config>() -> Weight {
 		weight = weight.saturating_add(v2::migrate_to_v2::<T>());
 		StorageVersion::new(2).put::<Pallet<T>>();
 	}
+	if storage_version < 3 {
+		weight = weight.saturating_add(v3::migrate_to_v3::<T>());
+		StorageVersion::new(3).put::<Pallet<T>>();
+	}
+	if storage_version < 4 {
+		weight = weight.saturating_add(v4::migrate_to_v4::<T>());
+		StorageVersion::new(4).put::<Pallet<T>>();
+	}
+	if storage_version < 5 {
+		weight = weight.saturating_add(v5::migrate_to_v5::<T>());
+		StorageVersion::new(5).put::<Pallet<T>>();
+	}
+	if storage_version < 6 {
+		weight = weight.saturating_add(v6::migrate_to_v6::<T>());
+		StorageVersion::new(6).put::<Pallet<T>>();
+	}
 	weight
 }
 
@@ -24,3 +40,183 @@ pub mod v2 {
 		T::DbWeight::get().writes(2)
 	}
 }
+
+pub mod v3 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldOutboundTransferInfo<AccountId, Balance, ChainId> {
+		from: AccountId,
+		to: Bytes,
+		amount: Balance,
+		target_chain: ChainId,
+		service_fee: Balance,
+	}
+
+	/// Outbound transfers made before BHC20 tokens could be bridged are native transfers
+	pub fn migrate_to_v3<T: crate::Config>() -> Weight {
+		let mut translated: Weight = 0;
+		OutboundTransfers::<T>::translate::<
+			OldOutboundTransferInfo<T::AccountId, BalanceOf<T>, ChainId>,
+			_,
+		>(|_, old| {
+			translated = translated.saturating_add(1);
+			Some(OutboundTransferInfo {
+				from: old.from,
+				to: old.to,
+				amount: old.amount,
+				target_chain: old.target_chain,
+				service_fee: old.service_fee,
+				asset: TokenSymbol::Native,
+			})
+		});
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// Count the registered relayers and require a majority of them to release inbound
+	/// transfers, rather than the default threshold of a single relayer
+	pub fn migrate_to_v4<T: crate::Config>() -> Weight {
+		let mut reads: Weight = 0;
+		let count = RegisteredRelayers::<T>::iter_values()
+			.filter(|registered| {
+				reads = reads.saturating_add(1);
+				*registered
+			})
+			.count() as u32;
+		RelayerCount::<T>::put(count);
+		if !RelayerThreshold::<T>::exists() {
+			RelayerThreshold::<T>::put(count / 2 + 1);
+		}
+		T::DbWeight::get().reads_writes(reads.saturating_add(1), 2)
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// Take the entries of a storage keyed by chain only
+	fn drain_chain_map<T: crate::Config, V: Decode>(storage_name: &[u8]) -> Vec<(ChainId, V)> {
+		migration::storage_key_iter::<ChainId, V, Blake2_128Concat>(
+			Pallet::<T>::name().as_bytes(),
+			storage_name,
+		)
+		.drain()
+		.collect()
+	}
+
+	/// Limits and volumes were kept per chain and only applied to the native currency. They
+	/// become the limits and volumes of the native currency of each chain.
+	pub fn migrate_to_v5<T: crate::Config>() -> Weight {
+		let mut migrated: Weight = 0;
+
+		for (chain, limits) in drain_chain_map::<T, ChainLimitsOf<T>>(b"ChainTransferLimits") {
+			ChainTransferLimits::<T>::insert(chain, TokenSymbol::Native, limits);
+			migrated = migrated.saturating_add(1);
+		}
+		for (chain, volume) in drain_chain_map::<T, TransferVolumeOf<T>>(b"OutboundVolume") {
+			OutboundVolume::<T>::insert(chain, TokenSymbol::Native, volume);
+			migrated = migrated.saturating_add(1);
+		}
+		for (chain, volume) in drain_chain_map::<T, TransferVolumeOf<T>>(b"InboundVolume") {
+			InboundVolume::<T>::insert(chain, TokenSymbol::Native, volume);
+			migrated = migrated.saturating_add(1);
+		}
+		for (chain, volume) in drain_chain_map::<T, TransferVolumeOf<T>>(b"InboundDailyVolume") {
+			InboundDailyVolume::<T>::insert(chain, TokenSymbol::Native, volume);
+			migrated = migrated.saturating_add(1);
+		}
+
+		// Each entry is removed from its old key and inserted under its new one
+		T::DbWeight::get().reads_writes(migrated, migrated.saturating_mul(2))
+	}
+}
+
+pub mod v6 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldOutboundTransferInfo<AccountId, Balance, ChainId> {
+		from: AccountId,
+		to: Bytes,
+		amount: Balance,
+		target_chain: ChainId,
+		service_fee: Balance,
+		asset: TokenSymbol,
+	}
+
+	#[derive(Decode)]
+	struct OldPendingRelease<AccountId, Balance, ChainId> {
+		source_chain: ChainId,
+		from: Bytes,
+		to: AccountId,
+		amount: Balance,
+		asset: TokenSymbol,
+	}
+
+	/// Mode `asset` is currently bridged with `chain` in, `None` for the native currency or
+	/// unregistered tokens
+	fn current_mode<T: crate::Config>(
+		chain: ChainId,
+		asset: TokenSymbol,
+	) -> Option<AssetBridgeMode> {
+		match asset {
+			TokenSymbol::Native => None,
+			TokenSymbol::Token(TokenInfo { id }) => Pallet::<T>::bridge_mode(chain, id).ok(),
+		}
+	}
+
+	/// Outbound transfers and pending releases keep the mode their BHC20 token was bridged in,
+	/// taken from the current registration, and the unsettled ones are counted
+	pub fn migrate_to_v6<T: crate::Config>() -> Weight {
+		let first_unconfirmed = NextConfirmOutboundTransferId::<T>::get();
+		let mut translated: Weight = 0;
+		let mut unsettled: Weight = 0;
+
+		OutboundTransfers::<T>::translate::<
+			OldOutboundTransferInfo<T::AccountId, BalanceOf<T>, ChainId>,
+			_,
+		>(|transfer_id, old| {
+			translated = translated.saturating_add(1);
+			if transfer_id >= first_unconfirmed &&
+				!OutboundTransferStatuses::<T>::contains_key(transfer_id)
+			{
+				Pallet::<T>::add_unsettled(old.target_chain, old.asset);
+				unsettled = unsettled.saturating_add(1);
+			}
+			Some(OutboundTransferInfo {
+				from: old.from,
+				to: old.to,
+				amount: old.amount,
+				target_chain: old.target_chain,
+				service_fee: old.service_fee,
+				asset: old.asset,
+				mode: current_mode::<T>(old.target_chain, old.asset),
+			})
+		});
+		PendingReleases::<T>::translate::<OldPendingRelease<T::AccountId, BalanceOf<T>, ChainId>, _>(
+			|_, old| {
+				translated = translated.saturating_add(1);
+				Pallet::<T>::add_unsettled(old.source_chain, old.asset);
+				unsettled = unsettled.saturating_add(1);
+				Some(PendingRelease {
+					source_chain: old.source_chain,
+					from: old.from,
+					to: old.to,
+					amount: old.amount,
+					asset: old.asset,
+					mode: current_mode::<T>(old.source_chain, old.asset),
+				})
+			},
+		);
+
+		// Each entry reads its status and the registration of its token
+		T::DbWeight::get().reads_writes(
+			translated.saturating_mul(4).saturating_add(unsettled).saturating_add(1),
+			translated.saturating_add(unsettled),
+		)
+	}
+}
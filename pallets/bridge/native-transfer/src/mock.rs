@@ -1,4 +1,6 @@
 use crate::{self as bholdus_bridge_native_transfer, pallet};
+use bholdus_support::parameter_type_with_key;
+use common_primitives::TokenId;
 use frame_support::{
 	parameter_types,
 	traits::{ExistenceRequirement, GenesisBuild},
@@ -7,7 +9,7 @@ use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, IdentityLookup, Zero},
 };
 use system::EnsureRoot;
 
@@ -26,6 +28,8 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		BridgeNativeTransfer: bholdus_bridge_native_transfer::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>, Config<T>},
+		BholdusTokens: bholdus_tokens::{Pallet, Call, Storage, Event<T>},
+		Currencies: bholdus_currencies::{Pallet, Call, Event<T>},
 	}
 );
 
@@ -62,6 +66,10 @@ impl system::Config for Runtime {
 
 parameter_types! {
 	pub const ExistentialDeposit: u64 = 10;
+	pub const BlocksPerDay: u64 = 100;
+	pub static RefundServiceFee: bool = true;
+	pub const TransferWindow: u32 = 3;
+	pub const MaxRelayers: u32 = 3;
 }
 impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
@@ -75,11 +83,69 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_asset_id: TokenId| -> Balance {
+		Zero::zero()
+	};
+}
+parameter_types! {
+	pub const BasicDeposit: u128 = 10;
+	pub const FieldDeposit: u128 = 10;
+	pub const MaxAdditionalFields: u32 = 2;
+	pub const MaxRegistrars: u32 = 20;
+	pub const TokenDeposit: u128 = 0;
+	pub const ApprovalDeposit: u128 = 0;
+	pub const StringLimit: u32 = 50;
+	pub const MaxDecimals: u8 = 18;
+	pub const MetadataDepositBase: u128 = 1;
+	pub const MetadataDepositPerByte: u128 = 1;
+	pub const MaxLocks: u32 = 50;
+}
+impl bholdus_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = i128;
+	type AssetId = TokenId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = TokenDeposit;
+	type BasicDeposit = BasicDeposit;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type MaxDecimals = MaxDecimals;
+	type MaxAdditionalFields = MaxAdditionalFields;
+	type MaxRegistrars = MaxRegistrars;
+	type FieldDeposit = FieldDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = MaxLocks;
+}
+
+parameter_types! {
+	pub const MaxTransferAllCurrencies: u32 = 10;
+}
+
+impl bholdus_currencies::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = BholdusTokens;
+	type MaxTransferAllCurrencies = MaxTransferAllCurrencies;
+	type WeightInfo = ();
+}
+
 impl bholdus_bridge_native_transfer::Config for Runtime {
 	type Event = Event;
 	type AdminOrigin = EnsureRoot<Self::AccountId>;
 	type Currency = Balances;
+	type MultiCurrency = Currencies;
 	type MinimumDeposit = ExistentialDeposit;
+	type BlocksPerDay = BlocksPerDay;
+	type RefundServiceFee = RefundServiceFee;
+	type TransferWindow = TransferWindow;
+	type MaxRelayers = MaxRelayers;
 	type WeightInfo = ();
 }
 
@@ -1,7 +1,21 @@
-use crate::{mock::*, Bytes, ChainId, OutboundTransferInfo, TransferId};
-use common_primitives::Balance;
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
-use sp_runtime::{traits::CheckedAdd, FixedPointNumber, FixedU128};
+use crate::{
+	mock::*, AssetBridgeMode, BridgedAsset, Bytes, ChainId, ChainLimits, InboundTransferResult,
+	InboundTransferResultStatus, OutboundTransferConfirmStatus, OutboundTransferInfo, TransferId,
+	TransferVolume,
+};
+use bholdus_support::MultiCurrency;
+use codec::Encode;
+use common_primitives::{Balance, TokenId, TokenInfo, TokenSymbol};
+use frame_support::{
+	assert_noop, assert_ok,
+	error::BadOrigin,
+	storage::migration::{get_storage_value, put_storage_value},
+	Blake2_128Concat, StorageHasher,
+};
+use sp_runtime::{
+	traits::{CheckedAdd, Hash},
+	DispatchError, FixedPointNumber, FixedU128,
+};
 
 #[test]
 fn force_register_relayer_should_work() {
@@ -65,7 +79,7 @@ fn force_set_platform_fee_should_work() {
 fn force_withdraw_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_noop!(
-			BridgeNativeTransfer::force_withdraw(Origin::signed(ALICE), CHARLIE),
+			BridgeNativeTransfer::force_withdraw(Origin::signed(ALICE), CHARLIE, None),
 			BadOrigin
 		);
 
@@ -75,9 +89,24 @@ fn force_withdraw_should_work() {
 			1_000
 		));
 		assert_eq!(Balances::free_balance(BridgeNativeTransfer::pallet_account_id()), 1_000);
-		assert_ok!(BridgeNativeTransfer::force_withdraw(Origin::root(), CHARLIE));
+		assert_ok!(BridgeNativeTransfer::force_withdraw(Origin::root(), CHARLIE, None));
 		assert_eq!(Balances::free_balance(BridgeNativeTransfer::pallet_account_id()), 0);
 		assert_eq!(Balances::free_balance(CHARLIE), 1_000);
+
+		// Locked BHC20 tokens can be recovered too
+		let token_id = create_token(ALICE, 1_000u128);
+		assert_ok!(<Currencies as MultiCurrency<AccountId>>::transfer(
+			token_id,
+			&ALICE,
+			&BridgeNativeTransfer::pallet_account_id(),
+			400u128
+		));
+		assert_ok!(BridgeNativeTransfer::force_withdraw(Origin::root(), CHARLIE, Some(token_id)));
+		assert_eq!(
+			Currencies::free_balance(token_id, &BridgeNativeTransfer::pallet_account_id()),
+			0
+		);
+		assert_eq!(Currencies::free_balance(token_id, &CHARLIE), 400u128);
 	})
 }
 
@@ -96,6 +125,7 @@ fn assert_initiate_transfer(
 		to.clone(),
 		amount,
 		target_chain,
+		TokenSymbol::Native
 	));
 
 	assert_eq!(Balances::free_balance(from), initial_from_balance.checked_sub(amount).unwrap());
@@ -115,7 +145,9 @@ fn assert_initiate_transfer(
 			from: from.clone(),
 			service_fee: fee,
 			target_chain,
-			to: to.clone()
+			to: to.clone(),
+			asset: TokenSymbol::Native,
+			mode: None,
 		}
 	);
 	assert_eq!(
@@ -129,6 +161,7 @@ fn assert_initiate_transfer(
 			from.clone(),
 			to.clone(),
 			amount,
+			TokenSymbol::Native,
 		),
 	));
 }
@@ -177,7 +210,8 @@ fn initiate_transfer_should_work() {
 				Origin::signed(ALICE),
 				to.clone(),
 				1_000_000u128,
-				target_chain
+				target_chain,
+				TokenSymbol::Native
 			),
 			crate::Error::<Runtime>::MustBeRegisteredChain
 		);
@@ -189,7 +223,8 @@ fn initiate_transfer_should_work() {
 				Origin::signed(ALICE),
 				to.clone(),
 				1,
-				target_chain
+				target_chain,
+				TokenSymbol::Native
 			),
 			crate::Error::<Runtime>::MinimumDepositRequired
 		);
@@ -199,7 +234,8 @@ fn initiate_transfer_should_work() {
 				Origin::signed(ALICE),
 				to.clone(),
 				1_000_000u128,
-				target_chain
+				target_chain,
+				TokenSymbol::Native
 			),
 			pallet_balances::Error::<Runtime>::InsufficientBalance
 		);
@@ -211,7 +247,8 @@ fn initiate_transfer_should_work() {
 				Origin::signed(ALICE),
 				to.clone(),
 				transfer_amount,
-				target_chain
+				target_chain,
+				TokenSymbol::Native
 			),
 			crate::Error::<Runtime>::Frozen
 		);
@@ -225,7 +262,11 @@ fn initiate_transfer_should_work() {
 
 fn assert_confirm_transfer(relayer_id: AccountId, transfer_id: TransferId) {
 	let relayer_initial_balance = Balances::free_balance(relayer_id);
-	assert_ok!(BridgeNativeTransfer::confirm_transfer(Origin::signed(relayer_id), transfer_id));
+	assert_ok!(BridgeNativeTransfer::confirm_transfer(
+		Origin::signed(relayer_id),
+		transfer_id,
+		OutboundTransferConfirmStatus::Successful
+	));
 
 	assert_eq!(
 		BridgeNativeTransfer::next_confirm_outbound_transfer_id(),
@@ -239,6 +280,17 @@ fn assert_confirm_transfer(relayer_id: AccountId, transfer_id: TransferId) {
 			.checked_add(relayer_initial_balance)
 			.unwrap()
 	);
+	assert_eq!(
+		BridgeNativeTransfer::outbound_transfer_status(transfer_id),
+		Some(OutboundTransferConfirmStatus::Successful)
+	);
+	System::assert_last_event(Event::BridgeNativeTransfer(
+		crate::Event::OutboundTransferConfirmed(
+			transfer_id,
+			relayer_id,
+			OutboundTransferConfirmStatus::Successful,
+		),
+	));
 }
 
 #[test]
@@ -252,7 +304,11 @@ fn confirm_transfer_should_work() {
 		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), target_chain));
 
 		assert_noop!(
-			BridgeNativeTransfer::confirm_transfer(Origin::signed(relayer_id), 0),
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayer_id),
+				0,
+				OutboundTransferConfirmStatus::Successful
+			),
 			crate::Error::<Runtime>::MustBeRegisteredRelayer
 		);
 
@@ -262,14 +318,22 @@ fn confirm_transfer_should_work() {
 		assert_initiate_transfer(&BOB, to.clone(), transfer_amount, target_chain, 1);
 
 		assert_noop!(
-			BridgeNativeTransfer::confirm_transfer(Origin::signed(relayer_id), 1),
-			crate::Error::<Runtime>::UnexpectedOutboundTransferConfirmation
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayer_id),
+				2,
+				OutboundTransferConfirmStatus::Successful
+			),
+			crate::Error::<Runtime>::OutboundTransferNotFound
 		);
 
 		assert_ok!(BridgeNativeTransfer::force_freeze(Origin::root()));
 
 		assert_noop!(
-			BridgeNativeTransfer::confirm_transfer(Origin::signed(ALICE), 0),
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(ALICE),
+				0,
+				OutboundTransferConfirmStatus::Successful
+			),
 			crate::Error::<Runtime>::Frozen
 		);
 
@@ -279,7 +343,214 @@ fn confirm_transfer_should_work() {
 		assert_confirm_transfer(relayer_id, 1);
 
 		assert_noop!(
-			BridgeNativeTransfer::confirm_transfer(Origin::signed(relayer_id), 1),
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayer_id),
+				1,
+				OutboundTransferConfirmStatus::Successful
+			),
+			crate::Error::<Runtime>::AllOutboundTransfersConfirmed
+		);
+	});
+}
+
+#[test]
+fn confirm_transfer_should_work_out_of_order() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let target_chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), target_chain));
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+		for transfer_id in 0..4 {
+			assert_initiate_transfer(&ALICE, to.clone(), 1_000u128, target_chain, transfer_id);
+		}
+
+		assert_noop!(
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayer_id),
+				3,
+				OutboundTransferConfirmStatus::Successful
+			),
+			crate::Error::<Runtime>::TransferOutsideWindow
+		);
+
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayer_id),
+			2,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(BridgeNativeTransfer::next_confirm_outbound_transfer_id(), 0);
+		assert_eq!(
+			BridgeNativeTransfer::outbound_transfer_status(2),
+			Some(OutboundTransferConfirmStatus::Failed)
+		);
+		assert_noop!(
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayer_id),
+				2,
+				OutboundTransferConfirmStatus::Successful
+			),
+			crate::Error::<Runtime>::UnexpectedOutboundTransferConfirmation
+		);
+
+		assert_confirm_transfer(relayer_id, 0);
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayer_id),
+			1,
+			OutboundTransferConfirmStatus::Successful
+		));
+		assert_eq!(BridgeNativeTransfer::next_confirm_outbound_transfer_id(), 3);
+		assert_confirm_transfer(relayer_id, 3);
+	});
+}
+
+#[test]
+fn confirm_failed_transfer_should_refund() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let transfer_amount = 1000_u128;
+	let target_chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), target_chain));
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+		assert_ok!(BridgeNativeTransfer::force_set_service_fee(Origin::root(), 20));
+
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			transfer_amount,
+			target_chain,
+			TokenSymbol::Native
+		));
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(BOB),
+			to.clone(),
+			transfer_amount,
+			target_chain,
+			TokenSymbol::Native
+		));
+		assert_eq!(Balances::free_balance(ALICE), 98_980u128);
+
+		// The amount and the service fee are refunded
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayer_id),
+			0,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(Balances::free_balance(ALICE), 100_000u128);
+		assert_eq!(Balances::free_balance(relayer_id), 0);
+		assert_eq!(
+			BridgeNativeTransfer::outbound_transfer_status(0),
+			Some(OutboundTransferConfirmStatus::Failed)
+		);
+		System::assert_has_event(Event::BridgeNativeTransfer(
+			crate::Event::OutboundTransferConfirmed(
+				0,
+				relayer_id,
+				OutboundTransferConfirmStatus::Failed,
+			),
+		));
+		System::assert_last_event(Event::BridgeNativeTransfer(
+			crate::Event::OutboundTransferRefunded(
+				0,
+				ALICE,
+				TokenSymbol::Native,
+				1_000u128,
+				20u128,
+			),
+		));
+
+		// The relayer keeps the service fee unless `RefundServiceFee` is set
+		RefundServiceFee::set(false);
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayer_id),
+			1,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(Balances::free_balance(BOB), 99_980u128);
+		assert_eq!(Balances::free_balance(relayer_id), 20u128);
+		System::assert_last_event(Event::BridgeNativeTransfer(
+			crate::Event::OutboundTransferRefunded(1, BOB, TokenSymbol::Native, 1_000u128, 0),
+		));
+		assert_eq!(BridgeNativeTransfer::next_confirm_outbound_transfer_id(), 2);
+	});
+}
+
+#[test]
+fn confirm_transfer_should_wait_for_relayer_threshold() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let target_chain: ChainId = 1;
+	let relayers: [AccountId; 3] = [10, 11, 12];
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), target_chain));
+		for relayer in relayers {
+			assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer));
+		}
+		assert_ok!(BridgeNativeTransfer::force_set_relayer_threshold(Origin::root(), 2));
+		assert_initiate_transfer(&ALICE, to.clone(), 1_000u128, target_chain, 0);
+
+		// A single relayer can't refund the transfer
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayers[0]),
+			0,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(Balances::free_balance(ALICE), 99_000u128);
+		assert_eq!(BridgeNativeTransfer::outbound_transfer_status(0), None);
+		System::assert_last_event(Event::BridgeNativeTransfer(
+			crate::Event::OutboundTransferVoted(
+				0,
+				relayers[0],
+				OutboundTransferConfirmStatus::Failed,
+			),
+		));
+		assert_noop!(
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayers[0]),
+				0,
+				OutboundTransferConfirmStatus::Successful
+			),
+			crate::Error::<Runtime>::AlreadyVoted
+		);
+
+		// Votes for different statuses don't add up
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayers[1]),
+			0,
+			OutboundTransferConfirmStatus::Successful
+		));
+		System::assert_has_event(Event::BridgeNativeTransfer(
+			crate::Event::ConflictingOutboundTransferVote(
+				0,
+				relayers[1],
+				OutboundTransferConfirmStatus::Successful,
+			),
+		));
+		assert_eq!(BridgeNativeTransfer::outbound_transfer_status(0), None);
+
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayers[2]),
+			0,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(Balances::free_balance(ALICE), 100_000u128);
+		assert_eq!(
+			BridgeNativeTransfer::outbound_transfer_status(0),
+			Some(OutboundTransferConfirmStatus::Failed)
+		);
+		assert_eq!(
+			BridgeNativeTransfer::outbound_transfer_votes(0, OutboundTransferConfirmStatus::Failed),
+			Vec::<AccountId>::new()
+		);
+		assert_noop!(
+			BridgeNativeTransfer::confirm_transfer(
+				Origin::signed(relayers[1]),
+				0,
+				OutboundTransferConfirmStatus::Failed
+			),
 			crate::Error::<Runtime>::AllOutboundTransfersConfirmed
 		);
 	});
@@ -288,6 +559,7 @@ fn confirm_transfer_should_work() {
 fn assert_release_tokens(
 	relayer_id: AccountId,
 	transfer_id: TransferId,
+	source_chain: ChainId,
 	from: Bytes,
 	to: AccountId,
 	amount: u128,
@@ -297,9 +569,11 @@ fn assert_release_tokens(
 	assert_ok!(BridgeNativeTransfer::release_tokens(
 		Origin::signed(relayer_id),
 		transfer_id,
+		source_chain,
 		from.clone(),
 		to,
-		amount
+		amount,
+		None
 	));
 	assert_eq!(Balances::free_balance(ALICE), initial_to_balance.checked_add(amount).unwrap());
 	assert_eq!(
@@ -315,12 +589,13 @@ fn assert_release_tokens(
 		from.clone(),
 		to,
 		amount,
+		TokenSymbol::Native,
 	)));
 }
 
 #[test]
 fn release_tokens_should_work() {
-	let target_chain: ChainId = 1;
+	let source_chain: ChainId = 1;
 	let relayer_id: AccountId = 10;
 	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
 	ExtBuilder::default()
@@ -329,15 +604,17 @@ fn release_tokens_should_work() {
 		.execute_with(|| {
 			System::set_block_number(1);
 
-			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), target_chain));
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
 
 			assert_noop!(
 				BridgeNativeTransfer::release_tokens(
 					Origin::signed(ALICE),
 					0,
+					source_chain,
 					from.clone(),
 					ALICE,
-					1_000u128
+					1_000u128,
+					None
 				),
 				crate::Error::<Runtime>::MustBeRegisteredRelayer
 			);
@@ -347,12 +624,14 @@ fn release_tokens_should_work() {
 			assert_noop!(
 				BridgeNativeTransfer::release_tokens(
 					Origin::signed(relayer_id),
-					1,
+					3,
+					source_chain,
 					from.clone(),
 					ALICE,
-					1_000u128
+					1_000u128,
+					None
 				),
-				crate::Error::<Runtime>::UnexpectedInboundTransfer
+				crate::Error::<Runtime>::TransferOutsideWindow
 			);
 
 			assert_ok!(BridgeNativeTransfer::force_freeze(Origin::root()));
@@ -361,41 +640,1162 @@ fn release_tokens_should_work() {
 				BridgeNativeTransfer::release_tokens(
 					Origin::signed(relayer_id),
 					1,
+					source_chain,
 					from.clone(),
 					ALICE,
-					1_000u128
+					1_000u128,
+					None
 				),
 				crate::Error::<Runtime>::Frozen
 			);
 
 			assert_ok!(BridgeNativeTransfer::force_unfreeze(Origin::root()));
 
-			assert_release_tokens(relayer_id, 0, from.clone(), ALICE, 1_000u128);
+			assert_release_tokens(relayer_id, 0, source_chain, from.clone(), ALICE, 1_000u128);
 
 			assert_noop!(
 				BridgeNativeTransfer::release_tokens(
 					Origin::signed(relayer_id),
 					0,
+					source_chain,
 					from.clone(),
 					ALICE,
-					1_000u128
+					1_000u128,
+					None
 				),
 				crate::Error::<Runtime>::UnexpectedInboundTransfer
 			);
+		});
+}
 
+#[test]
+fn release_tokens_should_work_out_of_order() {
+	let source_chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	ExtBuilder::default()
+		.with_balances(vec![(BridgeNativeTransfer::pallet_account_id(), 100_000u128)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
+			assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				1,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 1_000u128);
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 0);
+			assert_eq!(
+				BridgeNativeTransfer::inbound_transfer_results(1),
+				InboundTransferResult { status: InboundTransferResultStatus::Ok }
+			);
 			assert_noop!(
 				BridgeNativeTransfer::release_tokens(
 					Origin::signed(relayer_id),
+					1,
+					source_chain,
+					from.clone(),
+					ALICE,
+					1_000u128,
+					None
+				),
+				crate::Error::<Runtime>::UnexpectedInboundTransfer
+			);
+
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				0,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 2);
+
+			// Failed releases are recorded and can be retried
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				2,
+				source_chain,
+				from.clone(),
+				ALICE,
+				100_000u128,
+				None
+			));
+			let error: DispatchError =
+				pallet_balances::Error::<Runtime>::InsufficientBalance.into();
+			assert_eq!(
+				BridgeNativeTransfer::inbound_transfer_results(2),
+				InboundTransferResult {
+					status: InboundTransferResultStatus::Err(error.stripped())
+				}
+			);
+			System::assert_last_event(Event::BridgeNativeTransfer(
+				crate::Event::InboundTransferFailed(2, error.stripped()),
+			));
+			assert_eq!(Balances::free_balance(ALICE), 2_000u128);
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 3);
+
+			Balances::make_free_balance_be(&BridgeNativeTransfer::pallet_account_id(), 200_000u128);
+			assert_release_tokens(relayer_id, 2, source_chain, from.clone(), ALICE, 100_000u128);
+			assert_eq!(
+				BridgeNativeTransfer::inbound_transfer_results(2),
+				InboundTransferResult { status: InboundTransferResultStatus::Ok }
+			);
+		});
+}
+
+#[test]
+fn force_set_relayer_threshold_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(BridgeNativeTransfer::relayer_threshold(), 1);
+		assert_noop!(
+			BridgeNativeTransfer::force_set_relayer_threshold(Origin::signed(ALICE), 2),
+			BadOrigin
+		);
+		assert_noop!(
+			BridgeNativeTransfer::force_set_relayer_threshold(Origin::root(), 0),
+			crate::Error::<Runtime>::InvalidRelayerThreshold
+		);
+		// The threshold can't exceed the number of registered relayers
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), 10));
+		assert_noop!(
+			BridgeNativeTransfer::force_set_relayer_threshold(Origin::root(), 2),
+			crate::Error::<Runtime>::InvalidRelayerThreshold
+		);
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), 11));
+		assert_ok!(BridgeNativeTransfer::force_set_relayer_threshold(Origin::root(), 2));
+		assert_eq!(BridgeNativeTransfer::relayer_threshold(), 2);
+	});
+}
+
+#[test]
+fn relayer_count_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		for relayer in [10, 11, 12] {
+			assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer));
+		}
+		// Registering a relayer again doesn't count it twice
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), 10));
+		assert_eq!(BridgeNativeTransfer::relayer_count(), 3);
+		assert_noop!(
+			BridgeNativeTransfer::force_register_relayer(Origin::root(), 13),
+			crate::Error::<Runtime>::TooManyRelayers
+		);
+
+		assert_ok!(BridgeNativeTransfer::force_unregister_relayer(Origin::root(), 10));
+		assert_ok!(BridgeNativeTransfer::force_unregister_relayer(Origin::root(), 10));
+		assert_eq!(BridgeNativeTransfer::relayer_count(), 2);
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), 13));
+		assert_eq!(BridgeNativeTransfer::relayer_count(), 3);
+	});
+}
+
+#[test]
+fn migrate_to_v4_should_set_a_majority_threshold() {
+	ExtBuilder::default().build().execute_with(|| {
+		for relayer in [10, 11, 12] {
+			crate::RegisteredRelayers::<Runtime>::insert(relayer, true);
+		}
+		crate::RegisteredRelayers::<Runtime>::insert(13, false);
+
+		crate::migrations::v4::migrate_to_v4::<Runtime>();
+		assert_eq!(BridgeNativeTransfer::relayer_count(), 3);
+		assert_eq!(BridgeNativeTransfer::relayer_threshold(), 2);
+	});
+}
+
+#[test]
+fn release_tokens_should_wait_for_relayer_threshold() {
+	let source_chain: ChainId = 1;
+	let relayers: [AccountId; 3] = [10, 11, 12];
+	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	ExtBuilder::default()
+		.with_balances(vec![(BridgeNativeTransfer::pallet_account_id(), 100_000u128)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
+			for relayer in relayers {
+				assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer));
+			}
+			assert_ok!(BridgeNativeTransfer::force_set_relayer_threshold(Origin::root(), 2));
+
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayers[0]),
+				0,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 0);
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 0);
+
+			assert_noop!(
+				BridgeNativeTransfer::release_tokens(
+					Origin::signed(relayers[0]),
+					0,
+					source_chain,
+					from.clone(),
+					ALICE,
+					1_000u128,
+					None
+				),
+				crate::Error::<Runtime>::AlreadyVoted
+			);
+
+			// A relayer voting for a different payload is reported and doesn't count
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayers[1]),
+				0,
+				source_chain,
+				from.clone(),
+				BOB,
+				90_000u128,
+				None
+			));
+			let conflicting_hash = <Runtime as frame_system::Config>::Hashing::hash_of(&(
+				0 as TransferId,
+				source_chain,
+				&from,
+				&BOB,
+				90_000u128,
+				&None::<Bytes>,
+			));
+			assert!(System::events().iter().any(|record| record.event ==
+				Event::BridgeNativeTransfer(crate::Event::ConflictingInboundTransferVote(
+					0,
+					relayers[1],
+					conflicting_hash
+				))));
+			assert_eq!(Balances::free_balance(BOB), 0);
+
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayers[2]),
+				0,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 1_000u128);
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 1);
+			assert_eq!(
+				BridgeNativeTransfer::inbound_transfer_votes(0, conflicting_hash),
+				Vec::<AccountId>::new()
+			);
+			System::assert_last_event(Event::BridgeNativeTransfer(
+				crate::Event::InboundTokenReleased(
+					0,
+					from.clone(),
+					ALICE,
+					1_000u128,
+					TokenSymbol::Native,
+				),
+			));
+		});
+}
+
+#[test]
+fn release_tokens_should_ignore_votes_of_unregistered_relayers() {
+	let source_chain: ChainId = 1;
+	let relayers: [AccountId; 2] = [10, 11];
+	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	ExtBuilder::default()
+		.with_balances(vec![(BridgeNativeTransfer::pallet_account_id(), 100_000u128)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
+			for relayer in relayers {
+				assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer));
+			}
+			assert_ok!(BridgeNativeTransfer::force_set_relayer_threshold(Origin::root(), 2));
+
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayers[0]),
+				0,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_ok!(BridgeNativeTransfer::force_unregister_relayer(Origin::root(), relayers[0]));
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayers[1]),
+				0,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 0);
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 0);
+		});
+}
+
+#[test]
+fn force_set_chain_limits_should_work() {
+	let limits = ChainLimits {
+		max_transfer: 1_000u128,
+		window: 10,
+		max_outbound_per_window: 2_000u128,
+		max_inbound_per_window: 2_000u128,
+		max_inbound_per_day: 5_000u128,
+	};
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			BridgeNativeTransfer::force_set_chain_limits(
+				Origin::signed(ALICE),
+				1,
+				TokenSymbol::Native,
+				Some(limits.clone())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			BridgeNativeTransfer::force_set_chain_limits(
+				Origin::root(),
+				1,
+				TokenSymbol::Native,
+				Some(ChainLimits { window: 0, ..limits.clone() })
+			),
+			crate::Error::<Runtime>::InvalidChainLimits
+		);
+
+		assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+			Origin::root(),
+			1,
+			TokenSymbol::Native,
+			Some(limits.clone())
+		));
+		assert_eq!(
+			BridgeNativeTransfer::chain_limits(1, TokenSymbol::Native),
+			Some(limits.clone())
+		);
+		System::assert_last_event(Event::BridgeNativeTransfer(crate::Event::ChainLimitsSet(
+			1,
+			TokenSymbol::Native,
+			Some(limits),
+		)));
+
+		assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+			Origin::root(),
+			1,
+			TokenSymbol::Native,
+			None
+		));
+		assert_eq!(BridgeNativeTransfer::chain_limits(1, TokenSymbol::Native), None);
+	});
+}
+
+#[test]
+fn initiate_transfer_should_respect_chain_limits() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let target_chain: ChainId = 1;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), target_chain));
+		assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+			Origin::root(),
+			target_chain,
+			TokenSymbol::Native,
+			Some(ChainLimits {
+				max_transfer: 1_000u128,
+				window: 10,
+				max_outbound_per_window: 1_500u128,
+				max_inbound_per_window: 1_500u128,
+				max_inbound_per_day: 1_500u128,
+			})
+		));
+
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(ALICE),
+				to.clone(),
+				1_001u128,
+				target_chain,
+				TokenSymbol::Native
+			),
+			crate::Error::<Runtime>::TransferLimitExceeded
+		);
+
+		assert_initiate_transfer(&ALICE, to.clone(), 1_000u128, target_chain, 0);
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(BOB),
+				to.clone(),
+				1_000u128,
+				target_chain,
+				TokenSymbol::Native
+			),
+			crate::Error::<Runtime>::TransferLimitExceeded
+		);
+		assert_initiate_transfer(&BOB, to.clone(), 500u128, target_chain, 1);
+
+		// Half of the maximum volume is freed up over half of the window
+		System::set_block_number(6);
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(BOB),
+				to.clone(),
+				751u128,
+				target_chain,
+				TokenSymbol::Native
+			),
+			crate::Error::<Runtime>::TransferLimitExceeded
+		);
+		assert_initiate_transfer(&BOB, to.clone(), 750u128, target_chain, 2);
+
+		// The volume no longer resets at the end of the window
+		System::set_block_number(11);
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(BOB),
+				to.clone(),
+				1_000u128,
+				target_chain,
+				TokenSymbol::Native
+			),
+			crate::Error::<Runtime>::TransferLimitExceeded
+		);
+		System::set_block_number(16);
+		assert_initiate_transfer(&BOB, to.clone(), 1_000u128, target_chain, 3);
+	});
+}
+
+#[test]
+fn release_tokens_exceeding_chain_limits_should_wait_for_approval() {
+	let source_chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	ExtBuilder::default()
+		.with_balances(vec![(BridgeNativeTransfer::pallet_account_id(), 100_000u128)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
+			assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+			assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+				Origin::root(),
+				source_chain,
+				TokenSymbol::Native,
+				Some(ChainLimits {
+					max_transfer: 1_000u128,
+					window: 10,
+					max_outbound_per_window: 10_000u128,
+					max_inbound_per_window: 1_500u128,
+					max_inbound_per_day: 10_000u128,
+				})
+			));
+
+			assert_release_tokens(relayer_id, 0, source_chain, from.clone(), ALICE, 1_000u128);
+
+			// Exceeding the window volume freezes the bridge and queues the release
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				1,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 1_000u128);
+			assert_eq!(BridgeNativeTransfer::is_frozen(), true);
+			assert_eq!(BridgeNativeTransfer::next_inbound_transfer_id(), 2);
+			assert!(BridgeNativeTransfer::pending_releases(1).is_some());
+			System::assert_last_event(Event::BridgeNativeTransfer(
+				crate::Event::InboundTransferLimitExceeded(1, source_chain, 1_000u128),
+			));
+
+			assert_noop!(
+				BridgeNativeTransfer::force_approve_release(Origin::signed(ALICE), 1),
+				BadOrigin
+			);
+			assert_noop!(
+				BridgeNativeTransfer::force_approve_release(Origin::root(), 2),
+				crate::Error::<Runtime>::PendingReleaseNotFound
+			);
+			assert_ok!(BridgeNativeTransfer::force_approve_release(Origin::root(), 1));
+			assert_eq!(Balances::free_balance(ALICE), 2_000u128);
+			assert!(BridgeNativeTransfer::pending_releases(1).is_none());
+			System::assert_last_event(Event::BridgeNativeTransfer(
+				crate::Event::InboundTokenReleased(
 					1,
 					from.clone(),
 					ALICE,
-					100_000u128
+					1_000u128,
+					TokenSymbol::Native,
 				),
-				pallet_balances::Error::<Runtime>::InsufficientBalance
+			));
+
+			// A single transfer above the maximum is queued too
+			assert_ok!(BridgeNativeTransfer::force_unfreeze(Origin::root()));
+			System::set_block_number(11);
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				2,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_001u128,
+				None
+			));
+			assert_eq!(BridgeNativeTransfer::is_frozen(), true);
+			assert_noop!(
+				BridgeNativeTransfer::force_reject_release(Origin::signed(ALICE), 2),
+				BadOrigin
 			);
+			assert_ok!(BridgeNativeTransfer::force_reject_release(Origin::root(), 2));
+			assert!(BridgeNativeTransfer::pending_releases(2).is_none());
+			assert_eq!(Balances::free_balance(ALICE), 2_000u128);
+			System::assert_last_event(Event::BridgeNativeTransfer(
+				crate::Event::PendingReleaseRejected(2),
+			));
 		});
 }
 
+#[test]
+fn failed_release_should_not_count_in_chain_volumes() {
+	let source_chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	ExtBuilder::default()
+		.with_balances(vec![
+			(BridgeNativeTransfer::pallet_account_id(), 500u128),
+			(BOB, 100_000u128),
+		])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
+			assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+			assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+				Origin::root(),
+				source_chain,
+				TokenSymbol::Native,
+				Some(ChainLimits {
+					max_transfer: 1_000u128,
+					window: 10,
+					max_outbound_per_window: 10_000u128,
+					max_inbound_per_window: 1_000u128,
+					max_inbound_per_day: 1_000u128,
+				})
+			));
+
+			// The pallet account can't pay the transfer
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				0,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert!(matches!(
+				BridgeNativeTransfer::inbound_transfer_results(0).status,
+				InboundTransferResultStatus::Err(_)
+			));
+			assert_eq!(
+				BridgeNativeTransfer::inbound_volume(source_chain, TokenSymbol::Native).amount,
+				0
+			);
+			assert_eq!(
+				BridgeNativeTransfer::inbound_daily_volume(source_chain, TokenSymbol::Native)
+					.amount,
+				0
+			);
+
+			// Retrying it once the pallet account is funded stays within the limits
+			assert_ok!(Balances::transfer(
+				Origin::signed(BOB),
+				BridgeNativeTransfer::pallet_account_id(),
+				1_000u128
+			));
+			assert_release_tokens(relayer_id, 0, source_chain, from.clone(), ALICE, 1_000u128);
+			assert_eq!(BridgeNativeTransfer::is_frozen(), false);
+			assert_eq!(
+				BridgeNativeTransfer::inbound_volume(source_chain, TokenSymbol::Native).amount,
+				1_000u128
+			);
+		});
+}
+
+#[test]
+fn release_tokens_should_respect_daily_limit() {
+	let source_chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	let from = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	ExtBuilder::default()
+		.with_balances(vec![(BridgeNativeTransfer::pallet_account_id(), 100_000u128)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), source_chain));
+			assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+			assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+				Origin::root(),
+				source_chain,
+				TokenSymbol::Native,
+				Some(ChainLimits {
+					max_transfer: 1_000u128,
+					window: 10,
+					max_outbound_per_window: 10_000u128,
+					max_inbound_per_window: 1_000u128,
+					max_inbound_per_day: 2_000u128,
+				})
+			));
+
+			assert_release_tokens(relayer_id, 0, source_chain, from.clone(), ALICE, 1_000u128);
+			System::set_block_number(11);
+			assert_release_tokens(relayer_id, 1, source_chain, from.clone(), ALICE, 1_000u128);
+
+			// The window volume was freed up but the daily one only decayed by a tenth
+			System::set_block_number(21);
+			assert_ok!(BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				2,
+				source_chain,
+				from.clone(),
+				ALICE,
+				1_000u128,
+				None
+			));
+			assert_eq!(Balances::free_balance(ALICE), 2_000u128);
+			assert_eq!(BridgeNativeTransfer::is_frozen(), true);
+			assert!(BridgeNativeTransfer::pending_releases(2).is_some());
+
+			// The daily volume is freed up `BlocksPerDay` blocks after the last release
+			assert_ok!(BridgeNativeTransfer::force_unfreeze(Origin::root()));
+			System::set_block_number(101);
+			assert_release_tokens(relayer_id, 3, source_chain, from.clone(), ALICE, 1_000u128);
+		});
+}
+
+#[test]
+fn force_register_asset_should_work() {
+	let foreign_asset = hex::decode("55d398326f99059fF775485246999027B3197955").unwrap();
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			BridgeNativeTransfer::force_register_asset(
+				Origin::signed(ALICE),
+				1,
+				foreign_asset.clone(),
+				0,
+				AssetBridgeMode::LockUnlock
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			BridgeNativeTransfer::force_register_asset(
+				Origin::root(),
+				1,
+				foreign_asset.clone(),
+				0,
+				AssetBridgeMode::LockUnlock
+			),
+			crate::Error::<Runtime>::MustBeRegisteredChain
+		);
+
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), 1));
+		assert_ok!(BridgeNativeTransfer::force_register_asset(
+			Origin::root(),
+			1,
+			foreign_asset.clone(),
+			0,
+			AssetBridgeMode::LockUnlock
+		));
+		assert_eq!(
+			BridgeNativeTransfer::bridged_assets(1, &foreign_asset),
+			Some(BridgedAsset { token_id: 0, mode: AssetBridgeMode::LockUnlock })
+		);
+		assert_eq!(BridgeNativeTransfer::foreign_assets(1, 0), Some(foreign_asset.clone()));
+		System::assert_last_event(Event::BridgeNativeTransfer(crate::Event::AssetRegistered(
+			1,
+			foreign_asset.clone(),
+			0,
+			AssetBridgeMode::LockUnlock,
+		)));
+
+		assert_noop!(
+			BridgeNativeTransfer::force_register_asset(
+				Origin::root(),
+				1,
+				foreign_asset.clone(),
+				1,
+				AssetBridgeMode::MintBurn
+			),
+			crate::Error::<Runtime>::AssetAlreadyRegistered
+		);
+		assert_noop!(
+			BridgeNativeTransfer::force_register_asset(
+				Origin::root(),
+				1,
+				vec![0u8; 20],
+				0,
+				AssetBridgeMode::MintBurn
+			),
+			crate::Error::<Runtime>::AssetAlreadyRegistered
+		);
+
+		assert_noop!(
+			BridgeNativeTransfer::force_unregister_asset(
+				Origin::signed(ALICE),
+				1,
+				foreign_asset.clone()
+			),
+			BadOrigin
+		);
+		assert_ok!(BridgeNativeTransfer::force_unregister_asset(
+			Origin::root(),
+			1,
+			foreign_asset.clone()
+		));
+		assert_eq!(BridgeNativeTransfer::bridged_assets(1, &foreign_asset), None);
+		assert_eq!(BridgeNativeTransfer::foreign_assets(1, 0), None);
+		System::assert_last_event(Event::BridgeNativeTransfer(crate::Event::AssetUnregistered(
+			1,
+			foreign_asset.clone(),
+			0,
+		)));
+		assert_noop!(
+			BridgeNativeTransfer::force_unregister_asset(Origin::root(), 1, foreign_asset),
+			crate::Error::<Runtime>::AssetNotRegistered
+		);
+	});
+}
+
+fn create_token(owner: AccountId, amount: Balance) -> TokenId {
+	let token_id = BholdusTokens::next_asset_id();
+	assert_ok!(BholdusTokens::create(Origin::signed(owner), owner, 1));
+	assert_ok!(BholdusTokens::mint(Origin::signed(owner), token_id, owner, amount));
+	token_id
+}
+
+#[test]
+fn bridge_token_in_lock_unlock_mode_should_work() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let foreign_asset = hex::decode("55d398326f99059fF775485246999027B3197955").unwrap();
+	let chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let token_id = create_token(ALICE, 10_000u128);
+		let asset = TokenSymbol::Token(TokenInfo { id: token_id });
+		let pallet_account_id = BridgeNativeTransfer::pallet_account_id();
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), chain));
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+		assert_ok!(BridgeNativeTransfer::force_set_service_fee(Origin::root(), 20));
+
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(ALICE),
+				to.clone(),
+				1_000u128,
+				chain,
+				asset
+			),
+			crate::Error::<Runtime>::AssetNotRegistered
+		);
+		assert_ok!(BridgeNativeTransfer::force_register_asset(
+			Origin::root(),
+			chain,
+			foreign_asset.clone(),
+			token_id,
+			AssetBridgeMode::LockUnlock
+		));
+
+		// Tokens are locked and the fee is charged in the native currency
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			1_000u128,
+			chain,
+			asset
+		));
+		assert_eq!(Currencies::free_balance(token_id, &ALICE), 9_000u128);
+		assert_eq!(Currencies::free_balance(token_id, &pallet_account_id), 1_000u128);
+		assert_eq!(Balances::free_balance(ALICE), 99_980u128);
+		System::assert_last_event(Event::BridgeNativeTransfer(
+			crate::Event::OutboundTransferInitiated(0, ALICE, to.clone(), 1_000u128, asset),
+		));
+
+		// Failed transfers are refunded in the token
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayer_id),
+			0,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(Currencies::free_balance(token_id, &ALICE), 10_000u128);
+		assert_eq!(Balances::free_balance(ALICE), 100_000u128);
+		System::assert_last_event(Event::BridgeNativeTransfer(
+			crate::Event::OutboundTransferRefunded(0, ALICE, asset, 1_000u128, 20u128),
+		));
+
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			1_000u128,
+			chain,
+			asset
+		));
+
+		// Inbound transfers unlock the tokens
+		assert_noop!(
+			BridgeNativeTransfer::release_tokens(
+				Origin::signed(relayer_id),
+				0,
+				chain,
+				to.clone(),
+				BOB,
+				400u128,
+				Some(vec![0u8; 20])
+			),
+			crate::Error::<Runtime>::AssetNotRegistered
+		);
+		assert_ok!(BridgeNativeTransfer::release_tokens(
+			Origin::signed(relayer_id),
+			0,
+			chain,
+			to.clone(),
+			BOB,
+			400u128,
+			Some(foreign_asset.clone())
+		));
+		assert_eq!(Currencies::free_balance(token_id, &BOB), 400u128);
+		assert_eq!(Currencies::free_balance(token_id, &pallet_account_id), 600u128);
+		System::assert_last_event(Event::BridgeNativeTransfer(crate::Event::InboundTokenReleased(
+			0,
+			to.clone(),
+			BOB,
+			400u128,
+			asset,
+		)));
+	});
+}
+
+#[test]
+fn bridge_token_in_mint_burn_mode_should_work() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let foreign_asset = hex::decode("55d398326f99059fF775485246999027B3197955").unwrap();
+	let chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let token_id = create_token(ALICE, 10_000u128);
+		let asset = TokenSymbol::Token(TokenInfo { id: token_id });
+		let pallet_account_id = BridgeNativeTransfer::pallet_account_id();
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), chain));
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+		assert_ok!(BridgeNativeTransfer::force_register_asset(
+			Origin::root(),
+			chain,
+			foreign_asset.clone(),
+			token_id,
+			AssetBridgeMode::MintBurn
+		));
+
+		// Tokens are burnt when sent
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			1_000u128,
+			chain,
+			asset
+		));
+		assert_eq!(Currencies::free_balance(token_id, &ALICE), 9_000u128);
+		assert_eq!(Currencies::free_balance(token_id, &pallet_account_id), 0);
+		assert_eq!(Currencies::total_issuance(token_id), 9_000u128);
+
+		// And minted when received
+		assert_ok!(BridgeNativeTransfer::release_tokens(
+			Origin::signed(relayer_id),
+			0,
+			chain,
+			to.clone(),
+			BOB,
+			5_000u128,
+			Some(foreign_asset.clone())
+		));
+		assert_eq!(Currencies::free_balance(token_id, &BOB), 5_000u128);
+		assert_eq!(Currencies::total_issuance(token_id), 14_000u128);
+	});
+}
+
+#[test]
+fn bridge_token_should_respect_its_chain_limits() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let foreign_asset = hex::decode("55d398326f99059fF775485246999027B3197955").unwrap();
+	let chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let token_id = create_token(ALICE, 10_000u128);
+		let asset = TokenSymbol::Token(TokenInfo { id: token_id });
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), chain));
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+		assert_ok!(BridgeNativeTransfer::force_register_asset(
+			Origin::root(),
+			chain,
+			foreign_asset.clone(),
+			token_id,
+			AssetBridgeMode::LockUnlock
+		));
+		assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+			Origin::root(),
+			chain,
+			asset,
+			Some(ChainLimits {
+				max_transfer: 1_000u128,
+				window: 10,
+				max_outbound_per_window: 1_500u128,
+				max_inbound_per_window: 1_000u128,
+				max_inbound_per_day: 1_000u128,
+			})
+		));
+		assert_eq!(BridgeNativeTransfer::chain_limits(chain, TokenSymbol::Native), None);
+
+		// Limits are denominated in the token and don't apply to the native currency
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(ALICE),
+				to.clone(),
+				1_001u128,
+				chain,
+				asset
+			),
+			crate::Error::<Runtime>::TransferLimitExceeded
+		);
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			2_000u128,
+			chain,
+			TokenSymbol::Native
+		));
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			1_000u128,
+			chain,
+			asset
+		));
+		assert_eq!(BridgeNativeTransfer::outbound_volume(chain, asset).amount, 1_000u128);
+		assert_eq!(BridgeNativeTransfer::outbound_volume(chain, TokenSymbol::Native).amount, 0);
+		assert_noop!(
+			BridgeNativeTransfer::initiate_transfer(
+				Origin::signed(ALICE),
+				to.clone(),
+				1_000u128,
+				chain,
+				asset
+			),
+			crate::Error::<Runtime>::TransferLimitExceeded
+		);
+
+		// Inbound transfers above the limits of the token wait for approval
+		assert_ok!(BridgeNativeTransfer::release_tokens(
+			Origin::signed(relayer_id),
+			0,
+			chain,
+			to.clone(),
+			BOB,
+			1_001u128,
+			Some(foreign_asset.clone())
+		));
+		assert_eq!(Currencies::free_balance(token_id, &BOB), 0);
+		assert_eq!(BridgeNativeTransfer::is_frozen(), true);
+		assert!(BridgeNativeTransfer::pending_releases(0).is_some());
+		System::assert_last_event(Event::BridgeNativeTransfer(
+			crate::Event::InboundTransferLimitExceeded(0, chain, 1_001u128),
+		));
+	});
+}
+
+#[test]
+fn unsettled_transfers_should_keep_asset_registered() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let foreign_asset = hex::decode("55d398326f99059fF775485246999027B3197955").unwrap();
+	let chain: ChainId = 1;
+	let relayer_id: AccountId = 10;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let token_id = create_token(ALICE, 10_000u128);
+		let asset = TokenSymbol::Token(TokenInfo { id: token_id });
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), chain));
+		assert_ok!(BridgeNativeTransfer::force_register_relayer(Origin::root(), relayer_id));
+		assert_ok!(BridgeNativeTransfer::force_register_asset(
+			Origin::root(),
+			chain,
+			foreign_asset.clone(),
+			token_id,
+			AssetBridgeMode::LockUnlock
+		));
+		assert_ok!(BridgeNativeTransfer::force_set_chain_limits(
+			Origin::root(),
+			chain,
+			asset,
+			Some(ChainLimits {
+				max_transfer: 1_000u128,
+				window: 10,
+				max_outbound_per_window: 1_000u128,
+				max_inbound_per_window: 1_000u128,
+				max_inbound_per_day: 1_000u128,
+			})
+		));
+
+		// Outbound transfers keep the mode the token was sent in
+		assert_ok!(BridgeNativeTransfer::initiate_transfer(
+			Origin::signed(ALICE),
+			to.clone(),
+			1_000u128,
+			chain,
+			asset
+		));
+		assert_eq!(
+			BridgeNativeTransfer::outbound_transfers(0).unwrap().mode,
+			Some(AssetBridgeMode::LockUnlock)
+		);
+		assert_ok!(BridgeNativeTransfer::release_tokens(
+			Origin::signed(relayer_id),
+			0,
+			chain,
+			to.clone(),
+			BOB,
+			1_001u128,
+			Some(foreign_asset.clone())
+		));
+		assert_eq!(BridgeNativeTransfer::unsettled_transfers(chain, token_id), 2);
+		assert_noop!(
+			BridgeNativeTransfer::force_unregister_asset(
+				Origin::root(),
+				chain,
+				foreign_asset.clone()
+			),
+			crate::Error::<Runtime>::AssetHasUnsettledTransfers
+		);
+
+		assert_ok!(BridgeNativeTransfer::force_reject_release(Origin::root(), 0));
+		assert_ok!(BridgeNativeTransfer::force_unfreeze(Origin::root()));
+		assert_ok!(BridgeNativeTransfer::confirm_transfer(
+			Origin::signed(relayer_id),
+			0,
+			OutboundTransferConfirmStatus::Failed
+		));
+		assert_eq!(Currencies::free_balance(token_id, &ALICE), 10_000u128);
+		assert_eq!(BridgeNativeTransfer::unsettled_transfers(chain, token_id), 0);
+		assert_ok!(BridgeNativeTransfer::force_unregister_asset(
+			Origin::root(),
+			chain,
+			foreign_asset
+		));
+	});
+}
+
+#[test]
+fn migrate_to_v5_should_move_limits_to_the_native_currency() {
+	let limits = ChainLimits {
+		max_transfer: 1_000u128,
+		window: 10,
+		max_outbound_per_window: 2_000u128,
+		max_inbound_per_window: 2_000u128,
+		max_inbound_per_day: 5_000u128,
+	};
+	ExtBuilder::default().build().execute_with(|| {
+		let chain: ChainId = 1;
+		let key = Blake2_128Concat::hash(&chain.encode());
+		put_storage_value(b"BridgeNativeTransfer", b"ChainTransferLimits", &key, limits.clone());
+		put_storage_value(
+			b"BridgeNativeTransfer",
+			b"OutboundVolume",
+			&key,
+			TransferVolume { last_update: 1u64, amount: 500u128 },
+		);
+
+		crate::migrations::v5::migrate_to_v5::<Runtime>();
+		assert_eq!(BridgeNativeTransfer::chain_limits(chain, TokenSymbol::Native), Some(limits));
+		assert_eq!(
+			BridgeNativeTransfer::outbound_volume(chain, TokenSymbol::Native),
+			TransferVolume { last_update: 1u64, amount: 500u128 }
+		);
+		assert_eq!(
+			get_storage_value::<ChainLimits<Balance, u64>>(
+				b"BridgeNativeTransfer",
+				b"ChainTransferLimits",
+				&key
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v6_should_store_modes_and_count_unsettled_transfers() {
+	let to = hex::decode("2e8688827CCb7B015552a9817ca3E9E3a08Ae596").unwrap();
+	let foreign_asset = hex::decode("55d398326f99059fF775485246999027B3197955").unwrap();
+	ExtBuilder::default().build().execute_with(|| {
+		let chain: ChainId = 1;
+		let asset = TokenSymbol::Token(TokenInfo { id: 0 });
+		assert_ok!(BridgeNativeTransfer::force_register_chain(Origin::root(), chain));
+		assert_ok!(BridgeNativeTransfer::force_register_asset(
+			Origin::root(),
+			chain,
+			foreign_asset,
+			0,
+			AssetBridgeMode::MintBurn
+		));
+		// Outbound transfers of the native currency and of the token, before modes were stored
+		put_storage_value(
+			b"BridgeNativeTransfer",
+			b"OutboundTransfers",
+			&Blake2_128Concat::hash(&0u128.encode()),
+			(ALICE, to.clone(), 1_000u128, chain, 20u128, TokenSymbol::Native),
+		);
+		put_storage_value(
+			b"BridgeNativeTransfer",
+			b"OutboundTransfers",
+			&Blake2_128Concat::hash(&1u128.encode()),
+			(ALICE, to.clone(), 1_000u128, chain, 20u128, asset),
+		);
+		put_storage_value(
+			b"BridgeNativeTransfer",
+			b"PendingReleases",
+			&Blake2_128Concat::hash(&0u128.encode()),
+			(chain, to, BOB, 5_000u128, asset),
+		);
+
+		crate::migrations::v6::migrate_to_v6::<Runtime>();
+		assert_eq!(BridgeNativeTransfer::outbound_transfers(0).unwrap().mode, None);
+		assert_eq!(
+			BridgeNativeTransfer::outbound_transfers(1).unwrap().mode,
+			Some(AssetBridgeMode::MintBurn)
+		);
+		assert_eq!(
+			BridgeNativeTransfer::pending_releases(0).unwrap().mode,
+			Some(AssetBridgeMode::MintBurn)
+		);
+		assert_eq!(BridgeNativeTransfer::unsettled_transfers(chain, 0), 2);
+	});
+}
+
 #[test]
 fn force_freeze_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -20,6 +20,12 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2021-12-07, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("cygnus-dev"), DB CACHE: 128
+//!
+//! NOTE: the weights of `initiate_transfer`, `confirm_transfer`, `release_tokens`,
+//! `force_register_relayer`, `force_unregister_relayer`, `force_set_relayer_threshold`,
+//! `force_set_chain_limits`, `force_approve_release`, `force_reject_release`,
+//! `force_register_asset`, `force_unregister_asset`, `force_withdraw` are placeholder estimates
+//! written by hand, not benchmark output. Regenerate this file with the command below.
 
 // Executed Command:
 
@@ -58,9 +64,9 @@ pub trait WeightInfo {
 	
 	fn initiate_transfer(s: u32, ) -> Weight;
 	
-	fn confirm_transfer(s: u32, ) -> Weight;
+	fn confirm_transfer(r: u32, ) -> Weight;
 	
-	fn release_tokens(s: u32, ) -> Weight;
+	fn release_tokens(r: u32, ) -> Weight;
 	
 	fn force_register_relayer() -> Weight;
 	
@@ -80,6 +86,18 @@ pub trait WeightInfo {
 	
 	fn force_unfreeze() -> Weight;
 	
+	fn force_set_relayer_threshold() -> Weight;
+	
+	fn force_set_chain_limits() -> Weight;
+	
+	fn force_approve_release() -> Weight;
+	
+	fn force_reject_release() -> Weight;
+	
+	fn force_register_asset() -> Weight;
+	
+	fn force_unregister_asset() -> Weight;
+	
 }
 
 /// Weights for bholdus_bridge_native_transfer using the Substrate node and recommended hardware.
@@ -93,25 +111,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: BridgeNativeTransfer RegisteredChains (r:1 w:0)
 	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer ChainTransferLimits (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer OutboundVolume (r:1 w:1)
+	
 	// Storage: BridgeNativeTransfer ServiceFee (r:1 w:0)
 	
 	// Storage: BridgeNativeTransfer PlatformFee (r:1 w:0)
 	
 	// Storage: System Account (r:1 w:1)
 	
+	// Storage: BridgeNativeTransfer ForeignAssets (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
 	// Storage: BridgeNativeTransfer NextOutboundTransferId (r:1 w:1)
 	
 	// Storage: BridgeNativeTransfer OutboundTransfers (r:0 w:1)
 	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
 	fn initiate_transfer(_s: u32, ) -> Weight {
-		(62_247_000 as Weight)
+		(84_962_000 as Weight)
 			
 			
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 			
 			
 	}
@@ -125,17 +157,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: BridgeNativeTransfer NextOutboundTransferId (r:1 w:0)
 	
+	// Storage: BridgeNativeTransfer OutboundTransferStatuses (r:2 w:1)
+	
 	// Storage: BridgeNativeTransfer OutboundTransfers (r:1 w:0)
 	
-	fn confirm_transfer(_s: u32, ) -> Weight {
-		(17_715_000 as Weight)
+	// Storage: BridgeNativeTransfer OutboundTransferVotes (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerThreshold (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
+	fn confirm_transfer(r: u32, ) -> Weight {
+		(74_518_000 as Weight)
 			
+			.saturating_add((3_061_000 as Weight).saturating_mul(r as Weight))
 			
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			
+			
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 			
 			
 	}
@@ -145,47 +195,77 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: BridgeNativeTransfer RegisteredRelayers (r:1 w:0)
 	
+	// Storage: BridgeNativeTransfer RegisteredChains (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer InboundTransferResults (r:2 w:1)
+	
 	// Storage: BridgeNativeTransfer NextInboundTransferId (r:1 w:1)
 	
-	// Storage: System Account (r:1 w:1)
+	// Storage: BridgeNativeTransfer InboundTransferVotes (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerThreshold (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer ChainTransferLimits (r:1 w:0)
 	
-	fn release_tokens(_s: u32, ) -> Weight {
-		(44_435_000 as Weight)
+	// Storage: BridgeNativeTransfer InboundVolume (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer InboundDailyVolume (r:1 w:1)
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	fn release_tokens(r: u32, ) -> Weight {
+		(91_847_000 as Weight)
 			
+			.saturating_add((3_175_000 as Weight).saturating_mul(r as Weight))
 			
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			
+			
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 			
 			
 	}
 	
 	
-	// Storage: BridgeNativeTransfer RegisteredRelayers (r:0 w:1)
+	// Storage: BridgeNativeTransfer RegisteredRelayers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerCount (r:1 w:1)
 	
 	fn force_register_relayer() -> Weight {
-		(2_867_000 as Weight)
+		(5_718_000 as Weight)
 			
 			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			
 			
 	}
 	
 	
-	// Storage: BridgeNativeTransfer RegisteredRelayers (r:0 w:1)
+	// Storage: BridgeNativeTransfer RegisteredRelayers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerCount (r:1 w:1)
 	
 	fn force_unregister_relayer() -> Weight {
-		(2_660_000 as Weight)
+		(5_391_000 as Weight)
 			
 			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			
 			
 	}
@@ -249,14 +329,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: System Account (r:1 w:0)
 	
+	// Storage: Tokens Account (r:2 w:2)
+	
 	fn force_withdraw() -> Weight {
-		(4_813_000 as Weight)
+		(19_500_000 as Weight)
 			
 			
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			
 			
 			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
 			
 	}
 	
@@ -288,6 +372,122 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			
 	}
 	
+	
+	// Storage: BridgeNativeTransfer RelayerCount (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer RelayerThreshold (r:0 w:1)
+	
+	fn force_set_relayer_threshold() -> Weight {
+		(3_986_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer ChainTransferLimits (r:0 w:1)
+	
+	fn force_set_chain_limits() -> Weight {
+		(3_127_000 as Weight)
+			
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer PendingReleases (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer InboundTransferResults (r:0 w:1)
+	
+	fn force_approve_release() -> Weight {
+		(64_203_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer PendingReleases (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer InboundTransferResults (r:0 w:1)
+	
+	fn force_reject_release() -> Weight {
+		(5_907_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer RegisteredChains (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer ForeignAssets (r:1 w:1)
+	
+	fn force_register_asset() -> Weight {
+		(19_846_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer ForeignAssets (r:0 w:1)
+	
+	fn force_unregister_asset() -> Weight {
+		(15_072_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
 }
 
 // For backwards compatibility and tests
@@ -298,25 +498,39 @@ impl WeightInfo for () {
 	
 	// Storage: BridgeNativeTransfer RegisteredChains (r:1 w:0)
 	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer ChainTransferLimits (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer OutboundVolume (r:1 w:1)
+	
 	// Storage: BridgeNativeTransfer ServiceFee (r:1 w:0)
 	
 	// Storage: BridgeNativeTransfer PlatformFee (r:1 w:0)
 	
 	// Storage: System Account (r:1 w:1)
 	
+	// Storage: BridgeNativeTransfer ForeignAssets (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
 	// Storage: BridgeNativeTransfer NextOutboundTransferId (r:1 w:1)
 	
 	// Storage: BridgeNativeTransfer OutboundTransfers (r:0 w:1)
 	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
 	fn initiate_transfer(_s: u32, ) -> Weight {
-		(62_247_000 as Weight)
+		(84_962_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 			
 			
 	}
@@ -330,17 +544,35 @@ impl WeightInfo for () {
 	
 	// Storage: BridgeNativeTransfer NextOutboundTransferId (r:1 w:0)
 	
+	// Storage: BridgeNativeTransfer OutboundTransferStatuses (r:2 w:1)
+	
 	// Storage: BridgeNativeTransfer OutboundTransfers (r:1 w:0)
 	
-	fn confirm_transfer(_s: u32, ) -> Weight {
-		(17_715_000 as Weight)
+	// Storage: BridgeNativeTransfer OutboundTransferVotes (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerThreshold (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
+	fn confirm_transfer(r: u32, ) -> Weight {
+		(74_518_000 as Weight)
 			
+			.saturating_add((3_061_000 as Weight).saturating_mul(r as Weight))
 			
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 			
 			
 	}
@@ -350,47 +582,77 @@ impl WeightInfo for () {
 	
 	// Storage: BridgeNativeTransfer RegisteredRelayers (r:1 w:0)
 	
+	// Storage: BridgeNativeTransfer RegisteredChains (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer InboundTransferResults (r:2 w:1)
+	
 	// Storage: BridgeNativeTransfer NextInboundTransferId (r:1 w:1)
 	
-	// Storage: System Account (r:1 w:1)
+	// Storage: BridgeNativeTransfer InboundTransferVotes (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerThreshold (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer ChainTransferLimits (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer InboundVolume (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer InboundDailyVolume (r:1 w:1)
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Account (r:2 w:2)
 	
-	fn release_tokens(_s: u32, ) -> Weight {
-		(44_435_000 as Weight)
+	fn release_tokens(r: u32, ) -> Weight {
+		(91_847_000 as Weight)
 			
+			.saturating_add((3_175_000 as Weight).saturating_mul(r as Weight))
 			
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 			
 			
 	}
 	
 	
-	// Storage: BridgeNativeTransfer RegisteredRelayers (r:0 w:1)
+	// Storage: BridgeNativeTransfer RegisteredRelayers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerCount (r:1 w:1)
 	
 	fn force_register_relayer() -> Weight {
-		(2_867_000 as Weight)
+		(5_718_000 as Weight)
 			
 			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			
 			
 	}
 	
 	
-	// Storage: BridgeNativeTransfer RegisteredRelayers (r:0 w:1)
+	// Storage: BridgeNativeTransfer RegisteredRelayers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer RelayerCount (r:1 w:1)
 	
 	fn force_unregister_relayer() -> Weight {
-		(2_660_000 as Weight)
+		(5_391_000 as Weight)
 			
 			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			
 			
 	}
@@ -454,14 +716,18 @@ impl WeightInfo for () {
 	
 	// Storage: System Account (r:1 w:0)
 	
+	// Storage: Tokens Account (r:2 w:2)
+	
 	fn force_withdraw() -> Weight {
-		(4_813_000 as Weight)
+		(19_500_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			
 			
 			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
 			
 	}
 	
@@ -493,4 +759,120 @@ impl WeightInfo for () {
 			
 	}
 	
+	
+	// Storage: BridgeNativeTransfer RelayerCount (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer RelayerThreshold (r:0 w:1)
+	
+	fn force_set_relayer_threshold() -> Weight {
+		(3_986_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer ChainTransferLimits (r:0 w:1)
+	
+	fn force_set_chain_limits() -> Weight {
+		(3_127_000 as Weight)
+			
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer PendingReleases (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer InboundTransferResults (r:0 w:1)
+	
+	fn force_approve_release() -> Weight {
+		(64_203_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer PendingReleases (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer InboundTransferResults (r:0 w:1)
+	
+	fn force_reject_release() -> Weight {
+		(5_907_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer RegisteredChains (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer ForeignAssets (r:1 w:1)
+	
+	fn force_register_asset() -> Weight {
+		(19_846_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BridgeNativeTransfer BridgedAssets (r:1 w:1)
+	
+	// Storage: BridgeNativeTransfer UnsettledTransfers (r:1 w:0)
+	
+	// Storage: BridgeNativeTransfer ForeignAssets (r:0 w:1)
+	
+	fn force_unregister_asset() -> Weight {
+		(15_072_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
 }
\ No newline at end of file
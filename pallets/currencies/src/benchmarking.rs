@@ -0,0 +1,65 @@
+//! Currencies pallet benchmarking.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::Pallet as Currencies;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+/// Keep clear of the currencies created by the genesis config.
+const FIRST_CURRENCY_ID: u32 = 1_000;
+
+fn create_funded_currency<T>(id: u32, who: &T::AccountId) -> CurrencyIdOf<T>
+where
+	T: Config + bholdus_tokens::Config<AssetId = CurrencyIdOf<T>, Balance = BalanceOf<T>>,
+{
+	let currency_id: CurrencyIdOf<T> = (FIRST_CURRENCY_ID + id).into();
+	assert!(bholdus_tokens::Pallet::<T>::force_create(
+		<T as bholdus_tokens::Config>::ForceOrigin::successful_origin(),
+		currency_id,
+		T::Lookup::unlookup(who.clone()),
+		true,
+		1u32.into(),
+	)
+	.is_ok());
+	assert!(<Currencies<T> as MultiCurrency<T::AccountId>>::deposit(
+		currency_id,
+		who,
+		1_000u32.into()
+	)
+	.is_ok());
+	currency_id
+}
+
+benchmarks! {
+	where_clause {
+		where T: bholdus_tokens::Config<AssetId = CurrencyIdOf<T>, Balance = BalanceOf<T>>
+	}
+
+	transfer_all {
+		let c in 1 .. T::MaxTransferAllCurrencies::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount: BalanceOf<T> = 1_000u32.into();
+		let currency_ids = (0..c)
+			.map(|id| create_funded_currency::<T>(id, &caller))
+			.collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(to.clone()), currency_ids.clone(), false)
+	verify {
+		for currency_id in currency_ids {
+			assert_eq!(
+				<Currencies<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, &to),
+				amount
+			);
+		}
+	}
+}
+
+impl_benchmark_test_suite!(
+	Currencies,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Runtime
+);
@@ -9,6 +9,7 @@ use frame_support::{
 		LockableCurrency as PalletLockableCurrency, ReservableCurrency as PalletReservableCurrency,
 		WithdrawReasons,
 	},
+	transactional,
 };
 
 use bholdus_support::{
@@ -26,7 +27,9 @@ use sp_runtime::{
 use sp_std::{
 	convert::{TryFrom, TryInto},
 	fmt::Debug,
-	marker, result,
+	marker,
+	prelude::*,
+	result,
 };
 
 #[cfg(test)]
@@ -34,6 +37,8 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 mod weights;
 
 pub use pallet::*;
@@ -57,7 +62,7 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		type MultiCurrency: TransferAll<Self::AccountId>
+		type MultiCurrency: TransferAll<Self::AccountId, CurrencyIdOf<Self>>
 			+ MultiCurrencyExtended<Self::AccountId>
 			+ MultiLockableCurrency<Self::AccountId>
 			+ MultiReservableCurrency<Self::AccountId>;
@@ -72,6 +77,10 @@ pub mod pallet {
 		// #[pallet::constant]
 		// type GetNativeCurrencyId: Get<CurrencyIdOf<Self>>;
 
+		/// The maximum number of currencies that can be moved by a single `transfer_all`.
+		#[pallet::constant]
+		type MaxTransferAllCurrencies: Get<u32>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -82,6 +91,8 @@ pub mod pallet {
 		AmountIntoBalanceFailed,
 		/// Balance is too low.
 		BalanceTooLow,
+		/// Too many currencies were given to `transfer_all`.
+		TooManyCurrencies,
 	}
 
 	#[pallet::event]
@@ -95,6 +106,9 @@ pub mod pallet {
 		Deposited(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 		/// Withdraw success. \[currency_id, who, amount\]
 		Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// All transferable balances of the given currencies were moved. \[from, to,
+		/// currency_ids\]
+		TransferredAll(T::AccountId, T::AccountId, Vec<CurrencyIdOf<T>>),
 	}
 
 	#[pallet::pallet]
@@ -123,6 +137,36 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Transfer all transferable balances of the given currencies to another account.
+		///
+		/// Reserved, locked and frozen balances are left with the sender.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		///
+		/// - `currency_ids`: The currencies to move, at most `MaxTransferAllCurrencies`.
+		/// - `keep_alive`: Leave the minimum balance of each currency with the sender.
+		#[pallet::weight(T::WeightInfo::transfer_all(currency_ids.len() as u32))]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_ids: Vec<CurrencyIdOf<T>>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			ensure!(
+				currency_ids.len() as u32 <= T::MaxTransferAllCurrencies::get(),
+				Error::<T>::TooManyCurrencies
+			);
+			<Self as TransferAll<T::AccountId, CurrencyIdOf<T>>>::transfer_all(
+				&from,
+				&to,
+				&currency_ids,
+				keep_alive,
+			)
+		}
+
 		/* /// Transfer some native currency to another account.
 		///
 		/// The dispatch origin for this call must be `Signed` by the
@@ -732,15 +776,23 @@ where
 	}
 }
 
-impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
-	fn transfer_all(_source: &T::AccountId, _dest: &T::AccountId) -> DispatchResult {
-		// with_transaction_result(|| {
-		//     // transfer non-native free to dest
-		//     T::MultiCurrency::transfer_all(source, dest)?;
-
-		//     // transfer all free to dest
-		//     T::NativeCurrency::transfer(source, dest, T::NativeCurrency::free_balance(source))
-		// })
+impl<T: Config> TransferAll<T::AccountId, CurrencyIdOf<T>> for Pallet<T> {
+	#[transactional]
+	fn transfer_all(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		currency_ids: &[CurrencyIdOf<T>],
+		keep_alive: bool,
+	) -> DispatchResult {
+		if source == dest {
+			return Ok(());
+		}
+		T::MultiCurrency::transfer_all(source, dest, currency_ids, keep_alive)?;
+		Self::deposit_event(Event::TransferredAll(
+			source.clone(),
+			dest.clone(),
+			currency_ids.to_vec(),
+		));
 		Ok(())
 	}
 }
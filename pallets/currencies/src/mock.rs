@@ -112,15 +112,21 @@ impl bholdus_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type MaxDecimals = MaxDecimals;
+	type MaxLocks = MaxLocks;
 }
 
 // parameter_types! {
 //     pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
 // }
 
+parameter_types! {
+	pub const MaxTransferAllCurrencies: u32 = 10;
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = BholdusTokens;
+	type MaxTransferAllCurrencies = MaxTransferAllCurrencies;
 	// type NativeCurrency = AdaptedBasicCurrency;
 	// type GetNativeCurrencyId = GetNativeCurrencyId;
 	type WeightInfo = ();
@@ -86,3 +86,45 @@ fn native_currency_should_work() {
 		assert_eq!(Currencies::free_balance(0, &ALICE), 50);
 	});
 } */
+
+#[test]
+fn transfer_all_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Currencies::set_lock(ID_1, NATIVE_CURRENCY_ID, &ALICE, 30));
+		assert_ok!(BholdusTokens::freeze(Origin::signed(ALICE), X_TOKEN_ID, ALICE));
+
+		assert_ok!(Currencies::transfer_all(
+			Some(ALICE).into(),
+			EVA,
+			vec![NATIVE_CURRENCY_ID, TOKEN_ID, X_TOKEN_ID],
+			false
+		));
+		System::assert_last_event(Event::Currencies(crate::Event::TransferredAll(
+			ALICE.clone(),
+			EVA.clone(),
+			vec![NATIVE_CURRENCY_ID, TOKEN_ID, X_TOKEN_ID],
+		)));
+
+		assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &ALICE), 30);
+		assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &EVA), 70);
+		assert_eq!(Currencies::free_balance(X_TOKEN_ID, &ALICE), 100);
+		assert_eq!(Currencies::free_balance(X_TOKEN_ID, &EVA), 0);
+		assert_eq!(Currencies::free_balance(TOKEN_ID, &BOB), 100);
+	});
+}
+
+#[test]
+fn transfer_all_should_fail_with_too_many_currencies() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Currencies::transfer_all(
+				Some(ALICE).into(),
+				EVA,
+				vec![NATIVE_CURRENCY_ID; MaxTransferAllCurrencies::get() as usize + 1],
+				false
+			),
+			Error::<Runtime>::TooManyCurrencies
+		);
+	});
+}
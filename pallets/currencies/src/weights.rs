@@ -13,6 +13,7 @@ pub trait WeightInfo {
 	fn update_balance_non_native_currency() -> Weight;
 	fn update_balance_native_currency_creating() -> Weight;
 	fn update_balance_native_currency_killing() -> Weight;
+	fn transfer_all(c: u32, ) -> Weight;
 }
 
 /// Default weights.
@@ -42,4 +43,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Placeholder estimate written by hand, not benchmark output
+	fn transfer_all(c: u32, ) -> Weight {
+		(14_712_000 as Weight)
+			.saturating_add((38_452_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
 }
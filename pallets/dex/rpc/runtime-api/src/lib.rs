@@ -0,0 +1,61 @@
+//! Runtime API definition for the DEX pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use common_primitives::{
+	Balance, BlockNumber, CurrencyId, ExchangeRate, TradingPair, TradingPairStatus,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait DexApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Returns reserves of two currencies, in the order of the given currencies.
+		fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance);
+
+		/// Returns the amounts received along `path` when supplying `supply_amount`
+		/// of its first currency.
+		fn get_target_amounts(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Result<Vec<Balance>, DispatchError>;
+
+		/// Returns the amounts needed along `path` to receive `target_amount`
+		/// of its last currency.
+		fn get_supply_amounts(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Result<Vec<Balance>, DispatchError>;
+
+		/// Returns the status of the trading pair made of two currencies.
+		fn get_trading_pair_status(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> TradingPairStatus<Balance>;
+
+		/// Returns the provision of `who` to a provisioning trading pair,
+		/// in the order of the given currencies.
+		fn get_provision(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			who: AccountId,
+		) -> (Balance, Balance);
+
+		/// Returns all trading pairs in `Enabled` status.
+		fn get_enabled_trading_pairs() -> Vec<TradingPair>;
+
+		/// Returns the maximum length of a trading path.
+		fn get_trading_path_limit() -> u32;
+
+		/// Returns the time-weighted average price of `currency_id_a` in `currency_id_b` over
+		/// the last `window` blocks.
+		fn get_twap(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: BlockNumber,
+		) -> Option<ExchangeRate>;
+	}
+}
@@ -0,0 +1,401 @@
+//! RPC interface for the DEX pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use common_primitives::{
+	Balance, BlockNumber, CurrencyId, ExchangeRate, SwapLimit, TradingPair, TradingPairStatus,
+};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use bholdus_dex_rpc_runtime_api::DexApi as DexRuntimeApi;
+
+#[rpc(client, server)]
+pub trait DexApi<BlockHash, AccountId> {
+	/// Returns reserves of two currencies, in the order of the given currencies.
+	#[method(name = "dex_getLiquidity")]
+	fn get_liquidity(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(NumberOrHex, NumberOrHex)>;
+
+	/// Returns the amounts received along `path` when supplying `supply_amount`
+	/// of its first currency.
+	#[method(name = "dex_getTargetAmounts")]
+	fn get_target_amounts(
+		&self,
+		path: Vec<CurrencyId>,
+		supply_amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<NumberOrHex>>;
+
+	/// Returns the amounts needed along `path` to receive `target_amount`
+	/// of its last currency.
+	#[method(name = "dex_getSupplyAmounts")]
+	fn get_supply_amounts(
+		&self,
+		path: Vec<CurrencyId>,
+		target_amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<NumberOrHex>>;
+
+	/// Returns the status of the trading pair made of two currencies.
+	#[method(name = "dex_getTradingPairStatus")]
+	fn get_trading_pair_status(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradingPairStatus<Balance>>;
+
+	/// Returns the provision of `who` to a provisioning trading pair,
+	/// in the order of the given currencies.
+	#[method(name = "dex_getProvision")]
+	fn get_provision(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(NumberOrHex, NumberOrHex)>;
+
+	/// Returns the trading path with the best price for swapping `supply_currency_id` to
+	/// `target_currency_id` within `limit`, along with its supply and target amounts.
+	///
+	/// Paths are searched here, off-chain, among the enabled trading pairs. The result can be
+	/// passed as is to `swap_with_specific_path`.
+	#[method(name = "dex_getBestPriceSwapPath")]
+	fn get_best_price_swap_path(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<NumberOrHex>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Vec<CurrencyId>, NumberOrHex, NumberOrHex)>>;
+
+	/// Returns the time-weighted average price of `currency_id_a` in `currency_id_b` over the
+	/// last `window` blocks.
+	#[method(name = "dex_getTwap")]
+	fn get_twap(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ExchangeRate>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The quote could not be computed for the given path.
+	QuoteError,
+	/// The given amount is not a valid balance.
+	InvalidAmount,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+			Error::InvalidAmount => 3,
+		}
+	}
+}
+
+/// Provides RPC methods to query the DEX.
+pub struct Dex<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Dex<C, Block> {
+	/// Creates a new instance of the DEX RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the dex.",
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+fn to_balance(amount: NumberOrHex) -> RpcResult<Balance> {
+	amount.try_into().map_err(|_| {
+		CallError::Custom(ErrorObject::owned(
+			Error::InvalidAmount.into(),
+			"Amount doesn't fit in Balance.",
+			None::<()>,
+		))
+		.into()
+	})
+}
+
+fn to_amounts(
+	amounts: Result<Vec<Balance>, sp_runtime::DispatchError>,
+) -> RpcResult<Vec<NumberOrHex>> {
+	amounts
+		.map(|amounts| amounts.into_iter().map(Into::into).collect())
+		.map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::QuoteError.into(),
+				"Unable to quote the given path.",
+				Some(format!("{:?}", e)),
+			))
+			.into()
+		})
+}
+
+/// Returns all trading paths from `supply_currency_id` to `target_currency_id` made of
+/// `trading_pairs` and not longer than `path_limit`, shortest first.
+fn trading_paths(
+	trading_pairs: &[TradingPair],
+	supply_currency_id: CurrencyId,
+	target_currency_id: CurrencyId,
+	path_limit: usize,
+) -> Vec<Vec<CurrencyId>> {
+	let mut paths = vec![];
+	if supply_currency_id != target_currency_id {
+		let mut path = vec![supply_currency_id];
+		collect_trading_paths(trading_pairs, target_currency_id, path_limit, &mut path, &mut paths);
+		paths.sort_by_key(|path| path.len());
+	}
+	paths
+}
+
+/// Depth-first search of trading paths extending `path` towards `target_currency_id`.
+fn collect_trading_paths(
+	trading_pairs: &[TradingPair],
+	target_currency_id: CurrencyId,
+	path_limit: usize,
+	path: &mut Vec<CurrencyId>,
+	paths: &mut Vec<Vec<CurrencyId>>,
+) {
+	let last_currency_id = path[path.len() - 1];
+	if last_currency_id == target_currency_id {
+		paths.push(path.clone());
+		return;
+	}
+	if path.len() >= path_limit {
+		return;
+	}
+
+	for trading_pair in trading_pairs {
+		let next_currency_id = if trading_pair.first() == last_currency_id {
+			trading_pair.second()
+		} else if trading_pair.second() == last_currency_id {
+			trading_pair.first()
+		} else {
+			continue;
+		};
+		if path.contains(&next_currency_id) {
+			continue;
+		}
+
+		path.push(next_currency_id);
+		collect_trading_paths(trading_pairs, target_currency_id, path_limit, path, paths);
+		path.pop();
+	}
+}
+
+impl<C, Block, AccountId> DexApiServer<<Block as BlockT>::Hash, AccountId> for Dex<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DexRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Send + Sync + 'static,
+{
+	fn get_liquidity(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(NumberOrHex, NumberOrHex)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let (pool_a, pool_b) =
+			api.get_liquidity(&at, currency_id_a, currency_id_b).map_err(runtime_error)?;
+		Ok((pool_a.into(), pool_b.into()))
+	}
+
+	fn get_target_amounts(
+		&self,
+		path: Vec<CurrencyId>,
+		supply_amount: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let supply_amount = to_balance(supply_amount)?;
+
+		to_amounts(api.get_target_amounts(&at, path, supply_amount).map_err(runtime_error)?)
+	}
+
+	fn get_supply_amounts(
+		&self,
+		path: Vec<CurrencyId>,
+		target_amount: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let target_amount = to_balance(target_amount)?;
+
+		to_amounts(api.get_supply_amounts(&at, path, target_amount).map_err(runtime_error)?)
+	}
+
+	fn get_trading_pair_status(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TradingPairStatus<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.get_trading_pair_status(&at, currency_id_a, currency_id_b)
+			.map_err(runtime_error)
+	}
+
+	fn get_provision(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(NumberOrHex, NumberOrHex)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let (provision_a, provision_b) = api
+			.get_provision(&at, currency_id_a, currency_id_b, who)
+			.map_err(runtime_error)?;
+		Ok((provision_a.into(), provision_b.into()))
+	}
+
+	fn get_best_price_swap_path(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<NumberOrHex>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<(Vec<CurrencyId>, NumberOrHex, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let limit = match limit {
+			SwapLimit::ExactSupply(supply_amount, min_target_amount) =>
+				SwapLimit::ExactSupply(to_balance(supply_amount)?, to_balance(min_target_amount)?),
+			SwapLimit::ExactTarget(max_supply_amount, target_amount) =>
+				SwapLimit::ExactTarget(to_balance(max_supply_amount)?, to_balance(target_amount)?),
+		};
+
+		let trading_pairs = api.get_enabled_trading_pairs(&at).map_err(runtime_error)?;
+		let path_limit = api.get_trading_path_limit(&at).map_err(runtime_error)?;
+		let paths = trading_paths(
+			&trading_pairs,
+			supply_currency_id,
+			target_currency_id,
+			path_limit as usize,
+		);
+
+		// With `ExactSupply` the path giving the largest target amount wins, with `ExactTarget`
+		// the path asking the smallest supply amount wins. Ties go to the shorter path.
+		let mut best: Option<(Vec<CurrencyId>, Balance, Balance)> = None;
+		for path in paths {
+			match limit {
+				SwapLimit::ExactSupply(supply_amount, min_target_amount) => {
+					let amounts = api
+						.get_target_amounts(&at, path.clone(), supply_amount)
+						.map_err(runtime_error)?;
+					if let Ok(amounts) = amounts {
+						let target_amount = amounts[amounts.len() - 1];
+						let is_better = best.as_ref().map_or(true, |(_, _, best_target_amount)| {
+							target_amount > *best_target_amount
+						});
+						if target_amount >= min_target_amount && is_better {
+							best = Some((path, supply_amount, target_amount));
+						}
+					}
+				},
+				SwapLimit::ExactTarget(max_supply_amount, target_amount) => {
+					let amounts = api
+						.get_supply_amounts(&at, path.clone(), target_amount)
+						.map_err(runtime_error)?;
+					if let Ok(amounts) = amounts {
+						let supply_amount = amounts[0];
+						let is_better = best.as_ref().map_or(true, |(_, best_supply_amount, _)| {
+							supply_amount < *best_supply_amount
+						});
+						if supply_amount <= max_supply_amount && is_better {
+							best = Some((path, supply_amount, target_amount));
+						}
+					}
+				},
+			}
+		}
+
+		Ok(best.map(|(path, supply_amount, target_amount)| {
+			(path, supply_amount.into(), target_amount.into())
+		}))
+	}
+
+	fn get_twap(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ExchangeRate>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.get_twap(&at, currency_id_a, currency_id_b, window).map_err(runtime_error)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common_primitives::{TokenInfo, TokenSymbol};
+
+	const BHO: CurrencyId = CurrencyId::Token(TokenSymbol::Native);
+	const BNB: CurrencyId = CurrencyId::Token(TokenSymbol::Token(TokenInfo { id: 1 }));
+	const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::Token(TokenInfo { id: 2 }));
+	const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::Token(TokenInfo { id: 3 }));
+
+	#[test]
+	fn trading_paths_should_work() {
+		let trading_pairs = vec![
+			TradingPair::from_currency_ids(BHO, BNB).unwrap(),
+			TradingPair::from_currency_ids(BNB, DOT).unwrap(),
+			TradingPair::from_currency_ids(BHO, DOT).unwrap(),
+		];
+
+		assert_eq!(trading_paths(&[], BHO, DOT, 3), Vec::<Vec<CurrencyId>>::new());
+		assert_eq!(
+			trading_paths(&trading_pairs, BHO, DOT, 3),
+			vec![vec![BHO, DOT], vec![BHO, BNB, DOT]]
+		);
+		assert_eq!(trading_paths(&trading_pairs, BHO, DOT, 2), vec![vec![BHO, DOT]]);
+		assert_eq!(trading_paths(&trading_pairs, BHO, BHO, 3), Vec::<Vec<CurrencyId>>::new());
+		assert_eq!(trading_paths(&trading_pairs, BHO, BTC, 3), Vec::<Vec<CurrencyId>>::new());
+	}
+}
@@ -1,20 +1,343 @@
-//! Benchmarking setup for pallet-template
+//! DEX pallet benchmarking.
 
 use super::*;
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use crate::Pallet as Dex;
+use common_primitives::{TokenInfo, TokenSymbol};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_system::RawOrigin;
+use sp_core::U256;
+use sp_runtime::traits::StaticLookup;
+
+const SEED: u32 = 0;
+const UNIT: Balance = 1_000_000_000_000_000_000;
+/// Keep clear of the tokens created by the genesis config.
+const FIRST_TOKEN_ID: TokenId = 1_000;
+const BHO: CurrencyId = CurrencyId::Token(TokenSymbol::Native);
+
+fn create_token<T>(index: u32) -> CurrencyId
+where
+	T: Config + bholdus_tokens::Config<AssetId = TokenId, Balance = Balance>,
+{
+	let id = FIRST_TOKEN_ID + index as TokenId;
+	let owner: T::AccountId = account("owner", 0, SEED);
+	assert!(bholdus_tokens::Pallet::<T>::force_create(
+		<T as bholdus_tokens::Config>::ForceOrigin::successful_origin(),
+		id,
+		T::Lookup::unlookup(owner),
+		true,
+		1,
+	)
+	.is_ok());
+	CurrencyId::Token(TokenSymbol::Token(TokenInfo { id }))
+}
+
+fn fund<T: Config>(who: &T::AccountId, currency_id: CurrencyId, amount: Balance) {
+	match currency_id.token_id() {
+		Some(token_id) => assert!(T::Currency::deposit(token_id, who, amount).is_ok()),
+		None => {
+			T::NativeCurrency::deposit_creating(who, amount);
+		},
+	}
+}
+
+fn enable_trading_pair_with_liquidity<T: Config>(
+	currency_id_0: CurrencyId,
+	currency_id_1: CurrencyId,
+) {
+	let maker: T::AccountId = account("maker", 0, SEED);
+	fund::<T>(&maker, currency_id_0, 1_000 * UNIT);
+	fund::<T>(&maker, currency_id_1, 1_000 * UNIT);
+	assert!(Dex::<T>::enable_trading_pair(
+		T::ListingOrigin::successful_origin(),
+		currency_id_0,
+		currency_id_1
+	)
+	.is_ok());
+	assert!(Dex::<T>::add_liquidity(
+		RawOrigin::Signed(maker).into(),
+		currency_id_0,
+		currency_id_1,
+		1_000 * UNIT,
+		1_000 * UNIT,
+	)
+	.is_ok());
+}
+
+/// Returns a trading path of `length` currencies starting from BHO, with every trading pair
+/// along it enabled.
+fn trading_path<T>(length: u32) -> Vec<CurrencyId>
+where
+	T: Config + bholdus_tokens::Config<AssetId = TokenId, Balance = Balance>,
+{
+	let mut path = vec![BHO];
+	for index in 1..length {
+		let currency_id = create_token::<T>(index);
+		enable_trading_pair_with_liquidity::<T>(path[path.len() - 1], currency_id);
+		path.push(currency_id);
+	}
+	path
+}
+
+fn start_provisioning<T: Config>(currency_id_0: CurrencyId, currency_id_1: CurrencyId) {
+	assert!(Dex::<T>::start_trading_pair_provisioning(
+		T::ListingOrigin::successful_origin(),
+		currency_id_0,
+		currency_id_1,
+		1,
+		1,
+		UNIT,
+		UNIT,
+	)
+	.is_ok());
+}
+
+fn add_provision<T: Config>(
+	who: &T::AccountId,
+	currency_id_0: CurrencyId,
+	currency_id_1: CurrencyId,
+) {
+	fund::<T>(who, currency_id_0, 10 * UNIT);
+	fund::<T>(who, currency_id_1, 10 * UNIT);
+	assert!(Dex::<T>::add_trading_pair_provision(
+		RawOrigin::Signed(who.clone()).into(),
+		currency_id_0,
+		currency_id_1,
+		UNIT,
+		UNIT,
+	)
+	.is_ok());
+}
 
 benchmarks! {
-    do_something {
-        let s in 0 .. 100;
-        let caller: T::AccountId = whitelisted_caller();
-    }: _(RawOrigin::Signed(caller), s)
-    verify {
-        assert_eq!(Something::<T>::get(), Some(s));
-    }
+	where_clause {
+		where T: bholdus_tokens::Config<AssetId = TokenId, Balance = Balance>
+	}
+
+	swap_with_exact_supply {
+		let u in 2 .. T::TradingPathLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let path = trading_path::<T>(u);
+		fund::<T>(&caller, BHO, 10 * UNIT);
+	}: _(RawOrigin::Signed(caller.clone()), path.clone(), UNIT, 0)
+	verify {
+		assert!(!Dex::<T>::free_balance(path[path.len() - 1], &caller).is_zero());
+	}
+
+	swap_with_exact_target {
+		let u in 2 .. T::TradingPathLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let path = trading_path::<T>(u);
+		fund::<T>(&caller, BHO, 10 * UNIT);
+	}: _(RawOrigin::Signed(caller.clone()), path.clone(), UNIT, 10 * UNIT)
+	verify {
+		assert_eq!(Dex::<T>::free_balance(path[path.len() - 1], &caller), UNIT);
+	}
+
+	start_trading_pair_provisioning {
+		let currency_id = create_token::<T>(1);
+	}: {
+		Dex::<T>::start_trading_pair_provisioning(
+			T::ListingOrigin::successful_origin(),
+			BHO,
+			currency_id,
+			1,
+			1,
+			UNIT,
+			UNIT,
+		)?;
+	}
+	verify {
+		let trading_pair = TradingPair::from_currency_ids(BHO, currency_id).unwrap();
+		assert!(matches!(
+			Dex::<T>::trading_pair_statuses(trading_pair),
+			TradingPairStatus::<_>::Provisioning(_)
+		));
+	}
+
+	update_trading_pair_provisioning_parameters {
+		let currency_id = create_token::<T>(1);
+		start_provisioning::<T>(BHO, currency_id);
+	}: {
+		Dex::<T>::update_trading_pair_provisioning_parameters(
+			T::ListingOrigin::successful_origin(),
+			BHO,
+			currency_id,
+			2,
+			2,
+			2 * UNIT,
+			2 * UNIT,
+		)?;
+	}
+
+	add_trading_pair_provision {
+		let caller: T::AccountId = whitelisted_caller();
+		let currency_id = create_token::<T>(1);
+		start_provisioning::<T>(BHO, currency_id);
+		fund::<T>(&caller, BHO, 10 * UNIT);
+		fund::<T>(&caller, currency_id, 10 * UNIT);
+	}: _(RawOrigin::Signed(caller.clone()), BHO, currency_id, UNIT, UNIT)
+	verify {
+		assert_eq!(Dex::<T>::get_provision(&BHO, &currency_id, &caller), (UNIT, UNIT));
+	}
+
+	enable_provisioning_trading_pair {
+		let founder: T::AccountId = account("founder", 0, SEED);
+		let currency_id = create_token::<T>(1);
+		start_provisioning::<T>(BHO, currency_id);
+		add_provision::<T>(&founder, BHO, currency_id);
+	}: {
+		Dex::<T>::enable_provisioning_trading_pair(
+			T::ListingOrigin::successful_origin(),
+			BHO,
+			currency_id,
+		)?;
+	}
+	verify {
+		assert_eq!(Dex::<T>::get_liquidity(&BHO, &currency_id), (UNIT, UNIT));
+	}
+
+	enable_trading_pair {
+		let currency_id = create_token::<T>(1);
+	}: {
+		Dex::<T>::enable_trading_pair(T::ListingOrigin::successful_origin(), BHO, currency_id)?;
+	}
+	verify {
+		assert!(matches!(
+			Dex::<T>::get_trading_pair_status(&BHO, &currency_id),
+			TradingPairStatus::<_>::Enabled
+		));
+	}
+
+	add_liquidity {
+		let caller: T::AccountId = whitelisted_caller();
+		let currency_id = create_token::<T>(1);
+		enable_trading_pair_with_liquidity::<T>(BHO, currency_id);
+		fund::<T>(&caller, BHO, 10 * UNIT);
+		fund::<T>(&caller, currency_id, 10 * UNIT);
+	}: _(RawOrigin::Signed(caller.clone()), BHO, currency_id, UNIT, UNIT)
+	verify {
+		let trading_pair = TradingPair::from_currency_ids(BHO, currency_id).unwrap();
+		assert!(!Dex::<T>::shares(trading_pair, &caller).is_zero());
+	}
+
+	remove_liquidity {
+		let caller: T::AccountId = whitelisted_caller();
+		let currency_id = create_token::<T>(1);
+		enable_trading_pair_with_liquidity::<T>(BHO, currency_id);
+		fund::<T>(&caller, BHO, 10 * UNIT);
+		fund::<T>(&caller, currency_id, 10 * UNIT);
+		Dex::<T>::add_liquidity(
+			RawOrigin::Signed(caller.clone()).into(),
+			BHO,
+			currency_id,
+			UNIT,
+			UNIT,
+		)?;
+		let trading_pair = TradingPair::from_currency_ids(BHO, currency_id).unwrap();
+		let share_amount = Dex::<T>::shares(trading_pair, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), BHO, currency_id, share_amount, 0, 0)
+	verify {
+		assert!(Dex::<T>::shares(trading_pair, &caller).is_zero());
+	}
+
+	claim_dex_share {
+		let caller: T::AccountId = whitelisted_caller();
+		let founder: T::AccountId = account("founder", 0, SEED);
+		let currency_id = create_token::<T>(1);
+		start_provisioning::<T>(BHO, currency_id);
+		add_provision::<T>(&founder, BHO, currency_id);
+		Dex::<T>::enable_provisioning_trading_pair(
+			T::ListingOrigin::successful_origin(),
+			BHO,
+			currency_id,
+		)?;
+	}: _(RawOrigin::Signed(caller), founder.clone(), BHO, currency_id)
+	verify {
+		let trading_pair = TradingPair::from_currency_ids(BHO, currency_id).unwrap();
+		assert!(!Dex::<T>::shares(trading_pair, &founder).is_zero());
+	}
+
+	set_trading_pair_fee {
+		let currency_id = create_token::<T>(1);
+		let trading_pair = TradingPair::from_currency_ids(BHO, currency_id).unwrap();
+	}: {
+		Dex::<T>::set_trading_pair_fee(
+			T::ListingOrigin::successful_origin(),
+			BHO,
+			currency_id,
+			Some((1, 100)),
+		)?;
+	}
+	verify {
+		assert_eq!(Dex::<T>::trading_pair_fees(trading_pair), Some((1, 100)));
+	}
+
+	set_protocol_fee {
+		let receiver: T::AccountId = account("receiver", 0, SEED);
+		let protocol_fee = ProtocolFee {
+			share: Permill::from_percent(10),
+			destination: ProtocolFeeDestination::MintShares(receiver),
+		};
+	}: {
+		Dex::<T>::set_protocol_fee(
+			T::ListingOrigin::successful_origin(),
+			Some(protocol_fee.clone()),
+		)?;
+	}
+	verify {
+		assert_eq!(Dex::<T>::protocol_fee(), Some(protocol_fee));
+	}
+
+	disable_trading_pair {
+		let o in 1 .. Dex::<T>::price_observation_capacity();
+		let currency_id = create_token::<T>(1);
+		enable_trading_pair_with_liquidity::<T>(BHO, currency_id);
+		let trading_pair = TradingPair::from_currency_ids(BHO, currency_id).unwrap();
+		for index in 0..o {
+			PriceObservations::<T>::insert(
+				trading_pair,
+				index,
+				(T::BlockNumber::from(index), U256::zero(), U256::zero()),
+			);
+		}
+		PriceObservationState::<T>::insert(
+			trading_pair,
+			(o % Dex::<T>::price_observation_capacity(), o),
+		);
+	}: {
+		Dex::<T>::disable_trading_pair(T::ListingOrigin::successful_origin(), BHO, currency_id)?;
+	}
+	verify {
+		assert!(Dex::<T>::cumulative_prices(trading_pair).is_none());
+		assert_eq!(Dex::<T>::price_observation_state(trading_pair), (0, 0));
+	}
+
+	pay_fee_in_token {
+		let u in 2 .. T::TradingPathLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let refund_path = trading_path::<T>(u);
+		let path: Vec<CurrencyId> = refund_path.iter().rev().cloned().collect();
+		fund::<T>(&caller, path[0], 10 * UNIT);
+		Dex::<T>::set_fee_token(&caller, Some((path.clone().try_into().unwrap(), 10 * UNIT)));
+	}: {
+		// The swap buying the fee, then the swap of the refund back into the fee token
+		let (_, fee_path, max_supply_amount) = FeeToken::<T>::take().unwrap();
+		Dex::<T>::do_swap_with_specific_path(
+			&caller,
+			&fee_path,
+			SwapLimit::ExactTarget(max_supply_amount, UNIT),
+		)?;
+		Dex::<T>::do_swap_with_specific_path(
+			&caller,
+			&refund_path,
+			SwapLimit::ExactSupply(UNIT / 2, 0),
+		)?;
+	}
+	verify {
+		assert_eq!(Dex::<T>::free_balance(BHO, &caller), UNIT / 2);
+		assert!(FeeToken::<T>::get().is_none());
+	}
 }
 
-impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test,);
+impl_benchmark_test_suite!(Dex, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime);
@@ -0,0 +1,240 @@
+//! Paying transaction fees in BHC20 tokens.
+//!
+//! `ChargeFeeToken` is a signed extension letting the signer choose a trading path from a token
+//! to native BHO to pay fees with, along with the maximum amount of the token they accept to
+//! spend. `DexOnChargeTransaction` wraps another `OnChargeTransaction` and, when a fee path is
+//! chosen, swaps just enough of the token into native BHO before the fee is withdrawn. Whatever
+//! is refunded after dispatch is swapped back into the token along the reversed path, as long as
+//! the price did not move against the signer since the fee was withdrawn. The weight of these
+//! swaps is added to the block and charged on top of the fee.
+
+use super::*;
+use common_primitives::TokenSymbol;
+use frame_support::weights::WeightToFee;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+
+const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::Native);
+
+/// Trading path from the fee token to native BHO, capped by `TradingPathLimit`.
+pub type FeePath<T> = BoundedVec<CurrencyId, <T as Config>::TradingPathLimit>;
+
+/// Chooses the token the signer pays transaction fees with.
+///
+/// `Some((path, max_supply_amount))` swaps at most `max_supply_amount` of the first currency of
+/// `path` into native BHO along `path` to pay the fee, `None` pays the fee in native BHO.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFeeToken<T: Config + Send + Sync>(
+	Option<(FeePath<T>, Balance)>,
+	sp_std::marker::PhantomData<T>,
+);
+
+impl<T: Config + Send + Sync> ChargeFeeToken<T> {
+	/// Pays fees in native BHO.
+	pub fn new() -> Self {
+		Self(None, Default::default())
+	}
+
+	/// Pays fees in the first currency of `path`, spending at most `max_supply_amount` of it.
+	pub fn from(path: FeePath<T>, max_supply_amount: Balance) -> Self {
+		Self(Some((path, max_supply_amount)), Default::default())
+	}
+
+	/// Rejects fee paths that don't end in native BHO.
+	fn check_fee_path(&self) -> Result<(), TransactionValidityError> {
+		if let Some((path, _)) = &self.0 {
+			ensure!(
+				path.len() >= 2 && path.last() == Some(&NATIVE_CURRENCY_ID),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> Default for ChargeFeeToken<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeFeeToken<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeFeeToken<{:?}>", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ChargeFeeToken<T> {
+	const IDENTIFIER: &'static str = "ChargeFeeToken";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		self.check_fee_path()?;
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.check_fee_path()?;
+		if let Some((path, _)) = &self.0 {
+			// The swaps paying the fee run outside of the call, so their weight is added to the
+			// block here and charged by `DexOnChargeTransaction`.
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::pay_fee_in_token(path.len() as u32),
+				info.class,
+			);
+		}
+		Pallet::<T>::set_fee_token(who, self.0);
+		Ok(())
+	}
+}
+
+/// Fee charged for swapping along a fee path of `path_len` currencies, on top of the fee of the
+/// call.
+fn swap_weight_fee<T>(path_len: usize) -> Balance
+where
+	T: Config + pallet_transaction_payment::Config,
+{
+	let fee = <T as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+		&T::WeightInfo::pay_fee_in_token(path_len as u32),
+	);
+	pallet_transaction_payment::Pallet::<T>::next_fee_multiplier()
+		.saturating_mul_int(fee)
+		.saturated_into()
+}
+
+/// Pays transaction fees with the token chosen through `ChargeFeeToken`, swapping it into native
+/// BHO on the DEX, then lets `OCT` charge the fee in native BHO.
+///
+/// Must be used together with `ChargeFeeToken` placed before `ChargeTransactionPayment` in the
+/// runtime's signed extensions.
+pub struct DexOnChargeTransaction<OCT>(sp_std::marker::PhantomData<OCT>);
+
+impl<T, OCT> OnChargeTransaction<T> for DexOnChargeTransaction<OCT>
+where
+	T: Config + pallet_transaction_payment::Config,
+	OCT: OnChargeTransaction<T, Balance = Balance>,
+{
+	/// What `OCT` withdrew, the fee charged for the swaps, along with the quote of swapping the
+	/// native amount bought for the fee back into the fee token. \[native_amount, target_amount\]
+	type LiquidityInfo = (OCT::LiquidityInfo, Balance, Option<(Balance, Balance)>);
+	type Balance = OCT::Balance;
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::Call,
+		dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let mut swap_fee = Zero::zero();
+		let mut refund_quote = None;
+		if let Some((path, max_supply_amount)) = Pallet::<T>::fee_token_of(who) {
+			if !fee.is_zero() {
+				swap_fee = swap_weight_fee::<T>(path.len());
+				// Also top up the native balance to the existential deposit, otherwise the fee
+				// can't be withdrawn from an account holding tokens only.
+				let native_amount = fee.saturating_add(swap_fee).saturating_add(
+					T::NativeCurrency::minimum_balance()
+						.saturating_sub(T::NativeCurrency::free_balance(who)),
+				);
+				Pallet::<T>::do_swap_with_specific_path(
+					who,
+					&path,
+					SwapLimit::ExactTarget(max_supply_amount, native_amount),
+				)
+				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+
+				let refund_path: Vec<CurrencyId> = path.into_iter().rev().collect();
+				refund_quote = Pallet::<T>::get_target_amounts(&refund_path, native_amount)
+					.ok()
+					.map(|amounts| (native_amount, amounts[amounts.len() - 1]));
+			}
+		}
+
+		Ok((
+			OCT::withdraw_fee(who, call, dispatch_info, fee.saturating_add(swap_fee), tip)?,
+			swap_fee,
+			refund_quote,
+		))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::Call>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let fee_token = FeeToken::<T>::take();
+		let native_balance_before = T::NativeCurrency::free_balance(who);
+		let (already_withdrawn, swap_fee, refund_quote) = already_withdrawn;
+
+		OCT::correct_and_deposit_fee(
+			who,
+			dispatch_info,
+			post_info,
+			corrected_fee.saturating_add(swap_fee),
+			tip,
+			already_withdrawn,
+		)?;
+
+		if let (Some((account_id, path, _)), Some((native_amount, target_amount))) =
+			(fee_token, refund_quote)
+		{
+			if account_id == *who {
+				let refund =
+					T::NativeCurrency::free_balance(who).saturating_sub(native_balance_before);
+				if !refund.is_zero() {
+					// Swap outputs are concave in the supply amount, so swapping back part of
+					// `native_amount` gets at least the same share of `target_amount`, unless the
+					// call moved the price in between. The refund then stays in native BHO, as it
+					// does when it is too small to be swapped back.
+					let min_target_amount = U256::from(target_amount)
+						.saturating_mul(U256::from(refund))
+						.checked_div(U256::from(native_amount))
+						.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+						.unwrap_or(target_amount);
+					let refund_path: Vec<CurrencyId> = path.into_iter().rev().collect();
+					let _ = Pallet::<T>::do_swap_with_specific_path(
+						who,
+						&refund_path,
+						SwapLimit::ExactSupply(refund, min_target_amount),
+					);
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -1,137 +1,164 @@
+//! Mocks for the dex module.
+
 use crate as dex;
-use bholdus_currencies::{BasicCurrencyAdapter, NativeCurrencyOf};
-use common_primitives::{CurrencyId, TokenInfo, TokenSymbol, TradingPair};
 use bholdus_support::parameter_type_with_key;
+use common_primitives::{CurrencyId, TokenId, TokenInfo, TokenSymbol, TradingPair};
 use dex::*;
-use frame_support::{parameter_types, PalletId};
+use frame_support::{
+	parameter_types,
+	weights::{IdentityFee, Weight},
+	PalletId,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-    testing::Header,
-    traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup, Zero},
 };
 use system::EnsureRoot;
 
-type AccountId = u32;
+pub type AccountId = u32;
 type Balance = u128;
 
 parameter_types! {
-    pub const BlockHashCount: u64 = 250;
-    pub const SS58Prefix: u16 = 2207;
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u16 = 2207;
 }
 impl system::Config for Runtime {
-    type BaseCallFilter = frame_support::traits::Everything;
-    type BlockWeights = ();
-    type BlockLength = ();
-    type DbWeight = ();
-    type Origin = Origin;
-    type Call = Call;
-    type Index = u64;
-    type BlockNumber = u64;
-    type Hash = H256;
-    type Hashing = BlakeTwo256;
-    type AccountId = AccountId;
-    type Lookup = IdentityLookup<Self::AccountId>;
-    type Header = Header;
-    type Event = Event;
-    type BlockHashCount = BlockHashCount;
-    type Version = ();
-    type PalletInfo = PalletInfo;
-    type AccountData = pallet_balances::AccountData<Balance>;
-    type OnNewAccount = ();
-    type OnKilledAccount = ();
-    type SystemWeightInfo = ();
-    type SS58Prefix = SS58Prefix;
-    type OnSetCode = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
 parameter_types! {
-    pub const ExistentialDeposit: u64 = 1;
+	pub const ExistentialDeposit: u128 = 1;
 }
 impl pallet_balances::Config for Runtime {
-    type Balance = Balance;
-    type DustRemoval = ();
-    type Event = Event;
-    type ExistentialDeposit = ExistentialDeposit;
-    type AccountStore = frame_system::Pallet<Runtime>;
-    type MaxLocks = ();
-    type MaxReserves = ();
-    type ReserveIdentifier = [u8; 8];
-    type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
 }
 
 parameter_type_with_key! {
-    pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
-        Default::default()
-    };
+	pub ExistentialDeposits: |_asset_id: TokenId| -> Balance {
+		Zero::zero()
+	};
 }
 parameter_types! {
-    pub DustAccount: AccountId = PalletId(*b"orml/dst").into_account();
-    pub MaxLocks: u32 = 100_000;
+	pub const BasicDeposit: u128 = 10;
+	pub const FieldDeposit: u128 = 10;
+	pub const MaxAdditionalFields: u32 = 2;
+	pub const MaxRegistrars: u32 = 20;
+	pub const TokenDeposit: u128 = 0;
+	pub const ApprovalDeposit: u128 = 0;
+	pub const StringLimit: u32 = 50;
+	pub const MaxDecimals: u8 = 18;
+	pub const MetadataDepositBase: u128 = 1;
+	pub const MetadataDepositPerByte: u128 = 1;
+	pub const MaxLocks: u32 = 50;
 }
-parameter_types! {
-    pub const BasicDeposit: u64 = 10;
-    pub const FieldDeposit: u64 = 10;
-    pub const SubAccountDeposit: u64 = 10;
-    pub const MaxSubAccounts: u32 = 2;
-    pub const MaxAdditionalFields: u32 = 2;
-    pub const MaxRegistrars: u32 = 20;
+impl bholdus_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = i128;
+	type AssetId = TokenId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = TokenDeposit;
+	type BasicDeposit = BasicDeposit;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type MaxDecimals = MaxDecimals;
+	type MaxAdditionalFields = MaxAdditionalFields;
+	type MaxRegistrars = MaxRegistrars;
+	type FieldDeposit = FieldDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = MaxLocks;
 }
+
 parameter_types! {
-    pub const TokenDeposit: u64 = 0;
-    pub const ApprovalDeposit: u64 = 0;
-    pub const StringLimit: u32 = 50;
-    pub const MetadataDepositBase: u64 = 1;
-    pub const MetadataDepositPerByte: u64 = 1;
+	pub const MaxTransferAllCurrencies: u32 = 10;
 }
-impl bholdus_tokens::Config for Runtime {
-    type Event = Event;
-    type Balance = Balance;
-    type Amount = i64;
-    type AssetId = CurrencyId;
-    type Currency = Balances;
-    type ForceOrigin = EnsureRoot<AccountId>;
-    type AssetDeposit = TokenDeposit;
-    type BasicDeposit = BasicDeposit;
-    type ApprovalDeposit = ApprovalDeposit;
-    type StringLimit = StringLimit;
-    type MaxAdditionalFields = MaxAdditionalFields;
-    type MaxRegistrars = MaxRegistrars;
-    type FieldDeposit = FieldDeposit;
-    type MetadataDepositBase = MetadataDepositBase;
-    type MetadataDepositPerByte = MetadataDepositPerByte;
-    type Freezer = ();
-    type Extra = ();
-    type WeightInfo = ();
-    type ExistentialDeposits = ExistentialDeposits;
+
+impl bholdus_currencies::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = BholdusTokens;
+	type MaxTransferAllCurrencies = MaxTransferAllCurrencies;
+	type WeightInfo = ();
 }
 
 parameter_types! {
-    pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
+	pub const DexPalletId: PalletId = PalletId(*b"bhod/dex");
+	pub const ExchangeFee: (u32, u32) = (10, 100); // 10% fee
+	pub const TradingPathLimit: u32 = 3;
+	pub const MaxTwapWindow: u64 = 10;
 }
-impl bholdus_currencies::Config for Runtime {
-    type Event = Event;
-    type MultiCurrency = BholdusTokens;
-    type NativeCurrency = AdaptedBasicCurrency;
-    type GetNativeCurrencyId = GetNativeCurrencyId;
-    type WeightInfo = ();
+impl Config for Runtime {
+	type Event = Event;
+	type ListingOrigin = EnsureRoot<Self::AccountId>;
+	type PalletId = DexPalletId;
+	type ExchangeFee = ExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type Currency = Currencies;
+	type NativeCurrency = Balances;
+	type MaxTwapWindow = MaxTwapWindow;
+	type WeightInfo = ();
 }
-pub type NativeCurrency = NativeCurrencyOf<Runtime>;
-pub type AdaptedBasicCurrency = BasicCurrencyAdapter<Runtime, Balances, i64, u64>;
 
 parameter_types! {
-    pub const DexPalletId: PalletId = PalletId(*b"bhod/dex");
-    pub const ExchangeFee: (u32, u32) = (10,100); // 10$ fee
-    pub const TradingPathLimit: u32 = 3;
+	pub const OperationalFeeMultiplier: u8 = 5;
+}
+
+/// Charges one unit of fee per millisecond of weight, to keep the fees of the tests small.
+pub struct WeightToFee;
+impl frame_support::weights::WeightToFee for WeightToFee {
+	type Balance = Balance;
 
+	fn weight_to_fee(weight: &Weight) -> Balance {
+		(*weight / 1_000_000_000).into()
+	}
 }
-impl Config for Runtime {
-    type Event = Event;
-    type ListingOrigin = EnsureRoot<Self::AccountId>;
-    type PalletId = DexPalletId;
-    type ExchangeFee = ExchangeFee;
-    type TradingPathLimit = TradingPathLimit;
-    type Currency = Currencies;
+
+impl pallet_transaction_payment::Config for Runtime {
+	type Event = Event;
+	type OnChargeTransaction =
+		DexOnChargeTransaction<pallet_transaction_payment::CurrencyAdapter<Balances, ()>>;
+	type OperationalFeeMultiplier = OperationalFeeMultiplier;
+	type WeightToFee = WeightToFee;
+	type LengthToFee = IdentityFee<Balance>;
+	type FeeMultiplierUpdate = ();
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -139,17 +166,18 @@ type Block = frame_system::mocking::MockBlock<Runtime>;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
-    pub enum Runtime where
-        Block = Block,
-        NodeBlock = Block,
-        UncheckedExtrinsic = UncheckedExtrinsic,
-    {
-        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-        BholdusTokens: bholdus_tokens::{Pallet, Storage, Event<T>},
-        Currencies: bholdus_currencies::{Pallet, Call, Event<T>},
-        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        Dex: dex::{Pallet, Call, Storage, Config<T>, Event<T>},
-    }
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		BholdusTokens: bholdus_tokens::{Pallet, Call, Storage, Event<T>},
+		Currencies: bholdus_currencies::{Pallet, Call, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Dex: dex::{Pallet, Call, Storage, Config<T>, Event<T>},
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
+	}
 );
 
 // Mock Currencies
@@ -157,13 +185,11 @@ pub const BHO: CurrencyId = CurrencyId::Token(TokenSymbol::Native);
 pub const BNB: CurrencyId = CurrencyId::Token(TokenSymbol::Token(TokenInfo { id: 1 }));
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::Token(TokenInfo { id: 2 }));
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::Token(TokenInfo { id: 3 }));
-pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::Native);
 
 // Mock TradingPairs
 parameter_types! {
-    pub static BHOBNBPair: TradingPair = TradingPair::from_currency_ids(BHO, BNB).unwrap();
-    pub static BNBDOTPair: TradingPair = TradingPair::from_currency_ids(BNB, DOT).unwrap();
-    pub static DOTBTCPair: TradingPair = TradingPair::from_currency_ids(DOT,BTC).unwrap();
+	pub static BHOBNBPair: TradingPair = TradingPair::from_currency_ids(BHO, BNB).unwrap();
+	pub static BNBDOTPair: TradingPair = TradingPair::from_currency_ids(BNB, DOT).unwrap();
 }
 
 // Mock Accounts
@@ -173,91 +199,52 @@ pub const BNB_ADMIN: AccountId = 3;
 pub const DOT_ADMIN: AccountId = 4;
 
 pub struct ExtBuilder {
-    pub balances: Vec<(CurrencyId, AccountId, Balance)>,
-    pub initial_provisioning_trading_pairs:
-        Vec<(TradingPair, (Balance, Balance), (Balance, Balance))>,
-    pub initial_enabled_trading_pairs: Vec<TradingPair>,
-    pub initial_added_liquidity_pools: Vec<(
-        <Runtime as frame_system::Config>::AccountId,
-        Vec<(TradingPair, (Balance, Balance))>,
-    )>,
+	pub balances: Vec<(CurrencyId, AccountId, Balance)>,
 }
 
-#[cfg(feature = "std")]
 impl Default for ExtBuilder {
-    fn default() -> Self {
-        Self {
-            balances: vec![
-                (BHO, ALICE, 1_000_000_000u128),
-                (BHO, BOB, 1_000_000_000u128),
-                (BHO, Dex::account_id(), 1_000_000_000u128),
-                (BHO, BNB_ADMIN, 1_000_000_000u128),
-                (BHO, DOT_ADMIN, 1_000_000_000u128),
-                (BNB, ALICE, 1_000_000_000u128),
-                (BNB, BOB, 1_000_000_000u128),
-                (DOT, ALICE, 1_000_000_000u128),
-                (DOT, BOB, 1_000_000_000u128),
-                (BTC, ALICE, 1_000_000_000u128),
-                (BTC, BOB, 1_000_000_000u128),
-            ],
-            initial_provisioning_trading_pairs: vec![],
-            initial_added_liquidity_pools: vec![],
-            initial_enabled_trading_pairs: vec![],
-        }
-    }
+	fn default() -> Self {
+		Self {
+			balances: vec![
+				(BHO, ALICE, 1_000_000_000u128),
+				(BHO, BOB, 1_000_000_000u128),
+				(BHO, Dex::account_id(), 1_000_000_000u128),
+				(BHO, BNB_ADMIN, 1_000_000_000u128),
+				(BHO, DOT_ADMIN, 1_000_000_000u128),
+				(BNB, ALICE, 1_000_000_000u128),
+				(BNB, BOB, 1_000_000_000u128),
+				(DOT, ALICE, 1_000_000_000u128),
+				(DOT, BOB, 1_000_000_000u128),
+				(BTC, ALICE, 1_000_000_000u128),
+				(BTC, BOB, 1_000_000_000u128),
+			],
+		}
+	}
 }
 
 impl ExtBuilder {
-    fn initialize_enabled_trading_pairs(mut self) -> Self {
-        self.initial_enabled_trading_pairs = vec![BHOBNBPair::get()];
-        self
-    }
-
-    fn initialize_added_liquidity(
-        mut self,
-        who: <Runtime as frame_system::Config>::AccountId,
-    ) -> Self {
-        self.initial_added_liquidity_pools = vec![(
-            who,
-            vec![
-                (BHOBNBPair::get(), (1_000_000u128, 2_000_000u128)),
-                (BNBDOTPair::get(), (1_000_000u128, 2_000_000u128)),
-            ],
-        )];
-        self
-    }
-
-    pub fn build(&self) -> sp_io::TestExternalities {
-        let mut t = system::GenesisConfig::default()
-            .build_storage::<Runtime>()
-            .unwrap()
-            .into();
-
-        pallet_balances::GenesisConfig::<Runtime> {
-            balances: self
-                .balances
-                .clone()
-                .into_iter()
-                .filter_map(|(currency_id, account_id, balance)| {
-                    if currency_id == NATIVE_CURRENCY_ID {
-                        Some((account_id.clone(), balance))
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-        }
-        .assimilate_storage(&mut t)
-        .unwrap();
-
-        dex::GenesisConfig::<Runtime> {
-            initial_added_liquidity_pools: self.initial_added_liquidity_pools.clone(),
-            initial_enabled_trading_pairs: self.initial_enabled_trading_pairs.clone(),
-            initial_provisioning_trading_pairs: self.initial_provisioning_trading_pairs.clone(),
-        }
-        .assimilate_storage(&mut t)
-        .unwrap();
-
-        sp_io::TestExternalities::from(t)
-    }
+	pub fn build(&self) -> sp_io::TestExternalities {
+		let mut t = system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self
+				.balances
+				.clone()
+				.into_iter()
+				.filter_map(|(currency_id, account_id, balance)| {
+					if currency_id.is_native_currency_id() {
+						Some((account_id, balance))
+					} else {
+						None
+					}
+				})
+				.collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		dex::GenesisConfig::<Runtime>::default().assimilate_storage(&mut t).unwrap();
+
+		sp_io::TestExternalities::from(t)
+	}
 }
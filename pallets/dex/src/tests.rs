@@ -1,1390 +1,1741 @@
-use crate::{mock::*, Error, ProvisioningParameters, TradingPairStatus};
-use common_primitives::{ExchangeRate, TradingPair};
-use bholdus_support::MultiCurrency;
-use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+//! Unit tests for the dex module.
+
+use crate::{
+	mock::*, ChargeFeeToken, Error, FeePath, ProtocolFee, ProtocolFeeDestination,
+	ProvisioningParameters, SwapLimit, TradingPairStatus, WeightInfo,
+};
+use bholdus_support::TwapProvider;
+use common_primitives::{CurrencyId, ExchangeRate, TradingPair};
+use frame_support::{
+	assert_noop, assert_ok,
+	weights::{DispatchClass, DispatchInfo, PostDispatchInfo},
+};
+use pallet_transaction_payment::OnChargeTransaction;
 use sp_runtime::{
-    traits::{BadOrigin, One, Zero},
-    FixedPointNumber,
+	traits::{BadOrigin, One, SignedExtension, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	FixedPointNumber, Permill,
 };
 
 fn initialize_tokens(builder: &ExtBuilder) {
-    assert_ok!(BholdusTokens::create(
-        Origin::signed(BNB_ADMIN),
-        BNB,
-        BNB_ADMIN,
-        1u128
-    ));
-
-    assert_ok!(BholdusTokens::create(
-        Origin::signed(DOT_ADMIN),
-        DOT,
-        DOT_ADMIN,
-        1u128
-    ));
-
-    assert_ok!(BholdusTokens::create(
-        Origin::signed(ALICE),
-        TradingPair::from_currency_ids(BHO, BNB)
-            .unwrap()
-            .dex_share_currency_id(),
-        ALICE,
-        1u128
-    ));
-
-    assert_ok!(BholdusTokens::create(
-        Origin::signed(ALICE),
-        TradingPair::from_currency_ids(BNB, DOT)
-            .unwrap()
-            .dex_share_currency_id(),
-        ALICE,
-        1u128
-    ));
-
-    for (currency_id, account_id, balance) in builder.balances.clone() {
-        if currency_id == DOT {
-            assert_ok!(BholdusTokens::mint(
-                Origin::signed(DOT_ADMIN),
-                currency_id,
-                account_id,
-                balance
-            ));
-        } else if currency_id == BNB {
-            assert_ok!(BholdusTokens::mint(
-                Origin::signed(BNB_ADMIN),
-                currency_id,
-                account_id,
-                balance
-            ));
-        }
-    }
+	assert_ok!(BholdusTokens::force_create(Origin::root(), 1, BNB_ADMIN, false, 1u128));
+	assert_ok!(BholdusTokens::force_create(Origin::root(), 2, DOT_ADMIN, false, 1u128));
+	assert_ok!(BholdusTokens::force_create(Origin::root(), 3, ALICE, false, 1u128));
+
+	for (currency_id, account_id, balance) in builder.balances.clone() {
+		let admin = if currency_id == BNB {
+			BNB_ADMIN
+		} else if currency_id == DOT {
+			DOT_ADMIN
+		} else if currency_id == BTC {
+			ALICE
+		} else {
+			continue;
+		};
+		assert_ok!(BholdusTokens::mint(
+			Origin::signed(admin),
+			currency_id.token_id().unwrap(),
+			account_id,
+			balance
+		));
+	}
 }
 
 #[test]
 fn start_trading_pair_provisioning_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-
-        initialize_tokens(&builder);
-
-        // Only ListingOrigin can call
-        assert_noop!(
-            Dex::start_trading_pair_provisioning(
-                Origin::signed(ALICE),
-                BHO,
-                BNB,
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            BadOrigin
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Disabled
-        );
-
-        // Only valid trading pair is allowed
-        assert_noop!(
-            Dex::start_trading_pair_provisioning(
-                Origin::root(),
-                BHO,
-                BHO,
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            crate::Error::<Runtime>::InvalidCurrencyId
-        );
-        assert_noop!(
-            Dex::start_trading_pair_provisioning(
-                Origin::root(),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id(),
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            crate::Error::<Runtime>::InvalidCurrencyId
-        );
-
-        // Valid trading pair should have provisioning status
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Provisioning(ProvisioningParameters {
-                min_contribution: (2_000u128, 1_000u128),
-                target_contribution: (2_000u128, 1_000u128),
-                accumulated_contribution: Default::default()
-            })
-        );
-        System::assert_last_event(Event::Dex(crate::Event::TradingPairProvisioning(
-            TradingPair::from_currency_ids(BHO, BNB).unwrap(),
-        )));
-
-        // Only disabled trading pairs can become provisioning
-        assert_noop!(
-            Dex::start_trading_pair_provisioning(
-                Origin::root(),
-                BHO,
-                BNB,
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            Error::<Runtime>::TradingPairMustBeDisabled
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		initialize_tokens(&builder);
+
+		// Only ListingOrigin can call
+		assert_noop!(
+			Dex::start_trading_pair_provisioning(
+				Origin::signed(ALICE),
+				BHO,
+				BNB,
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			BadOrigin
+		);
+		assert_eq!(Dex::trading_pair_statuses(BHOBNBPair::get()), TradingPairStatus::<_>::Disabled);
+
+		// Only valid trading pair is allowed
+		assert_noop!(
+			Dex::start_trading_pair_provisioning(
+				Origin::root(),
+				BHO,
+				BHO,
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			crate::Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			Dex::start_trading_pair_provisioning(
+				Origin::root(),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id(),
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			crate::Error::<Runtime>::InvalidCurrencyId
+		);
+
+		// Valid trading pair should have provisioning status
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_eq!(
+			Dex::trading_pair_statuses(BHOBNBPair::get()),
+			TradingPairStatus::<_>::Provisioning(ProvisioningParameters {
+				min_contribution: (2_000u128, 1_000u128),
+				target_contribution: (2_000u128, 1_000u128),
+				accumulated_contribution: Default::default()
+			})
+		);
+		System::assert_last_event(Event::Dex(crate::Event::TradingPairProvisioning(
+			TradingPair::from_currency_ids(BHO, BNB).unwrap(),
+		)));
+
+		// Only disabled trading pairs can become provisioning
+		assert_noop!(
+			Dex::start_trading_pair_provisioning(
+				Origin::root(),
+				BHO,
+				BNB,
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			Error::<Runtime>::TradingPairMustBeDisabled
+		);
+	});
 }
 
 #[test]
 fn update_provisioning_parameters_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-        initialize_tokens(&builder);
-        // Should throw error for invalid trading pair
-        assert_noop!(
-            Dex::update_trading_pair_provisioning_parameters(
-                Origin::root(),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id(),
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            Error::<Runtime>::InvalidCurrencyId
-        );
-
-        // Only works for provisioning trading pairs
-        assert_noop!(
-            Dex::update_trading_pair_provisioning_parameters(
-                Origin::root(),
-                BHO,
-                BNB,
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            Error::<Runtime>::TradingPairMustBeProvisioning
-        );
-
-        // Only works with correct listing origin
-        assert_noop!(
-            Dex::update_trading_pair_provisioning_parameters(
-                Origin::signed(ALICE),
-                BHO,
-                BNB,
-                1_000u128,
-                2_000u128,
-                1_000u128,
-                2_000u128
-            ),
-            BadOrigin
-        );
-
-        // New parameters should store correctly
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-        assert_ok!(Dex::update_trading_pair_provisioning_parameters(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            10_000u128,
-            20_000u128
-        ));
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Provisioning(ProvisioningParameters {
-                min_contribution: (2_000u128, 1_000u128),
-                target_contribution: (20_000u128, 10_000u128),
-                accumulated_contribution: (0, 0),
-            })
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		// Should throw error for invalid trading pair
+		assert_noop!(
+			Dex::update_trading_pair_provisioning_parameters(
+				Origin::root(),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id(),
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		// Only works for provisioning trading pairs
+		assert_noop!(
+			Dex::update_trading_pair_provisioning_parameters(
+				Origin::root(),
+				BHO,
+				BNB,
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			Error::<Runtime>::TradingPairMustBeProvisioning
+		);
+
+		// Only works with correct listing origin
+		assert_noop!(
+			Dex::update_trading_pair_provisioning_parameters(
+				Origin::signed(ALICE),
+				BHO,
+				BNB,
+				1_000u128,
+				2_000u128,
+				1_000u128,
+				2_000u128
+			),
+			BadOrigin
+		);
+
+		// New parameters should store correctly
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_ok!(Dex::update_trading_pair_provisioning_parameters(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			10_000u128,
+			20_000u128
+		));
+		assert_eq!(
+			Dex::trading_pair_statuses(BHOBNBPair::get()),
+			TradingPairStatus::<_>::Provisioning(ProvisioningParameters {
+				min_contribution: (2_000u128, 1_000u128),
+				target_contribution: (20_000u128, 10_000u128),
+				accumulated_contribution: (0, 0),
+			})
+		);
+	});
 }
 
 #[test]
 fn enable_disabled_trading_pair_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-        initialize_tokens(&builder);
-
-        // Only works for listing origin
-        assert_noop!(
-            Dex::enable_trading_pair(Origin::signed(ALICE), BHO, BNB),
-            BadOrigin
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Disabled
-        );
-        // Should return error for invalid trading pair
-        assert_noop!(
-            Dex::enable_trading_pair(
-                Origin::root(),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id()
-            ),
-            Error::<Runtime>::InvalidCurrencyId
-        );
-
-        // Enable with listing origin shoud works
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::Enabled
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+
+		// Only works for listing origin
+		assert_noop!(Dex::enable_trading_pair(Origin::signed(ALICE), BHO, BNB), BadOrigin);
+		assert_eq!(Dex::trading_pair_statuses(BHOBNBPair::get()), TradingPairStatus::<_>::Disabled);
+		// Should return error for invalid trading pair
+		assert_noop!(
+			Dex::enable_trading_pair(
+				Origin::root(),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id()
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		// Enable with listing origin shoud works
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_eq!(Dex::trading_pair_statuses(BHOBNBPair::get()), TradingPairStatus::Enabled);
+	});
 }
 
 #[test]
 fn enable_provisioning_trading_pair_without_provision_yet_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-
-        initialize_tokens(&builder);
-
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BNB,
-            DOT,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-
-        // Should throw error for invalid trading pair
-        assert_noop!(
-            Dex::enable_trading_pair(Origin::signed(ALICE), BHO, BNB),
-            BadOrigin
-        );
-        // Only listing origin can work
-        assert_noop!(
-            Dex::enable_trading_pair(Origin::signed(ALICE), BHO, BNB),
-            BadOrigin
-        );
-
-        // Provisioning trading pair that are already provisioned should throw error
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128
-        ));
-        assert_noop!(
-            Dex::enable_trading_pair(Origin::root(), BHO, BNB),
-            Error::<Runtime>::TradingPairAlreadyProvisioned
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Provisioning(ProvisioningParameters {
-                min_contribution: (2_000u128, 1_000u128),
-                target_contribution: (2_000u128, 1_000u128),
-                accumulated_contribution: (2_000u128, 1_000u128)
-            })
-        );
-
-        // Provisioning trading pair that are not yet provisioned shoud work
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
-        assert_eq!(
-            Dex::trading_pair_statuses(BNBDOTPair::get()),
-            TradingPairStatus::<_>::Enabled
-        );
-        System::assert_last_event(Event::Dex(crate::Event::TradingPairEnabled(
-            BNBDOTPair::get(),
-        )));
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		initialize_tokens(&builder);
+
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BNB,
+			DOT,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+
+		// Should throw error for invalid trading pair
+		assert_noop!(Dex::enable_trading_pair(Origin::signed(ALICE), BHO, BNB), BadOrigin);
+		// Only listing origin can work
+		assert_noop!(Dex::enable_trading_pair(Origin::signed(ALICE), BHO, BNB), BadOrigin);
+
+		// Provisioning trading pair that are already provisioned should throw error
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128
+		));
+		assert_noop!(
+			Dex::enable_trading_pair(Origin::root(), BHO, BNB),
+			Error::<Runtime>::TradingPairAlreadyProvisioned
+		);
+		assert_eq!(
+			Dex::trading_pair_statuses(BHOBNBPair::get()),
+			TradingPairStatus::<_>::Provisioning(ProvisioningParameters {
+				min_contribution: (2_000u128, 1_000u128),
+				target_contribution: (2_000u128, 1_000u128),
+				accumulated_contribution: (2_000u128, 1_000u128)
+			})
+		);
+
+		// Provisioning trading pair that are not yet provisioned shoud work
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
+		assert_eq!(Dex::trading_pair_statuses(BNBDOTPair::get()), TradingPairStatus::<_>::Enabled);
+		System::assert_last_event(Event::Dex(crate::Event::TradingPairEnabled(BNBDOTPair::get())));
+	});
 }
 
 #[test]
 fn add_provision_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-        initialize_tokens(&builder);
-
-        assert_noop!(
-            Dex::add_trading_pair_provision(
-                Origin::signed(ALICE),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id(),
-                1_000u128,
-                2_000u128
-            ),
-            Error::<Runtime>::InvalidCurrencyId
-        );
-
-        // Disabled trading pairs are not allowed to add provision
-        assert_noop!(
-            Dex::add_trading_pair_provision(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128),
-            Error::<Runtime>::TradingPairMustBeProvisioning
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Disabled
-        );
-
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-
-        // Alice adds provision but not satisfies minimum contribution
-        assert_noop!(
-            Dex::add_trading_pair_provision(Origin::signed(ALICE), BHO, BNB, 500u128, 1_000u128),
-            Error::<Runtime>::InvalidContributionIncrement
-        );
-
-        // Alice adds provision and it should work correcly
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128
-        ));
-        assert_eq!(
-            Dex::provisioning_pool(BHOBNBPair::get(), ALICE),
-            (2_000u128, 1_000u128)
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::Provisioning(ProvisioningParameters {
-                min_contribution: (2_000u128, 1_000u128),
-                target_contribution: (2_000u128, 1_000u128),
-                accumulated_contribution: (2_000u128, 1_000u128),
-            })
-        );
-        System::assert_last_event(Event::Dex(crate::Event::AddProvision(
-            ALICE, BNB, 2_000u128, BHO, 1_000u128,
-        )));
-
-        // Alice adds provision second times
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            3_000u128,
-            4_000u128
-        ));
-        assert_eq!(
-            Dex::provisioning_pool(BHOBNBPair::get(), ALICE),
-            (6_000u128, 4_000u128)
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::Provisioning(ProvisioningParameters {
-                min_contribution: (2_000u128, 1_000u128),
-                target_contribution: (2_000u128, 1_000u128),
-                accumulated_contribution: (6_000u128, 4_000u128),
-            })
-        );
-        System::assert_last_event(Event::Dex(crate::Event::AddProvision(
-            ALICE, BNB, 4_000u128, BHO, 3_000u128,
-        )));
-
-        // Bob adds provision
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            4_000u128,
-            5_000u128
-        ));
-        assert_eq!(
-            Dex::provisioning_pool(BHOBNBPair::get(), BOB),
-            (5_000u128, 4_000u128)
-        );
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::Provisioning(ProvisioningParameters {
-                min_contribution: (2_000u128, 1_000u128),
-                target_contribution: (2_000u128, 1_000u128),
-                accumulated_contribution: (11_000u128, 8_000u128),
-            })
-        );
-        System::assert_last_event(Event::Dex(crate::Event::AddProvision(
-            BOB, BNB, 5_000u128, BHO, 4_000u128,
-        )));
-
-        // Alice adds provision that out of her balance should show error
-        assert_noop!(
-            Dex::add_trading_pair_provision(
-                Origin::signed(ALICE),
-                BHO,
-                BNB,
-                2_000_000_000u128,
-                2_000_000_000u128
-            ),
-            bholdus_tokens::Error::<Runtime>::BalanceLow
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+
+		assert_noop!(
+			Dex::add_trading_pair_provision(
+				Origin::signed(ALICE),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id(),
+				1_000u128,
+				2_000u128
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		// Disabled trading pairs are not allowed to add provision
+		assert_noop!(
+			Dex::add_trading_pair_provision(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128),
+			Error::<Runtime>::TradingPairMustBeProvisioning
+		);
+		assert_eq!(Dex::trading_pair_statuses(BHOBNBPair::get()), TradingPairStatus::<_>::Disabled);
+
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+
+		// Alice adds provision but not satisfies minimum contribution
+		assert_noop!(
+			Dex::add_trading_pair_provision(Origin::signed(ALICE), BHO, BNB, 500u128, 1_000u128),
+			Error::<Runtime>::InvalidContributionIncrement
+		);
+
+		// Alice adds provision and it should work correcly
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128
+		));
+		assert_eq!(Dex::provisioning_pool(BHOBNBPair::get(), ALICE), (2_000u128, 1_000u128));
+		assert_eq!(
+			Dex::trading_pair_statuses(BHOBNBPair::get()),
+			TradingPairStatus::Provisioning(ProvisioningParameters {
+				min_contribution: (2_000u128, 1_000u128),
+				target_contribution: (2_000u128, 1_000u128),
+				accumulated_contribution: (2_000u128, 1_000u128),
+			})
+		);
+		System::assert_last_event(Event::Dex(crate::Event::AddProvision(
+			ALICE, BNB, 2_000u128, BHO, 1_000u128,
+		)));
+
+		// Alice adds provision second times
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			3_000u128,
+			4_000u128
+		));
+		assert_eq!(Dex::provisioning_pool(BHOBNBPair::get(), ALICE), (6_000u128, 4_000u128));
+		assert_eq!(
+			Dex::trading_pair_statuses(BHOBNBPair::get()),
+			TradingPairStatus::Provisioning(ProvisioningParameters {
+				min_contribution: (2_000u128, 1_000u128),
+				target_contribution: (2_000u128, 1_000u128),
+				accumulated_contribution: (6_000u128, 4_000u128),
+			})
+		);
+		System::assert_last_event(Event::Dex(crate::Event::AddProvision(
+			ALICE, BNB, 4_000u128, BHO, 3_000u128,
+		)));
+
+		// Bob adds provision
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(BOB),
+			BHO,
+			BNB,
+			4_000u128,
+			5_000u128
+		));
+		assert_eq!(Dex::provisioning_pool(BHOBNBPair::get(), BOB), (5_000u128, 4_000u128));
+		assert_eq!(
+			Dex::trading_pair_statuses(BHOBNBPair::get()),
+			TradingPairStatus::Provisioning(ProvisioningParameters {
+				min_contribution: (2_000u128, 1_000u128),
+				target_contribution: (2_000u128, 1_000u128),
+				accumulated_contribution: (11_000u128, 8_000u128),
+			})
+		);
+		System::assert_last_event(Event::Dex(crate::Event::AddProvision(
+			BOB, BNB, 5_000u128, BHO, 4_000u128,
+		)));
+
+		// Alice adds provision that out of her balance should show error
+		assert_noop!(
+			Dex::add_trading_pair_provision(
+				Origin::signed(ALICE),
+				BHO,
+				BNB,
+				2_000_000_000u128,
+				2_000_000_000u128
+			),
+			bholdus_tokens::Error::<Runtime>::BalanceLow
+		);
+	});
+}
+
+#[test]
+fn get_trading_pair_status_and_provision_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+
+		assert_eq!(Dex::get_trading_pair_status(&BHO, &BNB), TradingPairStatus::<_>::Disabled);
+		assert_eq!(Dex::get_trading_pair_status(&BHO, &BHO), TradingPairStatus::<_>::Disabled);
+		assert_eq!(Dex::get_provision(&BHO, &BNB, &ALICE), (0, 0));
+
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128
+		));
+
+		assert_eq!(
+			Dex::get_trading_pair_status(&BNB, &BHO),
+			Dex::trading_pair_statuses(BHOBNBPair::get())
+		);
+		assert_eq!(Dex::get_provision(&BHO, &BNB, &ALICE), (1_000u128, 2_000u128));
+		assert_eq!(Dex::get_provision(&BNB, &BHO, &ALICE), (2_000u128, 1_000u128));
+		assert_eq!(Dex::get_provision(&BHO, &BNB, &BOB), (0, 0));
+	});
 }
 
 #[test]
 fn enable_provisioning_trading_pair_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-
-        initialize_tokens(&builder);
-
-        // Only listing origin can enable provisioning trading pair
-        assert_noop!(
-            Dex::enable_provisioning_trading_pair(Origin::signed(ALICE), BHO, BNB),
-            BadOrigin
-        );
-
-        // Enable invalid trading pair should throw error
-        assert_noop!(
-            Dex::enable_provisioning_trading_pair(
-                Origin::root(),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id()
-            ),
-            Error::<Runtime>::InvalidCurrencyId
-        );
-
-        // Disabled trading pair should throw error
-        assert_noop!(
-            Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB),
-            Error::<Runtime>::TradingPairMustBeProvisioning
-        );
-
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            0u128,
-            0_000u128,
-            1_000u128,
-            2_000u128
-        ));
-
-        // Alice adds provision
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            0u128,
-            1_000u128
-        ));
-        // Enable trading pair at this moment should throw error since target contribution is not
-        // reached
-        assert_noop!(
-            Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB),
-            Error::<Runtime>::UnqualifiedProvision
-        );
-
-        // Alice adds more provision
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            0u128,
-            1_000u128
-        ));
-        // Enable trading pair at this moment should throw error since target contribution is
-        // reached but either one currency provision is zero
-        assert_noop!(
-            Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB),
-            Error::<Runtime>::UnqualifiedProvision
-        );
-
-        // Bobs add provision
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            1_000u128,
-            1_000u128
-        ));
-
-        // Enable trading pair should work since target contribution is reached and neither
-        // currency provision is zero
-        assert_ok!(Dex::enable_provisioning_trading_pair(
-            Origin::root(),
-            BHO,
-            BNB
-        ));
-        assert_eq!(
-            Dex::trading_pair_statuses(BHOBNBPair::get()),
-            TradingPairStatus::<_>::Enabled
-        );
-        // Initial share exchange rate should be stored so first liquidity providers can claim
-        // their shares later
-        assert_eq!(
-            Dex::initial_share_exchange_rates(BHOBNBPair::get()),
-            (
-                ExchangeRate::one(),
-                ExchangeRate::checked_from_rational(3_000u128, 1_000u128).unwrap()
-            )
-        );
-        // Liquditiy pool must be set correctly
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (3_000u128, 1_000u128)
-        );
-        // Event must be emitted
-        System::assert_last_event(Event::Dex(
-            crate::Event::TradingPairEnabledFromProvisioning(
-                BNB, 3_000u128, BHO, 1_000u128, 6_000u128,
-            ),
-        ));
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		initialize_tokens(&builder);
+
+		// Only listing origin can enable provisioning trading pair
+		assert_noop!(
+			Dex::enable_provisioning_trading_pair(Origin::signed(ALICE), BHO, BNB),
+			BadOrigin
+		);
+
+		// Enable invalid trading pair should throw error
+		assert_noop!(
+			Dex::enable_provisioning_trading_pair(
+				Origin::root(),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id()
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		// Disabled trading pair should throw error
+		assert_noop!(
+			Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB),
+			Error::<Runtime>::TradingPairMustBeProvisioning
+		);
+
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			0u128,
+			0_000u128,
+			1_000u128,
+			2_000u128
+		));
+
+		// Alice adds provision
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			0u128,
+			1_000u128
+		));
+		// Enable trading pair at this moment should throw error since target contribution is not
+		// reached
+		assert_noop!(
+			Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB),
+			Error::<Runtime>::UnqualifiedProvision
+		);
+
+		// Alice adds more provision
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			0u128,
+			1_000u128
+		));
+		// Enable trading pair at this moment should throw error since target contribution is
+		// reached but either one currency provision is zero
+		assert_noop!(
+			Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB),
+			Error::<Runtime>::UnqualifiedProvision
+		);
+
+		// Bobs add provision
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(BOB),
+			BHO,
+			BNB,
+			1_000u128,
+			1_000u128
+		));
+
+		// Enable trading pair should work since target contribution is reached and neither
+		// currency provision is zero
+		assert_ok!(Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB));
+		assert_eq!(Dex::trading_pair_statuses(BHOBNBPair::get()), TradingPairStatus::<_>::Enabled);
+		// Initial share exchange rate should be stored so first liquidity providers can claim
+		// their shares later
+		assert_eq!(
+			Dex::initial_share_exchange_rates(BHOBNBPair::get()),
+			(
+				ExchangeRate::one(),
+				ExchangeRate::checked_from_rational(3_000u128, 1_000u128).unwrap()
+			)
+		);
+		// Liquditiy pool must be set correctly
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (3_000u128, 1_000u128));
+		// Event must be emitted
+		System::assert_last_event(Event::Dex(crate::Event::TradingPairEnabledFromProvisioning(
+			BNB, 3_000u128, BHO, 1_000u128, 6_000u128,
+		)));
+	});
 }
 
 #[test]
 fn claim_dex_share_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-
-        initialize_tokens(&builder);
-
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128
-        ));
-        assert_ok!(Dex::add_trading_pair_provision(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128
-        ));
-        assert_ok!(Dex::enable_provisioning_trading_pair(
-            Origin::root(),
-            BHO,
-            BNB
-        ));
-
-        // Alice comes to claim her dex share and this should work
-        assert_ok!(Dex::claim_dex_share(Origin::signed(ALICE), ALICE, BHO, BNB));
-        assert_eq!(
-            Dex::provisioning_pool(BHOBNBPair::get(), ALICE),
-            (Zero::zero(), Zero::zero())
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &ALICE
-            ),
-            4_000u128
-        );
-        // Bob claims his dex share and this should work
-        assert_ok!(Dex::claim_dex_share(Origin::signed(BOB), BOB, BHO, BNB));
-        assert_eq!(
-            Dex::provisioning_pool(BHOBNBPair::get(), BOB),
-            (Zero::zero(), Zero::zero())
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &BOB
-            ),
-            4_000u128
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		initialize_tokens(&builder);
+
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128
+		));
+		assert_ok!(Dex::add_trading_pair_provision(
+			Origin::signed(BOB),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128
+		));
+		assert_ok!(Dex::enable_provisioning_trading_pair(Origin::root(), BHO, BNB));
+
+		// Alice comes to claim her dex share and this should work
+		assert_ok!(Dex::claim_dex_share(Origin::signed(ALICE), ALICE, BHO, BNB));
+		assert_eq!(Dex::provisioning_pool(BHOBNBPair::get(), ALICE), (Zero::zero(), Zero::zero()));
+		assert_eq!(
+			Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &ALICE),
+			4_000u128
+		);
+		// Bob claims his dex share and this should work
+		assert_ok!(Dex::claim_dex_share(Origin::signed(BOB), BOB, BHO, BNB));
+		assert_eq!(Dex::provisioning_pool(BHOBNBPair::get(), BOB), (Zero::zero(), Zero::zero()));
+		assert_eq!(Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &BOB), 4_000u128);
+	});
 }
 
 #[test]
 fn add_liquidity_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-        initialize_tokens(&builder);
-
-        // Alice adds liquidity to invalid trading pair. This should throw error
-        assert_noop!(
-            Dex::add_liquidity(
-                Origin::signed(ALICE),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id(),
-                1_000u128,
-                2_000u128,
-            ),
-            Error::<Runtime>::InvalidCurrencyId
-        );
-        // Alice adds liquidity to disabled trading pair. This should throw error
-        assert_noop!(
-            Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128,),
-            Error::<Runtime>::TradingPairMustBeEnabled
-        );
-        // Alice ads liquidity to provisioning trading pair. This should show error
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            0u128,
-            0u128,
-            0u128,
-            0u128
-        ));
-        assert_noop!(
-            Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128,),
-            Error::<Runtime>::TradingPairMustBeEnabled
-        );
-        // Enable trading pair
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
-        // Alice adds liquidity but exceeds her balance so this should throw error
-        assert_noop!(
-            Dex::add_liquidity(
-                Origin::signed(ALICE),
-                BHO,
-                BNB,
-                2_000_000_000u128,
-                2_000_000_000u128
-            ),
-            bholdus_tokens::Error::<Runtime>::BalanceLow
-        );
-        // Alice adds zero amount of either currency which is invalid
-        assert_noop!(
-            Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 0, 1_000u128),
-            Error::<Runtime>::InvalidLiquidityIncrement
-        );
-
-        // Alice adds liquidity successfully and is first liquidity provider
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128
-        ));
-        // Liquidity pool must be set correctly
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (2_000u128, 1_000u128)
-        );
-        // Alice receives correct shares amount
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &ALICE
-            ),
-            4_000u128
-        );
-        // Total share must be correct
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_issuance(
-                BHOBNBPair::get().dex_share_currency_id(),
-            ),
-            4_000u128
-        );
-        // Alice should deposit her balance into liquidity pool
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &ALICE),
-            999_999_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &ALICE),
-            999_998_000u128
-        );
-        // Event must be emitted
-        System::assert_last_event(Event::Dex(crate::Event::AddLiquidity(
-            ALICE, BNB, 2_000u128, BHO, 1_000u128, 4_000u128,
-        )));
-        // Bob adds liquidity
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            2_000u128,
-            4_000u128
-        ));
-        // Liquidity pool must be set correctly
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (6_000u128, 3_000u128)
-        );
-        // Bob receives correct shares amount
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &BOB
-            ),
-            8_000u128
-        );
-        // Total share must be correct
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_issuance(
-                BHOBNBPair::get().dex_share_currency_id(),
-            ),
-            12_000u128
-        );
-        // Bob should deposit his balance into liquidity pool
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &BOB),
-            999_998_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &BOB),
-            999_996_000u128
-        );
-        // Event must be emitted
-        System::assert_last_event(Event::Dex(crate::Event::AddLiquidity(
-            BOB, BNB, 4_000u128, BHO, 2_000u128, 8_000u128,
-        )));
-        // Bob adds liquidity that has different exchange rate than current exchange rate. We should
-        // match his exchange rate to current exchange rate.
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            3_000u128,
-            7_000u128
-        ));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (12_000u128, 6_000u128)
-        );
-        // Total share must be correct
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_issuance(
-                BHOBNBPair::get().dex_share_currency_id(),
-            ),
-            24_000u128
-        );
-        // Bob receives correct shares amount
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &BOB
-            ),
-            20_000u128
-        );
-        // Bob should deposit his balance into liquidity pool
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &BOB),
-            999_995_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &BOB),
-            999_990_000u128
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+
+		// Alice adds liquidity to invalid trading pair. This should throw error
+		assert_noop!(
+			Dex::add_liquidity(
+				Origin::signed(ALICE),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id(),
+				1_000u128,
+				2_000u128,
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		// Alice adds liquidity to disabled trading pair. This should throw error
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128,),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+		// Alice ads liquidity to provisioning trading pair. This should show error
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			0u128,
+			0u128,
+			0u128,
+			0u128
+		));
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128,),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+		// Enable trading pair
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		// Alice adds liquidity but exceeds her balance so this should throw error
+		assert_noop!(
+			Dex::add_liquidity(
+				Origin::signed(ALICE),
+				BHO,
+				BNB,
+				2_000_000_000u128,
+				2_000_000_000u128
+			),
+			bholdus_tokens::Error::<Runtime>::BalanceLow
+		);
+		// Alice adds zero amount of either currency which is invalid
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 0, 1_000u128),
+			Error::<Runtime>::InvalidLiquidityIncrement
+		);
+
+		// Alice adds liquidity successfully and is first liquidity provider
+		assert_ok!(Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000u128, 2_000u128));
+		// Liquidity pool must be set correctly
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (2_000u128, 1_000u128));
+		// Alice receives correct shares amount
+		assert_eq!(
+			Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &ALICE),
+			4_000u128
+		);
+		// Total share must be correct
+		assert_eq!(Dex::total_issuance(BHOBNBPair::get().dex_share_currency_id(),), 4_000u128);
+		// Alice should deposit her balance into liquidity pool
+		assert_eq!(Dex::total_balance(BHO, &ALICE), 999_999_000u128);
+		assert_eq!(Dex::total_balance(BNB, &ALICE), 999_998_000u128);
+		// Event must be emitted
+		System::assert_last_event(Event::Dex(crate::Event::AddLiquidity(
+			ALICE, BNB, 2_000u128, BHO, 1_000u128, 4_000u128,
+		)));
+		// Bob adds liquidity
+		assert_ok!(Dex::add_liquidity(Origin::signed(BOB), BHO, BNB, 2_000u128, 4_000u128));
+		// Liquidity pool must be set correctly
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (6_000u128, 3_000u128));
+		// Bob receives correct shares amount
+		assert_eq!(Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &BOB), 8_000u128);
+		// Total share must be correct
+		assert_eq!(Dex::total_issuance(BHOBNBPair::get().dex_share_currency_id(),), 12_000u128);
+		// Bob should deposit his balance into liquidity pool
+		assert_eq!(Dex::total_balance(BHO, &BOB), 999_998_000u128);
+		assert_eq!(Dex::total_balance(BNB, &BOB), 999_996_000u128);
+		// Event must be emitted
+		System::assert_last_event(Event::Dex(crate::Event::AddLiquidity(
+			BOB, BNB, 4_000u128, BHO, 2_000u128, 8_000u128,
+		)));
+		// Bob adds liquidity that has different exchange rate than current exchange rate. We should
+		// match his exchange rate to current exchange rate.
+		assert_ok!(Dex::add_liquidity(Origin::signed(BOB), BHO, BNB, 3_000u128, 7_000u128));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (12_000u128, 6_000u128));
+		// Total share must be correct
+		assert_eq!(Dex::total_issuance(BHOBNBPair::get().dex_share_currency_id(),), 24_000u128);
+		// Bob receives correct shares amount
+		assert_eq!(Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &BOB), 20_000u128);
+		// Bob should deposit his balance into liquidity pool
+		assert_eq!(Dex::total_balance(BHO, &BOB), 999_995_000u128);
+		assert_eq!(Dex::total_balance(BNB, &BOB), 999_990_000u128);
+	});
 }
 
 #[test]
 fn remove_liquidity_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        initialize_tokens(&builder);
-        System::set_block_number(1);
-
-        // Remove liquidity from invalid trading pair shoud throw error
-        assert_noop!(
-            Dex::remove_liquidity(
-                Origin::signed(ALICE),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id(),
-                10,
-                10,
-                10
-            ),
-            Error::<Runtime>::InvalidCurrencyId
-        );
-
-        // Remove zero share should throw error
-        assert_noop!(
-            Dex::remove_liquidity(
-                Origin::signed(ALICE),
-                BHO,
-                BHOBNBPair::get().dex_share_currency_id(),
-                0,
-                10,
-                10
-            ),
-            Error::<Runtime>::InvalidRemoveShareAmount
-        );
-
-        // Remove liquidity from trading pair that doesn't have any share should throw error
-        assert_noop!(
-            Dex::remove_liquidity(Origin::signed(ALICE), BHO, BNB, 100, 10, 10),
-            Error::<Runtime>::ZeroTotalShare
-        );
-
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
-
-        // Alice adds liquidity as first liquidity provider
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            1_000_000u128,
-            5_000_000u128
-        ));
-
-        // Alice removes liquidity with specified minimum withdrawn amount higher than actual
-        // withdrawn amount should throw error
-        assert_noop!(
-            Dex::remove_liquidity(
-                Origin::signed(ALICE),
-                BHO,
-                BNB,
-                8_000_000u128,
-                800_001u128,
-                4_000_000u128
-            ),
-            Error::<Runtime>::UnacceptableWithdrawnAmount
-        );
-        assert_noop!(
-            Dex::remove_liquidity(
-                Origin::signed(ALICE),
-                BHO,
-                BNB,
-                8_000_000u128,
-                800_000u128,
-                4_000_001u128
-            ),
-            Error::<Runtime>::UnacceptableWithdrawnAmount
-        );
-
-        // Alice removes liqudity successfully
-        assert_ok!(Dex::remove_liquidity(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            8_000_000u128,
-            800_000u128,
-            4_000_000u128
-        ));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (1_000_000u128, 200_000u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &ALICE
-            ),
-            2_000_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_issuance(
-                BHOBNBPair::get().dex_share_currency_id(),
-            ),
-            2_000_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &ALICE),
-            999_800_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &ALICE),
-            999_000_000u128
-        );
-        System::assert_last_event(Event::Dex(crate::Event::RemoveLiqudity(
-            ALICE,
-            BNB,
-            4_000_000u128,
-            BHO,
-            800_000u128,
-            8_000_000u128,
-        )));
-
-        // Bob adds liquidity
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            200_000u128,
-            1_000_000u128
-        ));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (2_000_000u128, 400_000u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_issuance(
-                BHOBNBPair::get().dex_share_currency_id(),
-            ),
-            4_000_000u128
-        );
-        // Bob removes liquidity
-        assert_ok!(Dex::remove_liquidity(
-            Origin::signed(BOB),
-            BHO,
-            BNB,
-            2_000_000u128,
-            200_000u128,
-            1_000_000u128
-        ));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (1_000_000u128, 200_000u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(
-                BHOBNBPair::get().dex_share_currency_id(),
-                &BOB
-            ),
-            0
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_issuance(
-                BHOBNBPair::get().dex_share_currency_id(),
-            ),
-            2_000_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &BOB),
-            1_000_000_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &BOB),
-            1_000_000_000u128
-        );
-        System::assert_last_event(Event::Dex(crate::Event::RemoveLiqudity(
-            BOB,
-            BNB,
-            1_000_000u128,
-            BHO,
-            200_000u128,
-            2_000_000u128,
-        )));
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+		System::set_block_number(1);
+
+		// Remove liquidity from invalid trading pair shoud throw error
+		assert_noop!(
+			Dex::remove_liquidity(
+				Origin::signed(ALICE),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id(),
+				10,
+				10,
+				10
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		// Remove zero share should throw error
+		assert_noop!(
+			Dex::remove_liquidity(
+				Origin::signed(ALICE),
+				BHO,
+				BHOBNBPair::get().dex_share_currency_id(),
+				0,
+				10,
+				10
+			),
+			Error::<Runtime>::InvalidRemoveShareAmount
+		);
+
+		// Remove liquidity from trading pair that doesn't have any share should throw error
+		assert_noop!(
+			Dex::remove_liquidity(Origin::signed(ALICE), BHO, BNB, 100, 10, 10),
+			Error::<Runtime>::ZeroTotalShare
+		);
+
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+
+		// Alice adds liquidity as first liquidity provider
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			5_000_000u128
+		));
+
+		// Alice removes liquidity with specified minimum withdrawn amount higher than actual
+		// withdrawn amount should throw error
+		assert_noop!(
+			Dex::remove_liquidity(
+				Origin::signed(ALICE),
+				BHO,
+				BNB,
+				8_000_000u128,
+				800_001u128,
+				4_000_000u128
+			),
+			Error::<Runtime>::UnacceptableWithdrawnAmount
+		);
+		assert_noop!(
+			Dex::remove_liquidity(
+				Origin::signed(ALICE),
+				BHO,
+				BNB,
+				8_000_000u128,
+				800_000u128,
+				4_000_001u128
+			),
+			Error::<Runtime>::UnacceptableWithdrawnAmount
+		);
+
+		// Alice removes liqudity successfully
+		assert_ok!(Dex::remove_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			8_000_000u128,
+			800_000u128,
+			4_000_000u128
+		));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (1_000_000u128, 200_000u128));
+		assert_eq!(
+			Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &ALICE),
+			2_000_000u128
+		);
+		assert_eq!(Dex::total_issuance(BHOBNBPair::get().dex_share_currency_id(),), 2_000_000u128);
+		assert_eq!(Dex::total_balance(BHO, &ALICE), 999_800_000u128);
+		assert_eq!(Dex::total_balance(BNB, &ALICE), 999_000_000u128);
+		System::assert_last_event(Event::Dex(crate::Event::RemoveLiqudity(
+			ALICE,
+			BNB,
+			4_000_000u128,
+			BHO,
+			800_000u128,
+			8_000_000u128,
+		)));
+
+		// Bob adds liquidity
+		assert_ok!(Dex::add_liquidity(Origin::signed(BOB), BHO, BNB, 200_000u128, 1_000_000u128));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (2_000_000u128, 400_000u128));
+		assert_eq!(Dex::total_issuance(BHOBNBPair::get().dex_share_currency_id(),), 4_000_000u128);
+		// Bob removes liquidity
+		assert_ok!(Dex::remove_liquidity(
+			Origin::signed(BOB),
+			BHO,
+			BNB,
+			2_000_000u128,
+			200_000u128,
+			1_000_000u128
+		));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (1_000_000u128, 200_000u128));
+		assert_eq!(Dex::total_balance(BHOBNBPair::get().dex_share_currency_id(), &BOB), 0);
+		assert_eq!(Dex::total_issuance(BHOBNBPair::get().dex_share_currency_id(),), 2_000_000u128);
+		assert_eq!(Dex::total_balance(BHO, &BOB), 1_000_000_000u128);
+		assert_eq!(Dex::total_balance(BNB, &BOB), 1_000_000_000u128);
+		System::assert_last_event(Event::Dex(crate::Event::RemoveLiqudity(
+			BOB,
+			BNB,
+			1_000_000u128,
+			BHO,
+			200_000u128,
+			2_000_000u128,
+		)));
+	});
 }
 
 #[test]
 fn swap_with_exact_supply_should_work() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-        initialize_tokens(&builder);
-
-        // Swap with path exceeds trading path limit should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT, BTC],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::InvalidTradingPathLength
-        );
-
-        // Swap with path length that not at least form a trading pair should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(Origin::signed(ALICE), vec![], 1_000u128, 1_000u128),
-            Error::<Runtime>::InvalidTradingPathLength
-        );
-        assert_noop!(
-            Dex::swap_with_exact_supply(Origin::signed(ALICE), vec![BHO], 1_000u128, 1_000u128),
-            Error::<Runtime>::InvalidTradingPathLength
-        );
-
-        // Swap with trading path containing at least disabled/provisioning trading pair should throw
-        // error
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::TradingPairMustBeEnabled
-        );
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::TradingPairMustBeEnabled
-        );
-
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
-
-        // Swap with trading path that has zero liquidity (BHO-BNB) should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::InsufficientLiquidity
-        );
-
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            100_000_000u128,
-            200_000_000u128
-        ));
-
-        // Swap zero supply amount should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(Origin::signed(ALICE), vec![BHO, BNB], 0u128, 0u128),
-            Error::<Runtime>::ZeroTargetAmount
-        );
-
-        // Swap with trading path that has zero liquidity (BNB-DOT) should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::InsufficientLiquidity
-        );
-
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(ALICE),
-            BNB,
-            DOT,
-            100_000_000u128,
-            200_000_000u128
-        ));
-
-        // Alice swap BHO for BNB but her balance is not enough. This should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB],
-                1_000_000_000u128,
-                0u128
-            ),
-            pallet_balances::Error::<Runtime>::InsufficientBalance
-        );
-
-        // Alice swaps BHO for BNB but she wants to receive at least BNB greater than what dex can
-        // offer for current exchange rate and fee. This should throw error
-        assert_noop!(
-            Dex::swap_with_exact_supply(
-                Origin::signed(ALICE),
-                vec![BHO, BNB],
-                100_000_000u128,
-                200_000_000u128
-            ),
-            Error::<Runtime>::InsufficientTargetAmount
-        );
-
-        // Alice swaps successfully
-        assert_ok!(Dex::swap_with_exact_supply(
-            Origin::signed(ALICE),
-            vec![BHO, BNB],
-            100_000_000u128,
-            90_000_000u128
-        ));
-        System::assert_last_event(Event::Dex(crate::Event::Swap(
-            ALICE,
-            vec![BHO, BNB],
-            100_000_000u128,
-            94_736_842u128,
-        )));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (105_263_158u128, 200_000_000u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &ALICE),
-            800_000_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &ALICE),
-            794_736_842u128
-        );
-
-        // Alice swaps BHO for DOT (BHO-BNB-DOT) successfully
-        assert_ok!(Dex::swap_with_exact_supply(
-            Origin::signed(ALICE),
-            vec![BHO, BNB, DOT],
-            100_000_000u128,
-            40_000_000u128
-        ));
-        System::assert_last_event(Event::Dex(crate::Event::Swap(
-            ALICE,
-            vec![BHO, BNB, DOT],
-            100_000_000u128,
-            45_441_794u128,
-        )));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (72_595_282u128, 300_000_000u128)
-        );
-        assert_eq!(
-            Dex::liquidity_pool(BNBDOTPair::get()),
-            (154_558_206u128, 132_667_876u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &ALICE),
-            700_000_000u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(DOT, &ALICE),
-            845_441_794u128
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+
+		// Swap with path exceeds trading path limit should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT, BTC],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+
+		// Swap with path length that not at least form a trading pair should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(Origin::signed(ALICE), vec![], 1_000u128, 1_000u128),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			Dex::swap_with_exact_supply(Origin::signed(ALICE), vec![BHO], 1_000u128, 1_000u128),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+
+		// Swap with trading path containing at least disabled/provisioning trading pair should
+		// throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
+
+		// Swap with trading path that has zero liquidity (BHO-BNB) should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			100_000_000u128,
+			200_000_000u128
+		));
+
+		// Swap zero supply amount should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(Origin::signed(ALICE), vec![BHO, BNB], 0u128, 0u128),
+			Error::<Runtime>::ZeroTargetAmount
+		);
+
+		// Swap with trading path that has zero liquidity (BNB-DOT) should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BNB,
+			DOT,
+			100_000_000u128,
+			200_000_000u128
+		));
+
+		// Alice swap BHO for BNB but her balance is not enough. This should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB],
+				1_000_000_000u128,
+				0u128
+			),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+
+		// Alice swaps BHO for BNB but she wants to receive at least BNB greater than what dex can
+		// offer for current exchange rate and fee. This should throw error
+		assert_noop!(
+			Dex::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BHO, BNB],
+				100_000_000u128,
+				200_000_000u128
+			),
+			Error::<Runtime>::InsufficientTargetAmount
+		);
+
+		// Alice swaps successfully
+		assert_ok!(Dex::swap_with_exact_supply(
+			Origin::signed(ALICE),
+			vec![BHO, BNB],
+			100_000_000u128,
+			90_000_000u128
+		));
+		System::assert_last_event(Event::Dex(crate::Event::Swap(
+			ALICE,
+			vec![BHO, BNB],
+			100_000_000u128,
+			94_736_842u128,
+		)));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (105_263_158u128, 200_000_000u128));
+		assert_eq!(Dex::total_balance(BHO, &ALICE), 800_000_000u128);
+		assert_eq!(Dex::total_balance(BNB, &ALICE), 794_736_842u128);
+
+		// Alice swaps BHO for DOT (BHO-BNB-DOT) successfully
+		assert_ok!(Dex::swap_with_exact_supply(
+			Origin::signed(ALICE),
+			vec![BHO, BNB, DOT],
+			100_000_000u128,
+			40_000_000u128
+		));
+		System::assert_last_event(Event::Dex(crate::Event::Swap(
+			ALICE,
+			vec![BHO, BNB, DOT],
+			100_000_000u128,
+			45_441_794u128,
+		)));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (72_595_282u128, 300_000_000u128));
+		assert_eq!(Dex::liquidity_pool(BNBDOTPair::get()), (154_558_206u128, 132_667_876u128));
+		assert_eq!(Dex::total_balance(BHO, &ALICE), 700_000_000u128);
+		assert_eq!(Dex::total_balance(DOT, &ALICE), 845_441_794u128);
+	});
 }
 
 #[test]
 fn swap_with_exact_target_amount() {
-    let builder = ExtBuilder::default();
-    builder.build().execute_with(|| {
-        System::set_block_number(1);
-        initialize_tokens(&builder);
-
-        // Swap with path exceeds trading path limit should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT, BTC],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::InvalidTradingPathLength
-        );
-
-        // Swap with path length that not at least form a trading pair should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(Origin::signed(ALICE), vec![], 1_000u128, 1_000u128),
-            Error::<Runtime>::InvalidTradingPathLength
-        );
-        assert_noop!(
-            Dex::swap_with_exact_target(Origin::signed(ALICE), vec![BHO], 1_000u128, 1_000u128),
-            Error::<Runtime>::InvalidTradingPathLength
-        );
-
-        // Swap with trading path containing at least disabled/provisioning trading pair should throw
-        // error
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::TradingPairMustBeEnabled
-        );
-        assert_ok!(Dex::start_trading_pair_provisioning(
-            Origin::root(),
-            BHO,
-            BNB,
-            1_000u128,
-            2_000u128,
-            1_000u128,
-            2_000u128
-        ));
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::TradingPairMustBeEnabled
-        );
-
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
-        assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
-
-        // Swap with trading path that has zero liquidity (BHO-BNB) should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::InsufficientLiquidity
-        );
-
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(ALICE),
-            BHO,
-            BNB,
-            100_000_000u128,
-            200_000_000u128
-        ));
-
-        // Swap zero target amount should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(Origin::signed(ALICE), vec![BHO, BNB], 0u128, 0u128),
-            Error::<Runtime>::ZeroSupplyAmount
-        );
-
-        // Swap with trading path that has zero liquidity (BNB-DOT) should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                1_000u128,
-                1_000u128
-            ),
-            Error::<Runtime>::InsufficientLiquidity
-        );
-
-        assert_ok!(Dex::add_liquidity(
-            Origin::signed(ALICE),
-            BNB,
-            DOT,
-            100_000_000u128,
-            200_000_000u128
-        ));
-
-        // Alice swap BHO for BNB but her target amount is larger than the pool. This should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB],
-                1_000_000_000u128,
-                0u128
-            ),
-            Error::<Runtime>::InsufficientLiquidity
-        );
-
-        // Alice swaps BHO for BNB but she wants to spend at most some BHO that is smaller than what dex can
-        // offer for current exchange rate and fee. This should throw error
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB],
-                100_000_000u128,
-                100_000_000u128
-            ),
-            Error::<Runtime>::InsufficientSupplyAmount
-        );
-
-        // Alice swaps successfully
-        assert_ok!(Dex::swap_with_exact_target(
-            Origin::signed(ALICE),
-            vec![BHO, BNB],
-            100_000_000u128,
-            120_000_000u128
-        ));
-        System::assert_last_event(Event::Dex(crate::Event::Swap(
-            ALICE,
-            vec![BHO, BNB],
-            111_111_112u128,
-            100_000_000u128,
-        )));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (100_000_000u128, 211_111_112u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &ALICE),
-            788_888_888u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &ALICE),
-            800_000_000u128
-        );
-
-        // Alice swaps BHO for DOT (BHO-BNB-DOT) got error because of insufficient liquidity of
-        // BHO-BNB.
-        assert_noop!(
-            Dex::swap_with_exact_target(
-                Origin::signed(ALICE),
-                vec![BHO, BNB, DOT],
-                100_000_000u128,
-                40_000_000u128
-            ),
-            Error::<Runtime>::InsufficientLiquidity
-        );
-
-        // Alice swaps successfully
-        assert_ok!(Dex::swap_with_exact_target(
-            Origin::signed(ALICE),
-            vec![BHO, BNB, DOT],
-            50_000_000u128,
-            140_000_000u128
-        ));
-        System::assert_last_event(Event::Dex(crate::Event::Swap(
-            ALICE,
-            vec![BHO, BNB, DOT],
-            137_981_125u128,
-            50_000_000u128,
-        )));
-        assert_eq!(
-            Dex::liquidity_pool(BHOBNBPair::get()),
-            (62_962_962u128, 349_092_237u128)
-        );
-        assert_eq!(
-            Dex::liquidity_pool(BNBDOTPair::get()),
-            (150_000_000u128, 137_037_038u128)
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BHO, &ALICE),
-            650_907_763u128
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(BNB, &ALICE),
-            800_000_000u128,
-        );
-        assert_eq!(
-            <Runtime as crate::Config>::Currency::total_balance(DOT, &ALICE),
-            850_000_000u128
-        );
-    });
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+
+		// Swap with path exceeds trading path limit should throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT, BTC],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+
+		// Swap with path length that not at least form a trading pair should throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(Origin::signed(ALICE), vec![], 1_000u128, 1_000u128),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			Dex::swap_with_exact_target(Origin::signed(ALICE), vec![BHO], 1_000u128, 1_000u128),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+
+		// Swap with trading path containing at least disabled/provisioning trading pair should
+		// throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+		assert_ok!(Dex::start_trading_pair_provisioning(
+			Origin::root(),
+			BHO,
+			BNB,
+			1_000u128,
+			2_000u128,
+			1_000u128,
+			2_000u128
+		));
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
+
+		// Swap with trading path that has zero liquidity (BHO-BNB) should throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			100_000_000u128,
+			200_000_000u128
+		));
+
+		// Swap zero target amount should throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(Origin::signed(ALICE), vec![BHO, BNB], 0u128, 0u128),
+			Error::<Runtime>::ZeroSupplyAmount
+		);
+
+		// Swap with trading path that has zero liquidity (BNB-DOT) should throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				1_000u128,
+				1_000u128
+			),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BNB,
+			DOT,
+			100_000_000u128,
+			200_000_000u128
+		));
+
+		// Alice swap BHO for BNB but her target amount is larger than the pool. This should throw
+		// error
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB],
+				1_000_000_000u128,
+				0u128
+			),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		// Alice swaps BHO for BNB but she wants to spend at most some BHO that is smaller than what
+		// dex can offer for current exchange rate and fee. This should throw error
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB],
+				100_000_000u128,
+				100_000_000u128
+			),
+			Error::<Runtime>::InsufficientSupplyAmount
+		);
+
+		// Alice swaps successfully
+		assert_ok!(Dex::swap_with_exact_target(
+			Origin::signed(ALICE),
+			vec![BHO, BNB],
+			100_000_000u128,
+			120_000_000u128
+		));
+		System::assert_last_event(Event::Dex(crate::Event::Swap(
+			ALICE,
+			vec![BHO, BNB],
+			111_111_112u128,
+			100_000_000u128,
+		)));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (100_000_000u128, 211_111_112u128));
+		assert_eq!(Dex::total_balance(BHO, &ALICE), 788_888_888u128);
+		assert_eq!(Dex::total_balance(BNB, &ALICE), 800_000_000u128);
+
+		// Alice swaps BHO for DOT (BHO-BNB-DOT) got error because of insufficient liquidity of
+		// BHO-BNB.
+		assert_noop!(
+			Dex::swap_with_exact_target(
+				Origin::signed(ALICE),
+				vec![BHO, BNB, DOT],
+				100_000_000u128,
+				40_000_000u128
+			),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		// Alice swaps successfully
+		assert_ok!(Dex::swap_with_exact_target(
+			Origin::signed(ALICE),
+			vec![BHO, BNB, DOT],
+			50_000_000u128,
+			140_000_000u128
+		));
+		System::assert_last_event(Event::Dex(crate::Event::Swap(
+			ALICE,
+			vec![BHO, BNB, DOT],
+			137_981_125u128,
+			50_000_000u128,
+		)));
+		assert_eq!(Dex::liquidity_pool(BHOBNBPair::get()), (62_962_962u128, 349_092_237u128));
+		assert_eq!(Dex::liquidity_pool(BNBDOTPair::get()), (150_000_000u128, 137_037_038u128));
+		assert_eq!(Dex::total_balance(BHO, &ALICE), 650_907_763u128);
+		assert_eq!(Dex::total_balance(BNB, &ALICE), 800_000_000u128,);
+		assert_eq!(Dex::total_balance(DOT, &ALICE), 850_000_000u128);
+	});
+}
+
+fn initialize_multi_path_pools() {
+	assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+	assert_ok!(Dex::enable_trading_pair(Origin::root(), BNB, DOT));
+	assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, DOT));
+	assert_ok!(Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000_000u128, 1_000_000u128));
+	assert_ok!(Dex::add_liquidity(Origin::signed(ALICE), BNB, DOT, 1_000_000u128, 1_000_000u128));
+	assert_ok!(Dex::add_liquidity(Origin::signed(ALICE), BHO, DOT, 1_000_000u128, 100_000u128));
+}
+
+#[test]
+fn get_enabled_trading_pairs_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+		assert_eq!(Dex::get_enabled_trading_pairs(), Vec::<TradingPair>::new());
+
+		initialize_multi_path_pools();
+		let mut trading_pairs = Dex::get_enabled_trading_pairs();
+		trading_pairs.sort();
+		let mut expected = vec![
+			BHOBNBPair::get(),
+			BNBDOTPair::get(),
+			TradingPair::from_currency_ids(BHO, DOT).unwrap(),
+		];
+		expected.sort();
+		assert_eq!(trading_pairs, expected);
+
+		crate::TradingPairStatuses::<Runtime>::insert(
+			BNBDOTPair::get(),
+			TradingPairStatus::Disabled,
+		);
+		assert!(!Dex::get_enabled_trading_pairs().contains(&BNBDOTPair::get()));
+	});
+}
+
+#[test]
+fn swap_with_specific_path_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		initialize_multi_path_pools();
+
+		assert_noop!(
+			Dex::swap_with_specific_path(
+				Origin::signed(BOB),
+				vec![BHO, BNB, DOT, BTC],
+				SwapLimit::ExactSupply(1_000, 0)
+			),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			Dex::swap_with_specific_path(
+				Origin::signed(BOB),
+				vec![BHO, BNB, DOT],
+				SwapLimit::ExactSupply(1_000, 900)
+			),
+			Error::<Runtime>::InsufficientTargetAmount
+		);
+
+		let bob_bho_balance = Dex::free_balance(BHO, &BOB);
+		let bob_dot_balance = Dex::free_balance(DOT, &BOB);
+		assert_ok!(Dex::swap_with_specific_path(
+			Origin::signed(BOB),
+			vec![BHO, BNB, DOT],
+			SwapLimit::ExactSupply(1_000, 800)
+		));
+		System::assert_last_event(Event::Dex(crate::Event::Swap(
+			BOB,
+			vec![BHO, BNB, DOT],
+			1_000,
+			808,
+		)));
+		assert_eq!(Dex::free_balance(BHO, &BOB), bob_bho_balance - 1_000);
+		assert_eq!(Dex::free_balance(DOT, &BOB), bob_dot_balance + 808);
+		assert_eq!(Dex::get_liquidity(&BHO, &DOT), (1_000_000, 100_000));
+
+		assert_ok!(Dex::swap_with_specific_path(
+			Origin::signed(BOB),
+			vec![BHO, BNB, DOT],
+			SwapLimit::ExactTarget(1_000, 100)
+		));
+		assert_eq!(Dex::free_balance(DOT, &BOB), bob_dot_balance + 808 + 100);
+	});
+}
+
+type FeeCharger = <Runtime as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+#[test]
+fn pay_fee_in_token_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+		let fee_path: FeePath<Runtime> = vec![BNB, BHO].try_into().unwrap();
+		assert_ok!(ChargeFeeToken::<Runtime>::from(fee_path.clone(), 200)
+			.pre_dispatch(&BOB, &call, &info, 0));
+		assert_eq!(Dex::fee_token_of(&BOB), Some((fee_path, 200)));
+		assert_eq!(Dex::fee_token_of(&ALICE), None);
+
+		let bob_bho_balance = Dex::free_balance(BHO, &BOB);
+		let bob_bnb_balance = Dex::free_balance(BNB, &BOB);
+
+		// Just enough BNB is swapped to pay the fee and the swaps in BHO
+		let liquidity_info = FeeCharger::withdraw_fee(&BOB, &call, &info, 100, 0).unwrap();
+		assert_eq!(Dex::free_balance(BHO, &BOB), bob_bho_balance);
+		assert_eq!(Dex::free_balance(BNB, &BOB), bob_bnb_balance - 115);
+
+		// Overpaid fee is swapped back into BNB
+		assert_ok!(FeeCharger::correct_and_deposit_fee(
+			&BOB,
+			&info,
+			&PostDispatchInfo::default(),
+			60,
+			0,
+			liquidity_info
+		));
+		assert_eq!(Dex::free_balance(BHO, &BOB), bob_bho_balance);
+		assert_eq!(Dex::free_balance(BNB, &BOB), bob_bnb_balance - 115 + 36);
+		assert_eq!(Dex::fee_token_of(&BOB), None);
+	});
+}
+
+#[test]
+fn pay_fee_in_token_charges_swap_weight() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+		let fee_path: FeePath<Runtime> = vec![BNB, BHO].try_into().unwrap();
+
+		// Validation neither records the fee path nor adds weight
+		assert_ok!(
+			ChargeFeeToken::<Runtime>::from(fee_path.clone(), 200).validate(&BOB, &call, &info, 0)
+		);
+		assert_eq!(Dex::fee_token_of(&BOB), None);
+		assert_eq!(System::block_weight().get(DispatchClass::Normal), &0);
+
+		assert_ok!(
+			ChargeFeeToken::<Runtime>::from(fee_path, 200).pre_dispatch(&BOB, &call, &info, 0)
+		);
+		assert_eq!(
+			System::block_weight().get(DispatchClass::Normal),
+			&<() as WeightInfo>::pay_fee_in_token(2)
+		);
+
+		// The weight of the swaps is charged on top of the fee
+		let total_issuance = Balances::total_issuance();
+		let liquidity_info = FeeCharger::withdraw_fee(&BOB, &call, &info, 100, 0).unwrap();
+		assert_ok!(FeeCharger::correct_and_deposit_fee(
+			&BOB,
+			&info,
+			&PostDispatchInfo::default(),
+			60,
+			0,
+			liquidity_info
+		));
+		assert_eq!(Balances::total_issuance(), total_issuance - 60 - 3);
+	});
+}
+
+#[test]
+fn pay_fee_in_token_respects_max_supply_amount() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+		let bob_bho_balance = Dex::free_balance(BHO, &BOB);
+		let bob_bnb_balance = Dex::free_balance(BNB, &BOB);
+
+		let fee_path: FeePath<Runtime> = vec![BNB, BHO].try_into().unwrap();
+		assert_ok!(
+			ChargeFeeToken::<Runtime>::from(fee_path, 100).pre_dispatch(&BOB, &call, &info, 0)
+		);
+		assert_eq!(
+			FeeCharger::withdraw_fee(&BOB, &call, &info, 100, 0).map(|_| ()),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		);
+
+		// Without a fee token the fee is paid in BHO
+		assert_ok!(ChargeFeeToken::<Runtime>::new().pre_dispatch(&BOB, &call, &info, 0));
+		assert_eq!(Dex::fee_token_of(&BOB), None);
+		assert!(FeeCharger::withdraw_fee(&BOB, &call, &info, 100, 0).is_ok());
+		assert_eq!(Dex::free_balance(BHO, &BOB), bob_bho_balance - 100);
+		assert_eq!(Dex::free_balance(BNB, &BOB), bob_bnb_balance);
+	});
+}
+
+#[test]
+fn pay_fee_in_token_rejects_invalid_path() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+
+		// The path must end in native BHO
+		let fee_path: FeePath<Runtime> = vec![BHO, BNB].try_into().unwrap();
+		assert_eq!(
+			ChargeFeeToken::<Runtime>::from(fee_path, 100).validate(&BOB, &call, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		);
+		let fee_path: FeePath<Runtime> = vec![BHO].try_into().unwrap();
+		assert_eq!(
+			ChargeFeeToken::<Runtime>::from(fee_path, 100).pre_dispatch(&BOB, &call, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		);
+		assert_eq!(Dex::fee_token_of(&BOB), None);
+
+		// and can't be longer than `TradingPathLimit`
+		assert!(FeePath::<Runtime>::try_from(vec![DOT, BTC, BNB, BHO]).is_err());
+	});
+}
+
+#[test]
+fn pay_fee_in_token_keeps_refund_in_native_when_price_moves() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+
+		let call = Call::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+		let fee_path: FeePath<Runtime> = vec![BNB, BHO].try_into().unwrap();
+		assert_ok!(
+			ChargeFeeToken::<Runtime>::from(fee_path, 200).pre_dispatch(&BOB, &call, &info, 0)
+		);
+
+		let bob_bho_balance = Dex::free_balance(BHO, &BOB);
+		let bob_bnb_balance = Dex::free_balance(BNB, &BOB);
+		let liquidity_info = FeeCharger::withdraw_fee(&BOB, &call, &info, 100, 0).unwrap();
+
+		// BHO gets cheaper while the call is dispatched
+		assert_ok!(Dex::swap_with_exact_supply(
+			Origin::signed(ALICE),
+			vec![BHO, BNB],
+			100_000u128,
+			0
+		));
+
+		assert_ok!(FeeCharger::correct_and_deposit_fee(
+			&BOB,
+			&info,
+			&PostDispatchInfo::default(),
+			60,
+			0,
+			liquidity_info
+		));
+		assert_eq!(Dex::free_balance(BHO, &BOB), bob_bho_balance + 40);
+		assert_eq!(Dex::free_balance(BNB, &BOB), bob_bnb_balance - 115);
+		assert_eq!(Dex::fee_token_of(&BOB), None);
+	});
+}
+
+#[test]
+fn twap_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			2_000_000u128
+		));
+
+		// Accumulation starts at the first block the trading pair has liquidity
+		assert_eq!(
+			Dex::cumulative_prices(BHOBNBPair::get()),
+			Some((Default::default(), Default::default(), 1))
+		);
+		assert_eq!(Dex::get_twap(BHO, BNB, 1), None);
+
+		System::set_block_number(2);
+		assert_eq!(Dex::get_twap(BHO, BNB, 1), Some(ExchangeRate::saturating_from_integer(2)));
+		assert_eq!(Dex::get_twap(BNB, BHO, 1), Some(ExchangeRate::saturating_from_rational(1, 2)));
+		assert_eq!(Dex::get_twap(BHO, BNB, 2), None);
+		assert_eq!(Dex::get_twap(BHO, BNB, 0), None);
+		assert_eq!(Dex::get_twap(BHO, BHO, 1), None);
+
+		// Swaps only affect the average from the next block on
+		assert_ok!(Dex::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![BHO, BNB],
+			1_000_000u128,
+			0
+		));
+		assert_eq!(Dex::get_twap(BHO, BNB, 1), Some(ExchangeRate::saturating_from_integer(2)));
+		assert_eq!(Dex::price_observation_state(BHOBNBPair::get()), (2, 2));
+
+		let (pool_bho, pool_bnb) = Dex::get_liquidity(&BHO, &BNB);
+		let price = ExchangeRate::checked_from_rational(pool_bnb, pool_bho).unwrap();
+		System::set_block_number(3);
+		assert_eq!(Dex::get_twap(BHO, BNB, 1), Some(price));
+		assert_eq!(
+			Dex::get_twap(BHO, BNB, 2),
+			Some(ExchangeRate::from_inner(
+				(ExchangeRate::saturating_from_integer(2).into_inner() + price.into_inner()) / 2
+			))
+		);
+		assert_eq!(<Dex as TwapProvider<_, _>>::get_twap(BHO, BNB, 2), Dex::get_twap(BHO, BNB, 2));
+
+		// Windows are bounded by `MaxTwapWindow`
+		System::set_block_number(13);
+		assert_eq!(Dex::get_twap(BHO, BNB, 10), Some(price));
+		assert_eq!(Dex::get_twap(BHO, BNB, 11), None);
+
+		// Windows starting between two observations are interpolated
+		assert_ok!(Dex::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![BNB, BHO],
+			1_000_000u128,
+			0
+		));
+		let (pool_bho, pool_bnb) = Dex::get_liquidity(&BHO, &BNB);
+		let new_price = ExchangeRate::checked_from_rational(pool_bnb, pool_bho).unwrap();
+		System::set_block_number(14);
+		assert_eq!(
+			Dex::get_twap(BHO, BNB, 10),
+			Some(ExchangeRate::from_inner((price.into_inner() * 9 + new_price.into_inner()) / 10))
+		);
+	});
+}
+
+#[test]
+fn price_observations_are_bounded() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+
+		// Only the first change of the reserves in a block is observed
+		assert_ok!(Dex::swap_with_exact_supply(Origin::signed(BOB), vec![BHO, BNB], 1_000u128, 0));
+		assert_eq!(Dex::price_observation_state(BHOBNBPair::get()), (1, 1));
+
+		for block_number in 2..=15 {
+			System::set_block_number(block_number);
+			assert_ok!(Dex::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![BHO, BNB],
+				1_000u128,
+				0
+			));
+		}
+
+		// `MaxTwapWindow + 1` observations are kept, overwriting the oldest ones
+		assert_eq!(Dex::price_observation_capacity(), 11);
+		assert_eq!(Dex::price_observation_state(BHOBNBPair::get()), (4, 11));
+		assert_eq!(Dex::price_observations(BHOBNBPair::get(), 3).map(|(b, _, _)| b), Some(15));
+		assert_eq!(Dex::price_observations(BHOBNBPair::get(), 4).map(|(b, _, _)| b), Some(5));
+		assert!(Dex::get_twap(BHO, BNB, 10).is_some());
+	});
+}
+
+#[test]
+fn disable_trading_pair_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+
+		assert_noop!(Dex::disable_trading_pair(Origin::signed(ALICE), BHO, BNB), BadOrigin);
+		assert_noop!(
+			Dex::disable_trading_pair(Origin::root(), BHO, BNB),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+		System::set_block_number(2);
+		assert!(Dex::get_twap(BHO, BNB, 1).is_some());
+
+		assert_ok!(Dex::disable_trading_pair(Origin::root(), BHO, BNB));
+		System::assert_last_event(Event::Dex(crate::Event::TradingPairDisabled(BHOBNBPair::get())));
+		assert_eq!(Dex::get_trading_pair_status(&BHO, &BNB), TradingPairStatus::Disabled);
+
+		// The price history is pruned
+		assert_eq!(Dex::cumulative_prices(BHOBNBPair::get()), None);
+		assert_eq!(Dex::price_observation_state(BHOBNBPair::get()), (0, 0));
+		assert_eq!(Dex::price_observations(BHOBNBPair::get(), 0), None);
+		assert_eq!(Dex::get_twap(BHO, BNB, 1), None);
+
+		// Liquidity can still be removed, but not added
+		assert_noop!(
+			Dex::add_liquidity(Origin::signed(ALICE), BHO, BNB, 1_000u128, 1_000u128),
+			Error::<Runtime>::TradingPairMustBeEnabled
+		);
+		let share_amount = Dex::shares(BHOBNBPair::get(), ALICE);
+		assert_ok!(Dex::remove_liquidity(Origin::signed(ALICE), BHO, BNB, share_amount, 0, 0));
+	});
+}
+
+#[test]
+fn set_trading_pair_fee_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+		assert_eq!(Dex::trading_pair_fee(BHOBNBPair::get()), (10, 100));
+		assert_eq!(Dex::get_target_amounts(&[BHO, BNB], 1_000), Ok(vec![1_000, 899]));
+
+		assert_noop!(
+			Dex::set_trading_pair_fee(Origin::signed(ALICE), BHO, BNB, Some((0, 100))),
+			BadOrigin
+		);
+		assert_noop!(
+			Dex::set_trading_pair_fee(Origin::root(), BHO, BHO, Some((0, 100))),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			Dex::set_trading_pair_fee(Origin::root(), BHO, BNB, Some((100, 100))),
+			Error::<Runtime>::InvalidFee
+		);
+
+		assert_ok!(Dex::set_trading_pair_fee(Origin::root(), BHO, BNB, Some((0, 100))));
+		System::assert_last_event(Event::Dex(crate::Event::TradingPairFeeUpdated(
+			BHOBNBPair::get(),
+			Some((0, 100)),
+		)));
+		assert_eq!(Dex::trading_pair_fee(BHOBNBPair::get()), (0, 100));
+		assert_eq!(Dex::get_target_amounts(&[BHO, BNB], 1_000), Ok(vec![1_000, 999]));
+		assert_eq!(Dex::get_supply_amounts(&[BHO, BNB], 999), Ok(vec![1_000, 999]));
+
+		assert_ok!(Dex::set_trading_pair_fee(Origin::root(), BNB, BHO, None));
+		assert_eq!(Dex::trading_pair_fee(BHOBNBPair::get()), (10, 100));
+		assert_eq!(Dex::get_target_amounts(&[BHO, BNB], 1_000), Ok(vec![1_000, 899]));
+	});
+}
+
+#[test]
+fn protocol_fee_transfer_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+
+		let protocol_fee = ProtocolFee {
+			share: Permill::from_percent(50),
+			destination: ProtocolFeeDestination::Transfer(DOT_ADMIN),
+		};
+		assert_noop!(
+			Dex::set_protocol_fee(Origin::signed(ALICE), Some(protocol_fee.clone())),
+			BadOrigin
+		);
+		assert_ok!(Dex::set_protocol_fee(Origin::root(), Some(protocol_fee.clone())));
+		System::assert_last_event(Event::Dex(crate::Event::ProtocolFeeUpdated(Some(protocol_fee))));
+
+		// Half of the 100 BHO fee is sent to the receiver, the rest stays in the pool
+		let receiver_balance = Dex::free_balance(BHO, &DOT_ADMIN);
+		assert_ok!(Dex::swap_with_exact_supply(Origin::signed(BOB), vec![BHO, BNB], 1_000, 0));
+		System::assert_has_event(Event::Dex(crate::Event::ProtocolFeeCollected(
+			DOT_ADMIN, BHO, 50,
+		)));
+		assert_eq!(Dex::free_balance(BHO, &DOT_ADMIN), receiver_balance + 50);
+		assert_eq!(Dex::get_liquidity(&BHO, &BNB), (1_000_950, 999_101));
+
+		assert_ok!(Dex::set_protocol_fee(Origin::root(), None));
+		assert_ok!(Dex::swap_with_exact_supply(Origin::signed(BOB), vec![BHO, BNB], 1_000, 0));
+		assert_eq!(Dex::free_balance(BHO, &DOT_ADMIN), receiver_balance + 50);
+	});
+}
+
+#[test]
+fn protocol_fee_mint_shares_should_work() {
+	let builder = ExtBuilder::default();
+	builder.build().execute_with(|| {
+		System::set_block_number(1);
+		initialize_tokens(&builder);
+		assert_ok!(Dex::enable_trading_pair(Origin::root(), BHO, BNB));
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			BHO,
+			BNB,
+			1_000_000u128,
+			1_000_000u128
+		));
+		assert_ok!(Dex::set_protocol_fee(
+			Origin::root(),
+			Some(ProtocolFee {
+				share: Permill::from_percent(50),
+				destination: ProtocolFeeDestination::MintShares(DOT_ADMIN),
+			})
+		));
+
+		// The whole fee stays in the pool and shares worth half of it are minted
+		let total_shares = Dex::total_shares(BHOBNBPair::get());
+		let share_amount = total_shares * 50 / (2 * 1_001_000 - 50);
+		assert_ok!(Dex::swap_with_exact_supply(Origin::signed(BOB), vec![BHO, BNB], 1_000, 0));
+		System::assert_has_event(Event::Dex(crate::Event::ProtocolFeeCollected(
+			DOT_ADMIN,
+			BHOBNBPair::get().dex_share_currency_id(),
+			share_amount,
+		)));
+		assert_eq!(Dex::get_liquidity(&BHO, &BNB), (1_001_000, 999_101));
+		assert_eq!(Dex::shares(BHOBNBPair::get(), DOT_ADMIN), share_amount);
+		assert_eq!(Dex::total_shares(BHOBNBPair::get()), total_shares + share_amount);
+	});
 }
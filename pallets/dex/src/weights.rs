@@ -0,0 +1,818 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for bholdus_dex
+//!
+//! NOTE: these are placeholder estimates written by hand, not benchmark output. Regenerate this
+//! file with:
+//!
+//! ./target/release/bholdus benchmark pallet --chain=phoenix-dev --execution=wasm
+//! --wasm-execution=compiled --pallet=bholdus_dex --extrinsic=* --steps=20 --repeat=10
+//! --output=./pallets/dex/src/weights.rs --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for bholdus_dex.
+pub trait WeightInfo {
+	
+	fn swap_with_exact_supply(u: u32, ) -> Weight;
+	
+	fn swap_with_exact_target(u: u32, ) -> Weight;
+	
+	fn start_trading_pair_provisioning() -> Weight;
+	
+	fn update_trading_pair_provisioning_parameters() -> Weight;
+	
+	fn add_trading_pair_provision() -> Weight;
+	
+	fn enable_provisioning_trading_pair() -> Weight;
+	
+	fn enable_trading_pair() -> Weight;
+	
+	fn add_liquidity() -> Weight;
+	
+	fn remove_liquidity() -> Weight;
+	
+	fn claim_dex_share() -> Weight;
+	
+	fn disable_trading_pair(o: u32, ) -> Weight;
+	
+	fn set_trading_pair_fee() -> Weight;
+	
+	fn set_protocol_fee() -> Weight;
+	
+	fn pay_fee_in_token(u: u32, ) -> Weight;
+	
+}
+
+/// Weights for bholdus_dex using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex TradingPairFees (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex ProtocolFees (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn swap_with_exact_supply(u: u32, ) -> Weight {
+		(29_918_000 as Weight)
+			
+			.saturating_add((22_047_000 as Weight).saturating_mul(u as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(u as Weight)))
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(u as Weight)))
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex TradingPairFees (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex ProtocolFees (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn swap_with_exact_target(u: u32, ) -> Weight {
+		(30_544_000 as Weight)
+			
+			.saturating_add((22_391_000 as Weight).saturating_mul(u as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(u as Weight)))
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(u as Weight)))
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	fn start_trading_pair_provisioning() -> Weight {
+		(14_021_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	fn update_trading_pair_provisioning_parameters() -> Weight {
+		(13_876_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	// Storage: Dex ProvisioningPool (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	fn add_trading_pair_provision() -> Weight {
+		(58_340_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	// Storage: Dex TotalShares (r:1 w:1)
+	
+	// Storage: Dex Shares (r:1 w:1)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	// Storage: Dex InitialShareExchangeRate (r:0 w:1)
+	
+	fn enable_provisioning_trading_pair() -> Weight {
+		(38_201_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	fn enable_trading_pair() -> Weight {
+		(13_192_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex TotalShares (r:1 w:1)
+	
+	// Storage: Dex Shares (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn add_liquidity() -> Weight {
+		(73_860_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TotalShares (r:1 w:1)
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex Shares (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn remove_liquidity() -> Weight {
+		(79_517_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex ProvisioningPool (r:1 w:1)
+	
+	// Storage: Dex InitialShareExchangeRate (r:1 w:0)
+	
+	// Storage: Dex Shares (r:2 w:2)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn claim_dex_share() -> Weight {
+		(38_727_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	// Storage: Dex CumulativePrices (r:0 w:1)
+	
+	// Storage: Dex PriceObservationState (r:0 w:1)
+	
+	fn disable_trading_pair(o: u32, ) -> Weight {
+		(17_406_000 as Weight)
+			
+			.saturating_add((1_093_000 as Weight).saturating_mul(o as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+			
+	}
+	
+	
+	// Storage: Dex TradingPairFees (r:0 w:1)
+	
+	fn set_trading_pair_fee() -> Weight {
+		(14_237_000 as Weight)
+			
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex ProtocolFees (r:0 w:1)
+	
+	fn set_protocol_fee() -> Weight {
+		(12_865_000 as Weight)
+			
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex FeeToken (r:1 w:1)
+	
+	// Storage: Dex TradingPairStatuses (r:2 w:0)
+	
+	// Storage: Dex LiquidityPool (r:2 w:2)
+	
+	// Storage: Dex TradingPairFees (r:2 w:0)
+	
+	// Storage: Dex CumulativePrices (r:2 w:2)
+	
+	// Storage: Dex PriceObservationState (r:2 w:2)
+	
+	// Storage: Dex ProtocolFees (r:2 w:0)
+	
+	// Storage: System Account (r:4 w:4)
+	
+	// Storage: Tokens Asset (r:2 w:0)
+	
+	// Storage: Tokens Account (r:4 w:4)
+	
+	// Storage: Dex PriceObservations (r:0 w:2)
+	
+	fn pay_fee_in_token(u: u32, ) -> Weight {
+		(61_088_000 as Weight)
+			
+			.saturating_add((44_782_000 as Weight).saturating_mul(u as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads((12 as Weight).saturating_mul(u as Weight)))
+			
+			
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(u as Weight)))
+			
+	}
+	
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex TradingPairFees (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex ProtocolFees (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn swap_with_exact_supply(u: u32, ) -> Weight {
+		(29_918_000 as Weight)
+			
+			.saturating_add((22_047_000 as Weight).saturating_mul(u as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(u as Weight)))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(u as Weight)))
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex TradingPairFees (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex ProtocolFees (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn swap_with_exact_target(u: u32, ) -> Weight {
+		(30_544_000 as Weight)
+			
+			.saturating_add((22_391_000 as Weight).saturating_mul(u as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(u as Weight)))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(u as Weight)))
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	fn start_trading_pair_provisioning() -> Weight {
+		(14_021_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	fn update_trading_pair_provisioning_parameters() -> Weight {
+		(13_876_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	// Storage: Dex ProvisioningPool (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	fn add_trading_pair_provision() -> Weight {
+		(58_340_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	// Storage: Dex TotalShares (r:1 w:1)
+	
+	// Storage: Dex Shares (r:1 w:1)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	// Storage: Dex InitialShareExchangeRate (r:0 w:1)
+	
+	fn enable_provisioning_trading_pair() -> Weight {
+		(38_201_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	fn enable_trading_pair() -> Weight {
+		(13_192_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex TotalShares (r:1 w:1)
+	
+	// Storage: Dex Shares (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn add_liquidity() -> Weight {
+		(73_860_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TotalShares (r:1 w:1)
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex CumulativePrices (r:1 w:1)
+	
+	// Storage: Dex PriceObservationState (r:1 w:1)
+	
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	
+	// Storage: Dex Shares (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Tokens Asset (r:1 w:0)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	fn remove_liquidity() -> Weight {
+		(79_517_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	
+	// Storage: Dex ProvisioningPool (r:1 w:1)
+	
+	// Storage: Dex InitialShareExchangeRate (r:1 w:0)
+	
+	// Storage: Dex Shares (r:2 w:2)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn claim_dex_share() -> Weight {
+		(38_727_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex TradingPairStatuses (r:1 w:1)
+	
+	// Storage: Dex PriceObservations (r:0 w:1)
+	
+	// Storage: Dex CumulativePrices (r:0 w:1)
+	
+	// Storage: Dex PriceObservationState (r:0 w:1)
+	
+	fn disable_trading_pair(o: u32, ) -> Weight {
+		(17_406_000 as Weight)
+			
+			.saturating_add((1_093_000 as Weight).saturating_mul(o as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+			
+	}
+	
+	
+	// Storage: Dex TradingPairFees (r:0 w:1)
+	
+	fn set_trading_pair_fee() -> Weight {
+		(14_237_000 as Weight)
+			
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex ProtocolFees (r:0 w:1)
+	
+	fn set_protocol_fee() -> Weight {
+		(12_865_000 as Weight)
+			
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Dex FeeToken (r:1 w:1)
+	
+	// Storage: Dex TradingPairStatuses (r:2 w:0)
+	
+	// Storage: Dex LiquidityPool (r:2 w:2)
+	
+	// Storage: Dex TradingPairFees (r:2 w:0)
+	
+	// Storage: Dex CumulativePrices (r:2 w:2)
+	
+	// Storage: Dex PriceObservationState (r:2 w:2)
+	
+	// Storage: Dex ProtocolFees (r:2 w:0)
+	
+	// Storage: System Account (r:4 w:4)
+	
+	// Storage: Tokens Asset (r:2 w:0)
+	
+	// Storage: Tokens Account (r:4 w:4)
+	
+	// Storage: Dex PriceObservations (r:0 w:2)
+	
+	fn pay_fee_in_token(u: u32, ) -> Weight {
+		(61_088_000 as Weight)
+			
+			.saturating_add((44_782_000 as Weight).saturating_mul(u as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads((12 as Weight).saturating_mul(u as Weight)))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(u as Weight)))
+			
+	}
+	
+}
\ No newline at end of file
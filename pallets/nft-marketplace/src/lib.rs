@@ -8,8 +8,7 @@ use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
 
 use sp_runtime::{
-	traits::{Saturating, StaticLookup},
-	ArithmeticError, DispatchResult, FixedPointNumber, FixedU128, RuntimeDebug,
+	traits::Saturating, ArithmeticError, DispatchResult, FixedPointNumber, FixedU128, RuntimeDebug,
 };
 use sp_std::{if_std, prelude::*, vec::Vec};
 
@@ -19,20 +18,23 @@ use sp_std::{if_std, prelude::*, vec::Vec};
 mod mock;
 #[cfg(test)]
 mod tests;
-// mod weights;
+pub mod weights;
 
-use bholdus_support::MultiCurrency;
 use common_primitives::Balance;
 
+use bholdus_support_nft::RoyaltyRecipientOf;
 use bholdus_support_nft_marketplace::{
-	AuctionType, BHC20TokenId, Blacklist as NFTBlacklist, Denominator, FixedPriceListing,
-	FixedPriceListingInfo, FixedPriceListingInfoOf, MarketMode, MarketplaceFee, MarketplaceFeeInfo,
-	MarketplaceFeeInfoOf, NFTCurrencyId, NFTState, Numerator, Permission, Price, RoleType,
-	RoyaltyRate, TimeAuctionListingInfo, TimeAuctionListingInfoOf, UserBlacklist,
+	AuctionBid, AuctionBidOf, AuctionBids, AuctionType, BHC20TokenId, Blacklist as NFTBlacklist,
+	Denominator, DutchAuction, DutchAuctionListingInfo, DutchAuctionListingInfoOf,
+	FixedPriceListing, FixedPriceListingInfo, FixedPriceListingInfoOf, MarketMode, MarketplaceFee,
+	MarketplaceFeeInfo, MarketplaceFeeInfoOf, NFTCurrencyId, NFTState, Numerator, OfferInfoOf,
+	Offers, Permission, Price, RoleType, RoyaltyRate, TimeAuction, TimeAuctionListingInfo,
+	TimeAuctionListingInfoOf, UserBlacklist,
 };
 
 pub mod pallet;
-pub use pallet::{pallet::*, FixedPriceSetting};
+pub use pallet::{pallet::*, DutchAuctionSetting, FixedPriceSetting, TimeAuctionSetting};
+pub use weights::WeightInfo;
 
 pub type TokenIdOf<T> = <T as bholdus_support_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as bholdus_support_nft::Config>::ClassId;
@@ -69,6 +71,20 @@ impl<T: Config> Pallet<T> {
 		Self::check_new_listing(who, token, MarketMode::Auction(AuctionType::English))
 	}
 
+	pub fn check_new_dutch_auction(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		info: &DutchAuctionSettingOf<T>,
+	) -> DispatchResult {
+		let now = T::Time::now();
+		ensure!(
+			now < info.auction_end && info.auction_start < info.auction_end,
+			Error::<T>::InvalidTimeConfiguration
+		);
+		ensure!(info.floor_price <= info.start_price, Error::<T>::BadPrice);
+		Self::check_new_listing(who, token, MarketMode::Auction(AuctionType::Dutch))
+	}
+
 	pub fn is_available(time: MomentOf<T>) -> DispatchResult {
 		let now = T::Time::now();
 		ensure!(now < time, Error::<T>::ExpiredListing);
@@ -100,6 +116,13 @@ impl<T: Config> Pallet<T> {
 		bholdus_support_nft_marketplace::Pallet::<T>::new_time_auction(token, info)
 	}
 
+	pub fn new_dutch_auction(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		info: &DutchAuctionListingInfoOf<T>,
+	) -> DispatchResult {
+		bholdus_support_nft_marketplace::Pallet::<T>::new_dutch_auction(token, info)
+	}
+
 	pub fn new_fixed_price(
 		token: (ClassIdOf<T>, TokenIdOf<T>),
 		info: &FixedPriceListingInfoOf<T>,
@@ -162,10 +185,48 @@ impl<T: Config> Pallet<T> {
 
 	pub fn get_royalty_value(
 		token: (ClassIdOf<T>, TokenIdOf<T>),
-		account: &T::AccountId,
-		royalty: Option<(Numerator, Denominator)>,
-	) -> (T::AccountId, RoyaltyRate) {
-		bholdus_support_nft_marketplace::Pallet::<T>::get_royalty_value(token, account, royalty)
+	) -> (Vec<RoyaltyRecipientOf<T>>, RoyaltyRate) {
+		bholdus_support_nft_marketplace::Pallet::<T>::get_royalty_value(token)
+	}
+
+	pub fn royalty_amount(price: Balance, recipients: &[RoyaltyRecipientOf<T>]) -> Balance {
+		bholdus_support_nft_marketplace::Pallet::<T>::royalty_amount(price, recipients)
+	}
+
+	pub fn pay_royalties(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		from: &T::AccountId,
+		price: Balance,
+		recipients: &[RoyaltyRecipientOf<T>],
+	) -> DispatchResult {
+		bholdus_support_nft_marketplace::Pallet::<T>::pay_royalties(
+			currency_id,
+			from,
+			price,
+			recipients,
+		)
+	}
+
+	pub fn dutch_auction_price(info: &DutchAuctionListingInfoOf<T>) -> Balance {
+		bholdus_support_nft_marketplace::Pallet::<T>::dutch_auction_price(info)
+	}
+
+	pub fn free_balance(currency_id: NFTCurrencyId<BHC20TokenId>, who: &T::AccountId) -> Balance {
+		bholdus_support_nft_marketplace::Pallet::<T>::free_balance(currency_id, who)
+	}
+
+	pub fn transfer_currency(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		bholdus_support_nft_marketplace::Pallet::<T>::transfer_currency(
+			currency_id,
+			from,
+			to,
+			amount,
+		)
 	}
 
 	pub fn calc_amount(amount: Balance, rate: (Numerator, Denominator)) -> Balance {
@@ -179,4 +240,8 @@ impl<T: Config> Pallet<T> {
 	pub fn is_existed(token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
 		bholdus_support_nft_marketplace::Pallet::<T>::is_existed(token)
 	}
+
+	pub fn is_metadata_frozen(token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		bholdus_support_nft_marketplace::Pallet::<T>::is_metadata_frozen(token)
+	}
 }
@@ -94,6 +94,7 @@ parameter_types! {
 	pub const StringLimit: u32 = 50;
 	pub const MetadataDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
+	pub const MaxLocks: u32 = 50;
 }
 
 impl bholdus_tokens::Config for Runtime {
@@ -117,11 +118,17 @@ impl bholdus_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type MaxDecimals = MaxDecimals;
+	type MaxLocks = MaxLocks;
+}
+
+parameter_types! {
+	pub const MaxTransferAllCurrencies: u32 = 10;
 }
 
 impl bholdus_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
+	type MaxTransferAllCurrencies = MaxTransferAllCurrencies;
 	type WeightInfo = ();
 }
 
@@ -134,6 +141,9 @@ parameter_types! {
 	pub const NftPalletId: PalletId = PalletId(*b"bho/bNFT");
 	pub MaxAttributesBytes: u32 = 10;
 	pub MaxQuantity: u32 = 100;
+	pub MaxRoyaltyRecipients: u32 = 3;
+	pub MaxRoyalty: u32 = 5_000;
+	pub MaxClassMinters: u32 = 2;
 }
 
 impl bholdus_nft::Config for Runtime {
@@ -141,6 +151,10 @@ impl bholdus_nft::Config for Runtime {
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
 	type MaxQuantity = MaxQuantity;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxClassMinters = MaxClassMinters;
+	type Currency = Balances;
 	type WeightInfo = ();
 }
 
@@ -164,8 +178,16 @@ impl bholdus_support_nft_marketplace::Config for Runtime {
 	type Currency = Currencies;
 }
 
+parameter_types! {
+	pub const MinimumBidIncrement: (u32, u32) = (500, 10_000);
+	pub const OfferDeposit: Balance = 10;
+}
+
 impl Config for Runtime {
 	type Event = Event;
+	type MinimumBidIncrement = MinimumBidIncrement;
+	type OfferDeposit = OfferDeposit;
+	type WeightInfo = ();
 }
 
 use frame_system::Call as SystemCall;
@@ -4,7 +4,15 @@ pub struct TimeAuctionSetting<CurrencyId, Moment> {
 	pub min_price: Balance,
 	pub currency_id: CurrencyId,
 	pub auction_end: Moment,
-	pub royalty: Option<(u32, u32)>,
+}
+
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct DutchAuctionSetting<CurrencyId, Moment> {
+	pub start_price: Balance,
+	pub floor_price: Balance,
+	pub currency_id: CurrencyId,
+	pub auction_start: Moment,
+	pub auction_end: Moment,
 }
 
 impl<T: Config> Pallet<T> {
@@ -15,8 +23,7 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		let fee_info = MarketplaceFee::<T>::get().ok_or(Error::<T>::NotFoundServiceFee)?;
 		let (f_numerator, f_denominator) = fee_info.service_fee;
-		let (royalty_recipient, (r_numerator, r_denominator)) =
-			Self::get_royalty_value(token, &owner, info.royalty);
+		let (royalty_recipients, (r_numerator, r_denominator)) = Self::get_royalty_value(token);
 		let fee_rate = FixedU128::checked_from_rational(f_numerator, f_denominator)
 			.ok_or(ArithmeticError::Overflow)?;
 		let royalty_rate = FixedU128::checked_from_rational(r_numerator, r_denominator)
@@ -37,7 +44,7 @@ impl<T: Config> Pallet<T> {
 			auction_end: info.auction_end,
 			service_fee: (f_numerator, f_denominator),
 			fee_recipient: fee_info.beneficiary,
-			royalty_recipient,
+			royalty_recipients,
 			bid_count: 0u32,
 		};
 		Self::new_time_auction(token, &listing_info)?;
@@ -45,4 +52,109 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::NewTimeAuctionNFTListing { token, listing_info });
 		Ok(())
 	}
+	pub fn do_place_bid(
+		bidder: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(Self::is_existed(token), Error::<T>::NotFound);
+		let owner = Self::owner(token);
+		let listing_info =
+			TimeAuction::<T>::get((&owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
+		Self::is_available(listing_info.auction_end)?;
+
+		ensure!(listing_info.status == NFTState::Listing, Error::<T>::NotFound);
+		ensure!(bidder != listing_info.owner, Error::<T>::CannotBid);
+		ensure!(!Self::is_banned_user(&bidder), Error::<T>::UserBanned);
+
+		// The first bid must reach the minimum price, the next ones must outbid the highest bid
+		// by at least the minimum increment.
+		let min_amount = match AuctionBids::<T>::get(token.0, token.1) {
+			Some(highest_bid) => highest_bid.amount.saturating_add(
+				Self::calc_amount(highest_bid.amount, T::MinimumBidIncrement::get()).max(1),
+			),
+			None => listing_info.min_price,
+		};
+		ensure!(amount >= min_amount, Error::<T>::BidTooLow);
+
+		let bid = AuctionBid { bidder: bidder.clone(), amount };
+		bholdus_support_nft_marketplace::Pallet::<T>::new_bid(&owner, token, bid)?;
+		Self::deposit_event(Event::BidPlaced { token, bidder, amount });
+		Ok(())
+	}
+
+	pub fn do_settle_time_auction(token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		ensure!(Self::is_existed(token), Error::<T>::NotFound);
+		let owner = Self::owner(token);
+		let listing_info =
+			TimeAuction::<T>::get((&owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
+		ensure!(T::Time::now() >= listing_info.auction_end, Error::<T>::AuctionNotEnded);
+
+		match bholdus_support_nft_marketplace::Pallet::<T>::conclude_time_auction(&owner, token)? {
+			Some(bid) => Self::deposit_event(Event::TimeAuctionFulfilled { token, owner, bid }),
+			None => Self::deposit_event(Event::TimeAuctionEnded { token, owner }),
+		}
+		Ok(())
+	}
+	pub fn do_create_dutch_auction(
+		owner: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		info: DutchAuctionSettingOf<T>,
+	) -> DispatchResult {
+		let fee_info = MarketplaceFee::<T>::get().ok_or(Error::<T>::NotFoundServiceFee)?;
+		let (f_numerator, f_denominator) = fee_info.service_fee;
+		let (royalty_recipients, (r_numerator, r_denominator)) = Self::get_royalty_value(token);
+		let fee_rate = FixedU128::checked_from_rational(f_numerator, f_denominator)
+			.ok_or(ArithmeticError::Overflow)?;
+		let royalty_rate = FixedU128::checked_from_rational(r_numerator, r_denominator)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			fee_rate.saturating_add(royalty_rate) <
+				FixedU128::checked_from_rational(10_000, 10_000)
+					.ok_or(ArithmeticError::Overflow)?,
+			Error::<T>::InvalidRate
+		);
+		let listing_info = DutchAuctionListingInfo {
+			owner,
+			start_price: info.start_price,
+			floor_price: info.floor_price,
+			currency_id: info.currency_id,
+			royalty: (r_numerator, r_denominator),
+			status: NFTState::Pending,
+			auction_start: info.auction_start,
+			auction_end: info.auction_end,
+			service_fee: (f_numerator, f_denominator),
+			fee_recipient: fee_info.beneficiary,
+			royalty_recipients,
+		};
+		Self::new_dutch_auction(token, &listing_info)?;
+		Self::deposit_event(Event::NewDutchAuctionNFTListing { token, listing_info });
+		Ok(())
+	}
+
+	pub fn do_buy_dutch_auction(
+		buyer: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		max_price: Balance,
+	) -> DispatchResult {
+		ensure!(Self::is_existed(token), Error::<T>::NotFound);
+		let owner = Self::owner(token);
+		let listing_info =
+			DutchAuction::<T>::get((&owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
+		Self::is_available(listing_info.auction_end)?;
+
+		ensure!(listing_info.status == NFTState::Listing, Error::<T>::NotFound);
+		ensure!(buyer != listing_info.owner, Error::<T>::CannotBuyNFT);
+		ensure!(!Self::is_banned_user(&buyer), Error::<T>::UserBanned);
+
+		let price = Self::dutch_auction_price(&listing_info);
+		ensure!(price <= max_price, Error::<T>::BadPrice);
+
+		bholdus_support_nft_marketplace::Pallet::<T>::conclude_dutch_auction(
+			&owner, token, &buyer, price,
+		)?;
+		Self::deposit_event(Event::DutchAuctionFulfilled { token, owner, buyer, price });
+		Ok(())
+	}
 }
@@ -2,8 +2,10 @@ use super::*;
 
 pub mod auction;
 pub mod fixed_price;
+pub mod offer;
 pub use auction::*;
 pub use fixed_price::*;
+pub use offer::*;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -18,11 +20,24 @@ pub mod pallet {
 		+ pallet_balances::Config<Balance = Balance>
 	{
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Minimum increment of a new bid over the highest bid of a time auction
+		#[pallet::constant]
+		type MinimumBidIncrement: Get<(Numerator, Denominator)>;
+
+		/// Deposit reserved in native currency from the offerer for the storage of an offer
+		#[pallet::constant]
+		type OfferDeposit: Get<Balance>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
     	}
 
 	pub type MomentOf<T> = <<T as bholdus_support_nft_marketplace::Config>::Time as Time>::Moment;
 	pub type FixedPriceSettingOf<T> = FixedPriceSetting<NFTCurrencyId<BHC20TokenId>, MomentOf<T>>;
 	pub type TimeAuctionSettingOf<T> = TimeAuctionSetting<NFTCurrencyId<BHC20TokenId>, MomentOf<T>>;
+	pub type DutchAuctionSettingOf<T> =
+		DutchAuctionSetting<NFTCurrencyId<BHC20TokenId>, MomentOf<T>>;
 	#[pallet::error]
 	pub enum Error<T> {
 		IsListing,
@@ -47,6 +62,12 @@ pub mod pallet {
 		MissingRole,
 		MissingPermission,
 		InvalidRole,
+		CannotBid,
+		BidTooLow,
+		AuctionNotEnded,
+		AuctionHasBids,
+		OfferExists,
+		OfferNotExpired,
 	}
 
 	#[pallet::event]
@@ -74,6 +95,12 @@ pub mod pallet {
 			listing_info: TimeAuctionListingInfoOf<T>,
 		},
 
+		/// Emit Dutch Auction Listing Event
+		NewDutchAuctionNFTListing {
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			listing_info: DutchAuctionListingInfoOf<T>,
+		},
+
 		/// Approve listing
 		ListingApproved { controller: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>) },
 
@@ -90,6 +117,45 @@ pub mod pallet {
 			order: FixedPriceListingInfoOf<T>,
 		},
 
+		/// Place a bid on a time auction
+		BidPlaced { token: (ClassIdOf<T>, TokenIdOf<T>), bidder: T::AccountId, amount: Balance },
+
+		/// Time auction settled, the NFT was sold to the highest bidder
+		TimeAuctionFulfilled {
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			owner: T::AccountId,
+			bid: AuctionBidOf<T>,
+		},
+
+		/// Time auction ended without any bid
+		TimeAuctionEnded { token: (ClassIdOf<T>, TokenIdOf<T>), owner: T::AccountId },
+
+		/// Dutch auction sold to the first buyer at the current price
+		DutchAuctionFulfilled {
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			owner: T::AccountId,
+			buyer: T::AccountId,
+			price: Balance,
+		},
+
+		/// Make an offer on a NFT
+		OfferMade {
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			offerer: T::AccountId,
+			offer: OfferInfoOf<T>,
+		},
+
+		/// Offer accepted by the NFT owner
+		OfferAccepted {
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			owner: T::AccountId,
+			offerer: T::AccountId,
+			offer: OfferInfoOf<T>,
+		},
+
+		/// Expired offer withdrawn by the offerer
+		OfferWithdrawn { token: (ClassIdOf<T>, TokenIdOf<T>), offerer: T::AccountId },
+
 		/// Add a NFT item to blacklist
 		NFTBanned { controller: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), reason: Vec<u8> },
 
@@ -259,6 +325,30 @@ pub mod pallet {
 			Self::do_create_time_auction(who, token, info)
 		}
 
+		#[pallet::weight(T::WeightInfo::create_dutch_auction_listing())]
+		#[transactional]
+		pub fn create_dutch_auction_listing(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			info: DutchAuctionSettingOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::check_new_dutch_auction(&who, token, &info)?;
+			Self::do_create_dutch_auction(who, token, info)
+		}
+
+		/// Buy a dutch auction at the current price, failing if it exceeds `max_price`.
+		#[pallet::weight(T::WeightInfo::buy_dutch_auction())]
+		#[transactional]
+		pub fn buy_dutch_auction(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			max_price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_buy_dutch_auction(who, token, max_price)
+		}
+
 		#[pallet::weight(0)]
 		#[transactional]
 		pub fn buy_now(
@@ -269,6 +359,62 @@ pub mod pallet {
 			Self::buy_fixed_price(origin, token)
 		}
 
+		#[pallet::weight(T::WeightInfo::place_bid())]
+		#[transactional]
+		pub fn place_bid(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_place_bid(who, token, amount)
+		}
+
+		/// Settle a time auction after it ended. Anyone can settle it.
+		#[pallet::weight(T::WeightInfo::settle_time_auction())]
+		#[transactional]
+		pub fn settle_time_auction(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_settle_time_auction(token)
+		}
+
+		/// Make an offer on any existing NFT, reserving the offered amount and `OfferDeposit`
+		/// until the offer is accepted or withdrawn.
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		#[transactional]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			info: OfferInfoOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_make_offer(who, token, info)
+		}
+
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			offerer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_accept_offer(who, token, offerer)
+		}
+
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		#[transactional]
+		pub fn withdraw_offer(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw_offer(who, token)
+		}
+
 		#[pallet::weight(0)]
 		#[transactional]
 		pub fn approve_listing(
@@ -314,6 +460,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(Self::is_owner(&who, token), Error::<T>::NoPermission);
+			ensure!(!AuctionBids::<T>::contains_key(token.0, token.1), Error::<T>::AuctionHasBids);
 			Self::delist(&who, token)?;
 			Self::deposit_event(Event::CancelledListing { account: who, token, reason });
 			Ok(())
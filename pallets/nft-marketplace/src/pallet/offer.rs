@@ -0,0 +1,79 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	pub fn do_make_offer(
+		offerer: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		info: OfferInfoOf<T>,
+	) -> DispatchResult {
+		let now = T::Time::now();
+		ensure!(now < info.expired_time, Error::<T>::InvalidTimeConfiguration);
+		ensure!(info.amount > 0, Error::<T>::BadPrice);
+		ensure!(Self::is_existed(token), Error::<T>::NotFound);
+		ensure!(!Self::is_owner(&offerer, token), Error::<T>::CannotBuyNFT);
+		ensure!(!Self::is_banned_user(&offerer), Error::<T>::UserBanned);
+		ensure!(!Self::is_banned(token), Error::<T>::NFTBanned);
+
+		// An offer can only be replaced once it expired, so that the owner always accepts the
+		// offer they saw.
+		if let Some((offer, _)) = Offers::<T>::get((token.0, token.1, &offerer)) {
+			ensure!(now >= offer.expired_time, Error::<T>::OfferExists);
+		}
+
+		bholdus_support_nft_marketplace::Pallet::<T>::new_offer(
+			token,
+			&offerer,
+			&info,
+			T::OfferDeposit::get(),
+		)?;
+		Self::deposit_event(Event::OfferMade { token, offerer, offer: info });
+		Ok(())
+	}
+
+	pub fn do_accept_offer(
+		owner: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		offerer: T::AccountId,
+	) -> DispatchResult {
+		ensure!(Self::is_owner(&owner, token), Error::<T>::NoPermission);
+		ensure!(!Self::is_lock(&owner, token), Error::<T>::IsListing);
+		ensure!(!Self::is_banned_user(&owner), Error::<T>::UserBanned);
+		ensure!(!Self::is_banned(token), Error::<T>::NFTBanned);
+		let fee_info = MarketplaceFee::<T>::get().ok_or(Error::<T>::NotFoundServiceFee)?;
+		let (f_numerator, f_denominator) = fee_info.service_fee;
+		let (_, (r_numerator, r_denominator)) = Self::get_royalty_value(token);
+		let fee_rate = FixedU128::checked_from_rational(f_numerator, f_denominator)
+			.ok_or(ArithmeticError::Overflow)?;
+		let royalty_rate = FixedU128::checked_from_rational(r_numerator, r_denominator)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			fee_rate.saturating_add(royalty_rate) <
+				FixedU128::checked_from_rational(10_000, 10_000)
+					.ok_or(ArithmeticError::Overflow)?,
+			Error::<T>::InvalidRate
+		);
+
+		let (offer, _) =
+			Offers::<T>::get((token.0, token.1, &offerer)).ok_or(Error::<T>::NotFound)?;
+		Self::is_available(offer.expired_time)?;
+
+		let offer =
+			bholdus_support_nft_marketplace::Pallet::<T>::accept_offer(&owner, token, &offerer)?;
+		Self::deposit_event(Event::OfferAccepted { token, owner, offerer, offer });
+		Ok(())
+	}
+
+	pub fn do_withdraw_offer(
+		offerer: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let (offer, _) =
+			Offers::<T>::get((token.0, token.1, &offerer)).ok_or(Error::<T>::NotFound)?;
+		ensure!(T::Time::now() >= offer.expired_time, Error::<T>::OfferNotExpired);
+
+		bholdus_support_nft_marketplace::Pallet::<T>::remove_offer(token, &offerer)?;
+		Self::deposit_event(Event::OfferWithdrawn { token, offerer });
+		Ok(())
+	}
+}
@@ -1,13 +1,17 @@
 #[cfg(test)]
 use super::*;
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, ReservableCurrency},
+};
 use mock::{Event, *};
 use sp_std::collections::btree_map::BTreeMap;
 
-use bholdus_support_nft::{Error as SupportNFTError, TokenInfo};
+use bholdus_support::{MultiCurrency, MultiReservableCurrency};
+use bholdus_support_nft::{Error as SupportNFTError, RoyaltyRecipient, TokenInfo};
 use bholdus_support_nft_marketplace::{
 	Error as SupportNFTMarketplaceError, ItemListing as SupportNFTMItemListing, ManagerRole,
-	MemberRole, NFTState,
+	MemberRole, NFTState, OfferInfo,
 };
 use common_primitives::Balance;
 use sp_runtime::{traits::BlakeTwo256, ArithmeticError};
@@ -25,8 +29,8 @@ fn create_nft() {
 	let metadata = vec![1];
 	let token_attr = test_attr(1u8);
 
-	assert_ok!(NFT::create_class(Origin::signed(ALICE), test_attr(1)));
-	assert_ok!(SupportNFT::create_group());
+	assert_ok!(NFT::create_class(Origin::signed(ALICE), test_attr(1), vec![]));
+	assert_ok!(SupportNFT::create_group(&ALICE, vec![]));
 	assert_ok!(NFT::mint(Origin::signed(ALICE), ALICE, CLASS_ID, metadata, token_attr, 3));
 }
 
@@ -34,8 +38,8 @@ fn create_nft_with_account(account: &AccountId) {
 	let metadata = vec![1];
 	let token_attr = test_attr(1u8);
 
-	assert_ok!(NFT::create_class(Origin::signed(ALICE), test_attr(1)));
-	assert_ok!(SupportNFT::create_group());
+	assert_ok!(NFT::create_class(Origin::signed(ALICE), test_attr(1), vec![]));
+	assert_ok!(SupportNFT::create_group(&ALICE, vec![]));
 	assert_ok!(NFT::mint(
 		Origin::signed(ALICE),
 		account.clone(),
@@ -46,6 +50,18 @@ fn create_nft_with_account(account: &AccountId) {
 	));
 }
 
+fn create_nft_with_royalty(account: &AccountId, royalties: Vec<RoyaltyRecipient<AccountId>>) {
+	assert_ok!(NFT::create_class(Origin::signed(ALICE), test_attr(1), royalties));
+	assert_ok!(NFT::mint(
+		Origin::signed(ALICE),
+		account.clone(),
+		CLASS_ID,
+		vec![1],
+		test_attr(1),
+		3
+	));
+}
+
 fn create_token(admin: AccountId, amount: Balance) {
 	Balances::make_free_balance_be(&admin, 10);
 	Balances::make_free_balance_be(&DAVE, 10);
@@ -101,7 +117,6 @@ fn create_fixed_price_listing_should_work() {
 			price,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -163,7 +178,6 @@ fn create_fixed_price_listing_should_not_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_noop!(
@@ -247,7 +261,6 @@ fn approve_listing_should_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -287,7 +300,6 @@ fn approve_listing_should_not_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -321,7 +333,6 @@ fn cancel_listing_should_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -371,7 +382,6 @@ fn cancel_listing_should_not_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -402,7 +412,6 @@ fn reject_listing_should_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -452,7 +461,6 @@ fn reject_listing_should_not_work() {
 			price: PRICE,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -607,7 +615,6 @@ fn ban_user_should_work() {
 			price,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -659,7 +666,6 @@ fn ban_user_should_not_work() {
 			price,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_noop!(
@@ -723,7 +729,6 @@ fn ban_should_work() {
 			price,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -765,7 +770,6 @@ fn ban_should_not_work() {
 			price: 100u128,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some(ROYALTY_VALUE),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -839,7 +843,6 @@ fn buy_with_native_token_should_work() {
 			price: 100u128,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: Some((100, 10_0000)),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -879,7 +882,6 @@ fn buy_with_native_token_should_not_work() {
 			price: 1000u128,
 			currency_id: NFTCurrencyId::Native,
 			expired_time: EXPIRED_TIME,
-			royalty: None,
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -910,7 +912,10 @@ fn buy_with_token_should_work() {
 		let buyer = BOB;
 		let beneficiary = ALICE;
 		// Create a NFT
-		create_nft_with_account(&EVE);
+		create_nft_with_royalty(
+			&EVE,
+			vec![RoyaltyRecipient { recipient: royalty_recipient.clone(), basis_points: 500 }],
+		);
 		Timestamp::set_timestamp(100);
 		set_service_fee();
 
@@ -918,7 +923,6 @@ fn buy_with_token_should_work() {
 			price: 1000u128,
 			currency_id: NFTCurrencyId::Token(ASSET_ID),
 			expired_time: EXPIRED_TIME,
-			royalty: Some((500, 10_000)),
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -954,6 +958,52 @@ fn buy_with_token_should_work() {
 	})
 }
 
+#[test]
+fn class_royalty_should_be_split_between_recipients() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ferdie = AccountId::new([5u8; 32]);
+		let royalties = vec![
+			RoyaltyRecipient { recipient: DAVE, basis_points: 300 },
+			RoyaltyRecipient { recipient: ferdie.clone(), basis_points: 200 },
+		];
+		create_nft_with_royalty(&EVE, royalties.clone());
+		Timestamp::set_timestamp(100);
+		set_service_fee();
+
+		let info = FixedPriceSetting {
+			price: 1000u128,
+			currency_id: NFTCurrencyId::Native,
+			expired_time: EXPIRED_TIME,
+		};
+		assert_ok!(NFTMarketplace::create_fixed_price_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			info,
+		));
+
+		// The listing uses the class royalty
+		let listing_info =
+			SupportNFTMarketplace::fixed_price_listing((EVE, CLASS_ID, TOKEN_ID)).unwrap();
+		assert_eq!(listing_info.royalty, (500, 10_000));
+		assert_eq!(listing_info.royalty_amount, 50u128);
+		assert_eq!(listing_info.royalty_recipients, royalties);
+
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		Balances::make_free_balance_be(&BOB, 1001);
+		let beneficiary_balance = Balances::free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::buy_now(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+
+		assert!(NFTMarketplace::is_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Balances::free_balance(&BOB), 1u128);
+		// Service fee: 10%
+		assert_eq!(Balances::free_balance(&ALICE), beneficiary_balance + 100u128);
+		// Royalty: 3% and 2%
+		assert_eq!(Balances::free_balance(&DAVE), 30u128);
+		assert_eq!(Balances::free_balance(&ferdie), 20u128);
+		assert_eq!(Balances::free_balance(&EVE), 850u128);
+	})
+}
+
 #[test]
 fn buy_with_token_should_not_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -977,7 +1027,6 @@ fn buy_with_token_should_not_work() {
 			price: 1000u128,
 			currency_id: NFTCurrencyId::Token(ASSET_ID),
 			expired_time: EXPIRED_TIME,
-			royalty: None,
 		};
 
 		assert_ok!(NFTMarketplace::create_fixed_price_listing(
@@ -1116,3 +1165,571 @@ fn revoke_role_should_not_work() {
 		));
 	});
 }
+
+fn create_time_auction(currency_id: NFTCurrencyId<BHC20TokenId>, min_price: Balance) {
+	create_nft_with_account(&EVE);
+	Timestamp::set_timestamp(100);
+	set_service_fee();
+
+	let info = TimeAuctionSetting { min_price, currency_id, auction_end: EXPIRED_TIME };
+	assert_ok!(NFTMarketplace::create_time_auction_listing(
+		Origin::signed(EVE),
+		(CLASS_ID, TOKEN_ID),
+		info
+	));
+}
+
+#[test]
+fn time_auction_with_native_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_time_auction(NFTCurrencyId::Native, 1000u128);
+		Balances::make_free_balance_be(&BOB, 10_000);
+		Balances::make_free_balance_be(&DAVE, 10_000);
+
+		// Listing must be approved
+		assert_noop!(
+			NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000u128),
+			Error::<Runtime>::NotFound
+		);
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+
+		assert_noop!(
+			NFTMarketplace::place_bid(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), 1000u128),
+			Error::<Runtime>::CannotBid
+		);
+		assert_noop!(
+			NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 999u128),
+			Error::<Runtime>::BidTooLow
+		);
+		assert_ok!(NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000u128));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::BidPlaced {
+			token: (CLASS_ID, TOKEN_ID),
+			bidder: BOB,
+			amount: 1000u128,
+		}));
+		assert_eq!(Balances::reserved_balance(&BOB), 1000u128);
+
+		// A new bid must outbid the highest bid by 5%
+		assert_noop!(
+			NFTMarketplace::place_bid(Origin::signed(DAVE), (CLASS_ID, TOKEN_ID), 1049u128),
+			Error::<Runtime>::BidTooLow
+		);
+		assert_ok!(NFTMarketplace::place_bid(Origin::signed(DAVE), (CLASS_ID, TOKEN_ID), 1050u128));
+		// Previous highest bidder is refunded
+		assert_eq!(Balances::reserved_balance(&BOB), 0u128);
+		assert_eq!(Balances::free_balance(&BOB), 10_000u128);
+		assert_eq!(Balances::reserved_balance(&DAVE), 1050u128);
+		assert_eq!(
+			SupportNFTMarketplace::time_auction((EVE, CLASS_ID, TOKEN_ID))
+				.unwrap()
+				.bid_count,
+			2
+		);
+
+		assert_noop!(
+			NFTMarketplace::cancel_listing(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), vec![]),
+			Error::<Runtime>::AuctionHasBids
+		);
+		assert_noop!(
+			NFTMarketplace::settle_time_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::AuctionNotEnded
+		);
+
+		Timestamp::set_timestamp(EXPIRED_TIME);
+		assert_noop!(
+			NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 2000u128),
+			Error::<Runtime>::ExpiredListing
+		);
+
+		// Anyone can settle the auction once it ended
+		let beneficiary_balance = Balances::free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::settle_time_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::TimeAuctionFulfilled {
+			token: (CLASS_ID, TOKEN_ID),
+			owner: EVE,
+			bid: AuctionBid { bidder: DAVE, amount: 1050u128 },
+		}));
+		assert!(NFTMarketplace::is_owner(&DAVE, (CLASS_ID, TOKEN_ID)));
+		assert!(!NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+		assert!(!SupportNFTMItemListing::<Runtime>::contains_key((EVE, CLASS_ID, TOKEN_ID)));
+		assert!(SupportNFTMarketplace::auction_bid(CLASS_ID, TOKEN_ID).is_none());
+		assert_eq!(Balances::reserved_balance(&DAVE), 0u128);
+		assert_eq!(Balances::free_balance(&DAVE), 8950u128);
+		// Service fee: 10%
+		assert_eq!(Balances::free_balance(&ALICE), beneficiary_balance + 105u128);
+		// No class royalty, the owner gets the actual price
+		assert_eq!(Balances::free_balance(&EVE), 945u128);
+
+		assert_noop!(
+			NFTMarketplace::settle_time_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotFound
+		);
+	})
+}
+
+#[test]
+fn time_auction_with_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_time_auction(NFTCurrencyId::Token(ASSET_ID), 1000u128);
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		create_token(BOB, 10000u128);
+
+		assert_ok!(NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000u128));
+		assert_eq!(Currencies::reserved_balance(ASSET_ID, &BOB), 1000u128);
+		assert_eq!(Currencies::free_balance(ASSET_ID, &BOB), 9000u128);
+
+		Timestamp::set_timestamp(EXPIRED_TIME);
+		assert_ok!(NFTMarketplace::settle_time_auction(Origin::signed(EVE), (CLASS_ID, TOKEN_ID)));
+		assert!(NFTMarketplace::is_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Currencies::total_balance(ASSET_ID, &BOB), 9000u128);
+		assert_eq!(Currencies::total_balance(ASSET_ID, &ALICE), 100u128);
+		assert_eq!(Currencies::total_balance(ASSET_ID, &EVE), 900u128);
+	})
+}
+
+#[test]
+fn time_auction_with_whole_balance_bid_should_settle() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_time_auction(NFTCurrencyId::Native, 1000u128);
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		Balances::make_free_balance_be(&BOB, 1000);
+
+		// The bid reserves all the free balance of the bidder
+		assert_ok!(NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000u128));
+		assert_eq!(Balances::free_balance(&BOB), 0u128);
+		assert_eq!(Balances::reserved_balance(&BOB), 1000u128);
+
+		Timestamp::set_timestamp(EXPIRED_TIME);
+		let beneficiary_balance = Balances::free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::settle_time_auction(Origin::signed(EVE), (CLASS_ID, TOKEN_ID)));
+		assert!(NFTMarketplace::is_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Balances::total_balance(&BOB), 0u128);
+		assert_eq!(Balances::free_balance(&ALICE), beneficiary_balance + 100u128);
+		assert_eq!(Balances::free_balance(&EVE), 900u128);
+	})
+}
+
+#[test]
+fn cancel_time_auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_time_auction(NFTCurrencyId::Native, 1000u128);
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		Balances::make_free_balance_be(&BOB, 10_000);
+		assert_ok!(NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000u128));
+
+		// Rejecting the listing releases the highest bid
+		assert_ok!(NFTMarketplace::reject_listing(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			vec![]
+		));
+		assert_eq!(Balances::reserved_balance(&BOB), 0u128);
+		assert_eq!(Balances::free_balance(&BOB), 10_000u128);
+		assert!(SupportNFTMarketplace::auction_bid(CLASS_ID, TOKEN_ID).is_none());
+		assert!(!NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+
+		// Owner can cancel an auction without bids
+		let info = TimeAuctionSetting {
+			min_price: 1000u128,
+			currency_id: NFTCurrencyId::Native,
+			auction_end: EXPIRED_TIME,
+		};
+		assert_ok!(NFTMarketplace::create_time_auction_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			info
+		));
+		assert_ok!(NFTMarketplace::cancel_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			vec![]
+		));
+		assert!(!NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+	})
+}
+
+#[test]
+fn settle_time_auction_without_bids_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NFTMarketplace::settle_time_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotFound
+		);
+		assert_noop!(
+			NFTMarketplace::place_bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 1000u128),
+			Error::<Runtime>::NotFound
+		);
+		create_time_auction(NFTCurrencyId::Native, 1000u128);
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+
+		Timestamp::set_timestamp(EXPIRED_TIME);
+		assert_ok!(NFTMarketplace::settle_time_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::TimeAuctionEnded {
+			token: (CLASS_ID, TOKEN_ID),
+			owner: EVE,
+		}));
+		assert!(NFTMarketplace::is_owner(&EVE, (CLASS_ID, TOKEN_ID)));
+		assert!(!NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+		assert!(!NFTMarketplace::is_listing(
+			&EVE,
+			(CLASS_ID, TOKEN_ID),
+			MarketMode::Auction(AuctionType::English)
+		));
+	})
+}
+
+fn create_dutch_auction(currency_id: NFTCurrencyId<BHC20TokenId>) {
+	create_nft_with_account(&EVE);
+	Timestamp::set_timestamp(100);
+	set_service_fee();
+
+	let info = DutchAuctionSetting {
+		start_price: 10_000u128,
+		floor_price: 1_000u128,
+		currency_id,
+		auction_start: 100,
+		auction_end: 1100,
+	};
+	assert_ok!(NFTMarketplace::create_dutch_auction_listing(
+		Origin::signed(EVE),
+		(CLASS_ID, TOKEN_ID),
+		info
+	));
+}
+
+#[test]
+fn create_dutch_auction_listing_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_nft_with_account(&EVE);
+		Timestamp::set_timestamp(100);
+		set_service_fee();
+
+		let info = DutchAuctionSetting {
+			start_price: 1_000u128,
+			floor_price: 10_000u128,
+			currency_id: NFTCurrencyId::Native,
+			auction_start: 100,
+			auction_end: 1100,
+		};
+		assert_noop!(
+			NFTMarketplace::create_dutch_auction_listing(
+				Origin::signed(EVE),
+				(CLASS_ID, TOKEN_ID),
+				info.clone()
+			),
+			Error::<Runtime>::BadPrice
+		);
+		assert_noop!(
+			NFTMarketplace::create_dutch_auction_listing(
+				Origin::signed(EVE),
+				(CLASS_ID, TOKEN_ID),
+				DutchAuctionSetting { auction_start: 1100, ..info.clone() }
+			),
+			Error::<Runtime>::InvalidTimeConfiguration
+		);
+		assert_noop!(
+			NFTMarketplace::create_dutch_auction_listing(
+				Origin::signed(BOB),
+				(CLASS_ID, TOKEN_ID),
+				DutchAuctionSetting { start_price: 10_000u128, ..info }
+			),
+			Error::<Runtime>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn buy_dutch_auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_dutch_auction(NFTCurrencyId::Native);
+		Balances::make_free_balance_be(&BOB, 10_000);
+		assert!(NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+		assert!(NFTMarketplace::is_listing(
+			&EVE,
+			(CLASS_ID, TOKEN_ID),
+			MarketMode::Auction(AuctionType::Dutch)
+		));
+
+		// Listing must be approved
+		assert_noop!(
+			NFTMarketplace::buy_dutch_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 10_000),
+			Error::<Runtime>::NotFound
+		);
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		assert_noop!(
+			NFTMarketplace::buy_dutch_auction(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), 10_000),
+			Error::<Runtime>::CannotBuyNFT
+		);
+
+		// Price decays linearly from the start price to the floor price
+		let listing_info = SupportNFTMarketplace::dutch_auction((EVE, CLASS_ID, TOKEN_ID)).unwrap();
+		assert_eq!(NFTMarketplace::dutch_auction_price(&listing_info), 10_000u128);
+		Timestamp::set_timestamp(600);
+		assert_eq!(NFTMarketplace::dutch_auction_price(&listing_info), 5_500u128);
+		Timestamp::set_timestamp(2000);
+		assert_eq!(NFTMarketplace::dutch_auction_price(&listing_info), 1_000u128);
+		Timestamp::set_timestamp(600);
+
+		assert_noop!(
+			NFTMarketplace::buy_dutch_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 5_000),
+			Error::<Runtime>::BadPrice
+		);
+		let beneficiary_balance = Balances::free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::buy_dutch_auction(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			6_000
+		));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::DutchAuctionFulfilled {
+			token: (CLASS_ID, TOKEN_ID),
+			owner: EVE,
+			buyer: BOB,
+			price: 5_500u128,
+		}));
+		assert!(NFTMarketplace::is_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert!(!NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+		assert!(!SupportNFTMItemListing::<Runtime>::contains_key((EVE, CLASS_ID, TOKEN_ID)));
+		assert_eq!(Balances::free_balance(&BOB), 4_500u128);
+		assert_eq!(Balances::free_balance(&ALICE), beneficiary_balance + 550u128);
+		assert_eq!(Balances::free_balance(&EVE), 4_950u128);
+	})
+}
+
+#[test]
+fn buy_dutch_auction_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_dutch_auction(NFTCurrencyId::Token(ASSET_ID));
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+
+		Timestamp::set_timestamp(1100);
+		assert_noop!(
+			NFTMarketplace::buy_dutch_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 10_000),
+			Error::<Runtime>::ExpiredListing
+		);
+
+		assert_ok!(NFTMarketplace::cancel_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			vec![]
+		));
+		assert!(!NFTMarketplace::is_lock(&EVE, (CLASS_ID, TOKEN_ID)));
+		assert!(SupportNFTMarketplace::dutch_auction((EVE, CLASS_ID, TOKEN_ID)).is_none());
+	})
+}
+
+#[test]
+fn accept_offer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_nft_with_account(&EVE);
+		Timestamp::set_timestamp(100);
+		set_service_fee();
+		Balances::make_free_balance_be(&BOB, 10_000);
+
+		let offer =
+			OfferInfo { amount: 1000u128, currency_id: NFTCurrencyId::Native, expired_time: 1000 };
+		assert_noop!(
+			NFTMarketplace::make_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), offer.clone()),
+			Error::<Runtime>::CannotBuyNFT
+		);
+		assert_noop!(
+			NFTMarketplace::make_offer(Origin::signed(BOB), (CLASS_ID, 100), offer.clone()),
+			Error::<Runtime>::NotFound
+		);
+		assert_noop!(
+			NFTMarketplace::make_offer(
+				Origin::signed(BOB),
+				(CLASS_ID, TOKEN_ID),
+				OfferInfo { expired_time: 50, ..offer.clone() }
+			),
+			Error::<Runtime>::InvalidTimeConfiguration
+		);
+
+		assert_ok!(NFTMarketplace::make_offer(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			offer.clone()
+		));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::OfferMade {
+			token: (CLASS_ID, TOKEN_ID),
+			offerer: BOB,
+			offer: offer.clone(),
+		}));
+		assert_eq!(Balances::reserved_balance(&BOB), 1010u128);
+		assert_noop!(
+			NFTMarketplace::make_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), offer.clone()),
+			Error::<Runtime>::OfferExists
+		);
+
+		assert_noop!(
+			NFTMarketplace::accept_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTMarketplace::accept_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), DAVE),
+			Error::<Runtime>::NotFound
+		);
+
+		// Listed NFT must be delisted first
+		let info = FixedPriceSetting {
+			price: 10_000u128,
+			currency_id: NFTCurrencyId::Native,
+			expired_time: EXPIRED_TIME,
+		};
+		assert_ok!(NFTMarketplace::create_fixed_price_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			info
+		));
+		assert_noop!(
+			NFTMarketplace::accept_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), BOB),
+			Error::<Runtime>::IsListing
+		);
+		assert_ok!(NFTMarketplace::cancel_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			vec![]
+		));
+
+		let beneficiary_balance = Balances::free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::accept_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), BOB));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::OfferAccepted {
+			token: (CLASS_ID, TOKEN_ID),
+			owner: EVE,
+			offerer: BOB,
+			offer,
+		}));
+		assert!(NFTMarketplace::is_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert!(SupportNFTMarketplace::offers((CLASS_ID, TOKEN_ID, BOB)).is_none());
+		assert_eq!(Balances::reserved_balance(&BOB), 0u128);
+		assert_eq!(Balances::free_balance(&BOB), 9_000u128);
+		assert_eq!(Balances::free_balance(&ALICE), beneficiary_balance + 100u128);
+		assert_eq!(Balances::free_balance(&EVE), 900u128);
+	})
+}
+
+#[test]
+fn accept_offer_should_fail_with_invalid_rate() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_nft_with_royalty(
+			&EVE,
+			vec![RoyaltyRecipient { recipient: DAVE, basis_points: 5_000 }],
+		);
+		Timestamp::set_timestamp(100);
+		grant_admin_role(ALICE);
+		assert_ok!(NFTMarketplace::set_marketplace_fee(
+			Origin::signed(ALICE),
+			(5000, 10000),
+			ALICE
+		));
+		Balances::make_free_balance_be(&BOB, 10_000);
+
+		let offer =
+			OfferInfo { amount: 1000u128, currency_id: NFTCurrencyId::Native, expired_time: 1000 };
+		assert_ok!(NFTMarketplace::make_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), offer));
+		assert_noop!(
+			NFTMarketplace::accept_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), BOB),
+			Error::<Runtime>::InvalidRate
+		);
+
+		assert_ok!(NFTMarketplace::set_marketplace_fee(
+			Origin::signed(ALICE),
+			(1000, 10000),
+			ALICE
+		));
+		assert_ok!(NFTMarketplace::accept_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), BOB));
+		assert_eq!(Balances::free_balance(&DAVE), 500u128);
+	})
+}
+
+#[test]
+fn withdraw_offer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_nft_with_account(&EVE);
+		Timestamp::set_timestamp(100);
+		set_service_fee();
+		create_token(BOB, 10000u128);
+		Balances::make_free_balance_be(&BOB, 100);
+
+		let offer = OfferInfo {
+			amount: 1000u128,
+			currency_id: NFTCurrencyId::Token(ASSET_ID),
+			expired_time: 1000,
+		};
+		assert_ok!(NFTMarketplace::make_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), offer));
+		assert_eq!(Currencies::reserved_balance(ASSET_ID, &BOB), 1000u128);
+		assert_eq!(Currencies::free_balance(ASSET_ID, &BOB), 9000u128);
+		// The storage deposit is reserved in native currency
+		assert_eq!(Balances::reserved_balance(&BOB), 10u128);
+
+		assert_noop!(
+			NFTMarketplace::withdraw_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::OfferNotExpired
+		);
+
+		Timestamp::set_timestamp(1000);
+		assert_noop!(
+			NFTMarketplace::accept_offer(Origin::signed(EVE), (CLASS_ID, TOKEN_ID), BOB),
+			Error::<Runtime>::ExpiredListing
+		);
+		assert_ok!(NFTMarketplace::withdraw_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTMarketplace(crate::Event::OfferWithdrawn {
+			token: (CLASS_ID, TOKEN_ID),
+			offerer: BOB,
+		}));
+		assert_eq!(Currencies::reserved_balance(ASSET_ID, &BOB), 0u128);
+		assert_eq!(Currencies::free_balance(ASSET_ID, &BOB), 10000u128);
+		assert_eq!(Balances::reserved_balance(&BOB), 0u128);
+		assert_eq!(Balances::free_balance(&BOB), 100u128);
+		assert!(NFTMarketplace::is_owner(&EVE, (CLASS_ID, TOKEN_ID)));
+
+		assert_noop!(
+			NFTMarketplace::withdraw_offer(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotFound
+		);
+	})
+}
+
+#[test]
+fn buy_with_native_token_should_be_atomic() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_nft_with_account(&EVE);
+		Timestamp::set_timestamp(100);
+		set_service_fee();
+
+		let info = FixedPriceSetting {
+			price: 100u128,
+			currency_id: NFTCurrencyId::Native,
+			expired_time: EXPIRED_TIME,
+		};
+		assert_ok!(NFTMarketplace::create_fixed_price_listing(
+			Origin::signed(EVE),
+			(CLASS_ID, TOKEN_ID),
+			info,
+		));
+		assert_ok!(NFTMarketplace::approve_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+
+		// Reserved funds can't pay for the NFT
+		Balances::make_free_balance_be(&BOB, 150);
+		assert_ok!(Balances::reserve(&BOB, 100));
+		assert_noop!(
+			NFTMarketplace::buy_now(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::InsufficientBalance
+		);
+		Balances::unreserve(&BOB, 100);
+
+		// The buyer's account must be kept alive
+		Balances::make_free_balance_be(&BOB, 100);
+		assert_noop!(
+			NFTMarketplace::buy_now(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			pallet_balances::Error::<Runtime>::KeepAlive
+		);
+
+		Balances::make_free_balance_be(&BOB, 101);
+		let beneficiary_balance = Balances::free_balance(&ALICE);
+		assert_ok!(NFTMarketplace::buy_now(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert!(NFTMarketplace::is_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Balances::free_balance(&BOB), 1u128);
+		assert_eq!(Balances::free_balance(&ALICE), beneficiary_balance + 10u128);
+		assert_eq!(Balances::free_balance(&EVE), 90u128);
+	})
+}
@@ -0,0 +1,467 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for bholdus_nft_marketplace
+//!
+//! NOTE: these are placeholder estimates written by hand, the pallet has no benchmarks yet.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for bholdus_nft_marketplace.
+pub trait WeightInfo {
+	
+	fn place_bid() -> Weight;
+	
+	fn settle_time_auction() -> Weight;
+	
+	fn create_dutch_auction_listing() -> Weight;
+	
+	fn buy_dutch_auction() -> Weight;
+	
+	fn make_offer() -> Weight;
+	
+	fn accept_offer() -> Weight;
+	
+	fn withdraw_offer() -> Weight;
+	
+}
+
+/// Weights for bholdus_nft_marketplace using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace TimeAuction (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace AuctionBids (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	fn place_bid() -> Weight {
+		(46_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace TimeAuction (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace ItemListing (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace AuctionBids (r:1 w:1)
+	
+	// Storage: System Account (r:13 w:13)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	// Storage: BholdusSupportNFT TokenApprovals (r:0 w:1)
+	
+	fn settle_time_auction() -> Weight {
+		(118_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(21 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Blacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace ItemListing (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace MarketplaceFee (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassRoyalties (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace DutchAuction (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:0 w:1)
+	
+	fn create_dutch_auction_listing() -> Weight {
+		(52_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace DutchAuction (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace ItemListing (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:0 w:1)
+	
+	// Storage: System Account (r:13 w:13)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	// Storage: BholdusSupportNFT TokenApprovals (r:0 w:1)
+	
+	fn buy_dutch_auction() -> Weight {
+		(112_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Blacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Offers (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn make_offer() -> Weight {
+		(48_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Blacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace MarketplaceFee (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassRoyalties (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Offers (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: System Account (r:4 w:4)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	// Storage: BholdusSupportNFT TokenApprovals (r:0 w:1)
+	
+	fn accept_offer() -> Weight {
+		(98_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: SupportNFTMarketplace Offers (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn withdraw_offer() -> Weight {
+		(38_000_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace TimeAuction (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace AuctionBids (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	fn place_bid() -> Weight {
+		(46_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace TimeAuction (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace ItemListing (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace AuctionBids (r:1 w:1)
+	
+	// Storage: System Account (r:13 w:13)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	// Storage: BholdusSupportNFT TokenApprovals (r:0 w:1)
+	
+	fn settle_time_auction() -> Weight {
+		(118_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(21 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Blacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace ItemListing (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace MarketplaceFee (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassRoyalties (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace DutchAuction (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:0 w:1)
+	
+	fn create_dutch_auction_listing() -> Weight {
+		(52_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: SupportNFTMarketplace DutchAuction (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace ItemListing (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:0 w:1)
+	
+	// Storage: System Account (r:13 w:13)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	// Storage: BholdusSupportNFT TokenApprovals (r:0 w:1)
+	
+	fn buy_dutch_auction() -> Weight {
+		(112_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Blacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Offers (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn make_offer() -> Weight {
+		(48_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace UserBlacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Blacklist (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace MarketplaceFee (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassRoyalties (r:1 w:0)
+	
+	// Storage: SupportNFTMarketplace Offers (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: System Account (r:4 w:4)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	// Storage: BholdusSupportNFT TokenApprovals (r:0 w:1)
+	
+	fn accept_offer() -> Weight {
+		(98_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: SupportNFTMarketplace Offers (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn withdraw_offer() -> Weight {
+		(38_000_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+}
\ No newline at end of file
@@ -10,6 +10,7 @@ use frame_system::RawOrigin;
 use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
 
 pub use crate::*;
+use bholdus_support_nft::RoyaltyRecipient;
 use common_primitives::Balance;
 
 pub struct Module<T: Config>(crate::Pallet<T>);
@@ -35,15 +36,21 @@ fn create_token_class<T: Config>(
 	let module_account: T::AccountId =
 		T::PalletId::get().into_sub_account(bholdus_support_nft::Pallet::<T>::next_class_id());
 
-	crate::Pallet::<T>::create_class(RawOrigin::Signed(caller).into(), test_attr())?;
+	crate::Pallet::<T>::create_class(RawOrigin::Signed(caller).into(), test_attr(), vec![])?;
 	Ok(module_account)
 }
 
 benchmarks! {
 	// create NFT class
 	create_class {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
+
 		let caller: T::AccountId = account("caller", 0, SEED);
-	}: _(RawOrigin::Signed(caller), test_attr())
+		let royalties = (0..r).map(|i| RoyaltyRecipient {
+			recipient: account("recipient", i, SEED),
+			basis_points: T::MaxRoyalty::get() / r,
+		}).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), test_attr(), royalties)
 
 	// mint NFT token
 	mint {
@@ -56,6 +63,17 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(account), to_lookup, 0u32.into(), vec![1], test_attr(), i)
 
+	// mint NFT tokens with their own metadata and attributes
+	mint_batch {
+		let i in 1 .. 99;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+		let account = create_token_class::<T>(caller)?;
+		let tokens = (0..i).map(|_| (vec![1], test_attr())).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(account), to_lookup, 0u32.into(), tokens)
+
 	// transfer NFT token to another account
 	transfer {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -73,6 +91,70 @@ benchmarks! {
 			1)?;
 	}: _(RawOrigin::Signed(to), caller_lookup, (0u32.into(), 0u32.into()))
 
+	// transfer NFT tokens to another account
+	transfer_batch {
+		let i in 1 .. 99;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(account).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			test_attr(),
+			i)?;
+		let tokens = (0..i).map(|token_id| (0u32.into(), token_id.into())).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(to), caller_lookup, tokens)
+
+	// approve an account to transfer NFT token
+	approve {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let account = create_token_class::<T>(caller.clone())?;
+
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(account).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			test_attr(),
+			1)?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), Some(T::Lookup::unlookup(caller)))
+
+	// allow an account to transfer all NFT tokens of a class
+	set_operator {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		create_token_class::<T>(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), T::Lookup::unlookup(operator), true)
+
+	// transfer NFT token on behalf of its owner
+	transfer_from {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let account = create_token_class::<T>(caller.clone())?;
+
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(account).into(),
+			to_lookup.clone(),
+			0u32.into(),
+			vec![1],
+			test_attr(),
+			1)?;
+		crate::Pallet::<T>::approve(
+			RawOrigin::Signed(to).into(),
+			(0u32.into(), 0u32.into()),
+			Some(caller_lookup.clone()))?;
+	}: _(RawOrigin::Signed(caller), to_lookup, caller_lookup, (0u32.into(), 0u32.into()))
+
 	// burn NFT token
 	burn {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -84,9 +166,157 @@ benchmarks! {
 
 	// destroy NFT class
 	destroy_class {
+		let m in 0 .. T::MaxClassMinters::get();
+
 		let caller: T::AccountId = account("caller", 0, SEED);
 		create_token_class::<T>(caller.clone())?;
+		for i in 0..m {
+			let minter: T::AccountId = account("minter", i, SEED);
+			crate::Pallet::<T>::add_class_minter(
+				RawOrigin::Signed(caller.clone()).into(),
+				0u32.into(),
+				T::Lookup::unlookup(minter))?;
+		}
 	}: _(RawOrigin::Signed(caller.clone()), 0u32.into())
+
+	// set NFT class royalty
+	set_class_royalty {
+		let r in 1 .. T::MaxRoyaltyRecipients::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		create_token_class::<T>(caller.clone())?;
+		let royalties = (0..r).map(|i| RoyaltyRecipient {
+			recipient: account("recipient", i, SEED),
+			basis_points: T::MaxRoyalty::get() / r,
+		}).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), 0u32.into(), royalties)
+
+	// allow an account to mint NFT tokens of a class
+	add_class_minter {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let minter_lookup = T::Lookup::unlookup(minter);
+		create_token_class::<T>(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), minter_lookup)
+
+	// disallow an account to mint NFT tokens of a class
+	remove_class_minter {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let minter_lookup = T::Lookup::unlookup(minter);
+		create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::add_class_minter(
+			RawOrigin::Signed(caller.clone()).into(),
+			0u32.into(),
+			minter_lookup.clone())?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), minter_lookup)
+
+	// set the maximum supply of a NFT class
+	set_max_supply {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		create_token_class::<T>(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), Some(100u32.into()))
+
+	// open public minting of a NFT class
+	set_public_mint {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		create_token_class::<T>(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), Some(100u32.into()))
+
+	// set the metadata of a token
+	set_token_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(caller.clone());
+		create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			Default::default(),
+			1)?;
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), vec![2])
+
+	// set an attribute of a token
+	set_token_attribute {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(caller.clone());
+		create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			Default::default(),
+			1)?;
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), vec![1], vec![1])
+
+	// remove an attribute of a token
+	remove_token_attribute {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(caller.clone());
+		create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			Default::default(),
+			1)?;
+		crate::Pallet::<T>::set_token_attribute(
+			RawOrigin::Signed(caller.clone()).into(),
+			(0u32.into(), 0u32.into()),
+			vec![1],
+			vec![1])?;
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), vec![1])
+
+	// freeze the metadata and attributes of a token
+	freeze_token_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(caller.clone());
+		create_token_class::<T>(caller.clone())?;
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			Default::default(),
+			1)?;
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()))
+
+	// create NFT group
+	create_group {
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), vec![1])
+
+	// set the metadata of a group
+	set_group_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		crate::Pallet::<T>::create_group(RawOrigin::Signed(caller.clone()).into(), vec![1])?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), vec![2])
+
+	// destroy NFT group
+	destroy_group {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		crate::Pallet::<T>::create_group(RawOrigin::Signed(caller.clone()).into(), vec![1])?;
+	}: _(RawOrigin::Signed(caller), 0u32.into())
+
+	// move NFT token to another group
+	move_to_group {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(
+			RawOrigin::Signed(account).into(),
+			to_lookup,
+			0u32.into(),
+			vec![1],
+			test_attr(),
+			1)?;
+		crate::Pallet::<T>::create_group(RawOrigin::Signed(to.clone()).into(), vec![1])?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), Some(1u32.into()))
 }
 
 #[cfg(test)]
@@ -3,22 +3,45 @@
 //! - `create_clas` - Create NFT(non fungible token) class
 //! - `transfer` - Transfer NFT to another account.
 //! - `mint` - Mint NFT
+//! - `mint_batch` - Mint NFTs with their own metadata and attributes
+//! - `transfer_batch` - Transfer NFTs to another account
+//! - `approve` - Approve an account to transfer an NFT
+//! - `set_operator` - Allow an account to transfer all NFTs of a class
+//! - `transfer_from` - Transfer NFT on behalf of its owner
 //! - `burn` - Burn NFT
 //! - `destroy_class` - Destroy NFT
+//! - `set_class_royalty` - Set the royalty recipients of a class
+//! - `add_class_minter` - Allow an account to mint tokens of a class
+//! - `remove_class_minter` - Disallow an account to mint tokens of a class
+//! - `set_max_supply` - Set the maximum supply of a class
+//! - `set_public_mint` - Open or close public minting of a class
+//! - `create_group` - Create a group of NFTs
+//! - `set_group_metadata` - Set the metadata of a group
+//! - `destroy_group` - Destroy an empty group
+//! - `move_to_group` - Move NFT to another group
+//! - `set_token_metadata` - Set the metadata of a token
+//! - `set_token_attribute` - Set an attribute of a token
+//! - `remove_token_attribute` - Remove an attribute of a token
+//! - `freeze_token_metadata` - Freeze the metadata and attributes of a token
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unnecessary_cast)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::unpper_case_acronyms)]
 
-use frame_support::{pallet_prelude::*, require_transactional, transactional, PalletId};
+use frame_support::{
+	pallet_prelude::*,
+	require_transactional,
+	traits::{Currency, ExistenceRequirement},
+	transactional, PalletId,
+};
 
 use scale_info::TypeInfo;
 
 use frame_system::pallet_prelude::*;
 
 use bholdus_support::NFT;
-use bholdus_support_nft::{ClassInfo, ClassInfoOf, TokenInfo, TokenInfoOf};
+use bholdus_support_nft::{ClassInfo, ClassInfoOf, RoyaltyRecipientOf, TokenInfo, TokenInfoOf};
 use common_primitives::NFTBalance;
 
 #[cfg(feature = "std")]
@@ -47,6 +70,8 @@ pub type Attributes = BTreeMap<Vec<u8>, Vec<u8>>;
 pub type TokenIdOf<T> = <T as bholdus_support_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as bholdus_support_nft::Config>::ClassId;
 pub type GroupIdOf<T> = <T as bholdus_support_nft::Config>::GroupId;
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -84,6 +109,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// Maximum number of royalty recipients of a class
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
+		/// Maximum total royalty of a class, in basis points
+		#[pallet::constant]
+		type MaxRoyalty: Get<u32>;
+
+		/// Maximum number of minters of a class
+		#[pallet::constant]
+		type MaxClassMinters: Get<u32>;
+
+		/// Currency paying for public minting
+		type Currency: Currency<Self::AccountId>;
+
 		/// Weight information for the extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -104,6 +144,24 @@ pub mod pallet {
 		AttributesTooLarge,
 		/// Failed because the Maximum amount of metadata was exceeded
 		MaxMetadataExceeded,
+		/// Too many royalty recipients
+		TooManyRoyaltyRecipients,
+		/// Total royalty exceeds the maximum royalty
+		RoyaltyTooHigh,
+		/// Royalty can only be set before the first token of the class is minted
+		CannotSetRoyalty,
+		/// The account is already a minter of the class
+		AlreadyMinter,
+		/// The account is not a minter of the class
+		NotMinter,
+		/// The class has the maximum number of minters
+		TooManyMinters,
+		/// The class reached its maximum supply
+		MaxSupplyReached,
+		/// The token has no attribute with this key
+		AttributeNotFound,
+		/// GroupId not found
+		GroupIdNotFound,
 	}
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
@@ -120,20 +178,109 @@ pub mod pallet {
 			token_info: TokenInfoOf<T>,
 			quantity: u32,
 		},
+		/// Minted NFT batch
+		MintedTokenBatch {
+			group_id: GroupIdOf<T>,
+			class_id: ClassIdOf<T>,
+			owner: T::AccountId,
+			token_ids: Vec<TokenIdOf<T>>,
+		},
 		/// Transferred NFT
 		TransferredToken {
 			from: T::AccountId,
 			to: T::AccountId,
 			token: (ClassIdOf<T>, TokenIdOf<T>),
 		},
+		/// Transferred NFT batch
+		TransferredTokenBatch {
+			from: T::AccountId,
+			to: T::AccountId,
+			tokens: Vec<(ClassIdOf<T>, TokenIdOf<T>)>,
+		},
+
+		/// Approved an account to transfer NFT, `None` if the approval was removed
+		Approved {
+			owner: T::AccountId,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			approved: Option<T::AccountId>,
+		},
+		/// Allowed or disallowed an operator to transfer all NFTs of a class
+		OperatorSet {
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			operator: T::AccountId,
+			approved: bool,
+		},
 
 		/// Burned NFT
 		BurnedToken { owner: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>) },
 
 		/// Destroyed NFT
 		DestroyedClass { owner: T::AccountId, class_id: ClassIdOf<T> },
+
+		/// Set class royalty
+		ClassRoyaltySet {
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			royalties: Vec<RoyaltyRecipientOf<T>>,
+		},
+
+		/// Added class minter
+		ClassMinterAdded { class_id: ClassIdOf<T>, minter: T::AccountId },
+
+		/// Removed class minter
+		ClassMinterRemoved { class_id: ClassIdOf<T>, minter: T::AccountId },
+
+		/// Set class maximum supply
+		MaxSupplySet { class_id: ClassIdOf<T>, max_supply: Option<TokenIdOf<T>> },
+
+		/// Set class public mint price, `None` if public minting is closed
+		PublicMintSet { class_id: ClassIdOf<T>, price: Option<BalanceOf<T>> },
+
+		/// Set token metadata
+		TokenMetadataSet { token: (ClassIdOf<T>, TokenIdOf<T>), metadata: CID },
+
+		/// Set token attribute
+		TokenAttributeSet { token: (ClassIdOf<T>, TokenIdOf<T>), key: Vec<u8>, value: Vec<u8> },
+
+		/// Removed token attribute
+		TokenAttributeRemoved { token: (ClassIdOf<T>, TokenIdOf<T>), key: Vec<u8> },
+
+		/// Froze token metadata and attributes
+		TokenMetadataFrozen { token: (ClassIdOf<T>, TokenIdOf<T>) },
+
+		/// Created NFT group
+		CreatedGroup { owner: T::AccountId, group_id: GroupIdOf<T>, metadata: CID },
+
+		/// Set group metadata
+		GroupMetadataSet { group_id: GroupIdOf<T>, metadata: CID },
+
+		/// Destroyed NFT group
+		DestroyedGroup { owner: T::AccountId, group_id: GroupIdOf<T> },
+
+		/// Moved NFT to another group, `None` if it was removed from its group
+		MovedToGroup { token: (ClassIdOf<T>, TokenIdOf<T>), group_id: Option<GroupIdOf<T>> },
 	}
+
+	/// Accounts allowed to mint tokens of a class, besides the class owner
+	#[pallet::storage]
+	#[pallet::getter(fn class_minters)]
+	pub type ClassMinters<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Number of minters of each class, bounded by `MaxClassMinters`
+	#[pallet::storage]
+	#[pallet::getter(fn class_minter_count)]
+	pub type ClassMinterCount<T: Config> =
+		StorageMap<_, Twox64Concat, ClassIdOf<T>, u32, ValueQuery>;
+
+	/// Price paid to the class owner for each token minted by anyone, if public minting is open
+	#[pallet::storage]
+	#[pallet::getter(fn public_mint_price)]
+	pub type PublicMintPrice<T: Config> = StorageMap<_, Twox64Concat, ClassIdOf<T>, BalanceOf<T>>;
+
 	#[pallet::pallet]
+	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
@@ -143,18 +290,24 @@ pub mod pallet {
 		/// Create NFT class
 		///
 		/// - `metadata`: external metadata
-		#[pallet::weight(<T as Config>::WeightInfo::create_class())]
+		/// - `royalties`: royalty recipients of the class and their share in basis points, can be
+		///   empty
+		#[pallet::weight(<T as Config>::WeightInfo::create_class(royalties.len() as u32))]
 		#[transactional]
 		pub fn create_class(
 			origin: OriginFor<T>,
 			attributes: Attributes,
+			royalties: Vec<RoyaltyRecipientOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin)?;
 			let class_id = bholdus_support_nft::Pallet::<T>::next_class_id();
 			// let owner: T::AccountId = T::PalletId::get().into_sub_account(next_id);
 			let data = ClassData { attributes };
 			bholdus_support_nft::Pallet::<T>::create_class(&owner, data.clone())?;
-			Self::deposit_event(Event::CreatedClass { owner, class_id, data });
+			Self::deposit_event(Event::CreatedClass { owner: owner.clone(), class_id, data });
+			if !royalties.is_empty() {
+				Self::do_set_class_royalty(owner, class_id, royalties)?;
+			}
 			Ok(().into())
 		}
 
@@ -179,6 +332,24 @@ pub mod pallet {
 			Self::do_mint(who, to, class_id, metadata, attributes, quantity)
 		}
 
+		/// Mint NFT tokens, each with its own metadata and attributes
+		///
+		/// - `to`: the token owner's account
+		/// - `class_id`: token belong to the class id
+		/// - `tokens`: metadata and attributes of each token
+		#[pallet::weight(<T as Config>::WeightInfo::mint_batch(tokens.len() as u32))]
+		#[transactional]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			to: <T::Lookup as StaticLookup>::Source,
+			class_id: ClassIdOf<T>,
+			tokens: Vec<(CID, Attributes)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			Self::do_mint_batch(who, to, class_id, tokens)
+		}
+
 		/// Transfer NFT to another account
 		/// - `to` the token owner's account
 		/// - `token`: (class_id, token_id)
@@ -195,6 +366,82 @@ pub mod pallet {
 			Self::do_transfer(who, to, token)
 		}
 
+		/// Transfer NFTs to another account
+		/// - `to` the token owner's account
+		/// - `tokens`: list of (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_batch(tokens.len() as u32))]
+		#[transactional]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			to: <T::Lookup as StaticLookup>::Source,
+			tokens: Vec<(ClassIdOf<T>, TokenIdOf<T>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			Self::do_transfer_batch(who, to, tokens)
+		}
+
+		/// Approve an account to transfer NFT on behalf of the owner, cleared on transfer
+		/// - `token`: (class_id, token_id)
+		/// - `approved`: the approved account, `None` to remove the approval
+		#[pallet::weight(<T as Config>::WeightInfo::approve())]
+		#[transactional]
+		pub fn approve(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			approved: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let approved = approved.map(T::Lookup::lookup).transpose()?;
+			let token_info = bholdus_support_nft::Pallet::<T>::tokens(token.0, token.1)
+				.ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(who == token_info.owner, Error::<T>::NoPermission);
+			bholdus_support_nft::Pallet::<T>::approve(&who, token, approved.clone())?;
+			Self::deposit_event(Event::Approved { owner: who, token, approved });
+			Ok(())
+		}
+
+		/// Allow or disallow an account to transfer all NFTs of a class on behalf of the owner
+		/// - `class_id`: the class ID
+		/// - `operator`: the operator's account
+		/// - `approved`: whether the operator is allowed
+		#[pallet::weight(<T as Config>::WeightInfo::set_operator())]
+		#[transactional]
+		pub fn set_operator(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			operator: <T::Lookup as StaticLookup>::Source,
+			approved: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let operator = T::Lookup::lookup(operator)?;
+			ensure!(
+				bholdus_support_nft::Pallet::<T>::classes(class_id).is_some(),
+				Error::<T>::ClassIdNotFound
+			);
+			bholdus_support_nft::Pallet::<T>::set_operator(&who, class_id, &operator, approved)?;
+			Self::deposit_event(Event::OperatorSet { owner: who, class_id, operator, approved });
+			Ok(())
+		}
+
+		/// Transfer NFT on behalf of its owner
+		/// - `from` the token owner's account
+		/// - `to` the receiver's account
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_from())]
+		#[transactional]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: <T::Lookup as StaticLookup>::Source,
+			to: <T::Lookup as StaticLookup>::Source,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+			Self::do_transfer_from(who, from, to, token)
+		}
+
 		/// Burn NFT
 		///
 		/// - `token`: (class_id, token_id)
@@ -208,7 +455,7 @@ pub mod pallet {
 		/// Destroy NFT class
 		///
 		/// - `class_id`: The class ID to destroy
-		#[pallet::weight(<T as Config>::WeightInfo::destroy_class())]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_class(T::MaxClassMinters::get()))]
 		#[transactional]
 		pub fn destroy_class(
 			origin: OriginFor<T>,
@@ -220,9 +467,268 @@ pub mod pallet {
 			ensure!(who == class_info.owner, Error::<T>::NoPermission);
 			ensure!(class_info.total_issuance == Zero::zero(), Error::<T>::CannotDestroyClass);
 			bholdus_support_nft::Pallet::<T>::destroy_class(&who, class_id)?;
+			let minters = ClassMinterCount::<T>::take(class_id);
+			ClassMinters::<T>::remove_prefix(class_id, None);
+			PublicMintPrice::<T>::remove(class_id);
 
 			Self::deposit_event(Event::DestroyedClass { owner: who, class_id });
-			Ok(().into())
+			Ok(Some(<T as Config>::WeightInfo::destroy_class(minters)).into())
+		}
+
+		/// Set the royalty recipients of a class, paid on every marketplace sale of its tokens
+		///
+		/// - `class_id`: the class ID
+		/// - `royalties`: recipients and their share in basis points, empty to remove the royalty
+		#[pallet::weight(<T as Config>::WeightInfo::set_class_royalty(royalties.len() as u32))]
+		#[transactional]
+		pub fn set_class_royalty(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			royalties: Vec<RoyaltyRecipientOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_class_royalty(who, class_id, royalties)
+		}
+
+		/// Allow an account to mint tokens of a class
+		///
+		/// - `class_id`: the class ID
+		/// - `minter`: the account allowed to mint
+		#[pallet::weight(<T as Config>::WeightInfo::add_class_minter())]
+		#[transactional]
+		pub fn add_class_minter(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			minter: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let minter = T::Lookup::lookup(minter)?;
+			Self::ensure_class_owner(&who, class_id)?;
+			ensure!(!ClassMinters::<T>::contains_key(class_id, &minter), Error::<T>::AlreadyMinter);
+			ClassMinterCount::<T>::try_mutate(class_id, |count| -> DispatchResult {
+				ensure!(*count < T::MaxClassMinters::get(), Error::<T>::TooManyMinters);
+				*count = count.saturating_add(1);
+				Ok(())
+			})?;
+
+			ClassMinters::<T>::insert(class_id, &minter, ());
+			Self::deposit_event(Event::ClassMinterAdded { class_id, minter });
+			Ok(())
+		}
+
+		/// Disallow an account to mint tokens of a class
+		///
+		/// - `class_id`: the class ID
+		/// - `minter`: the account not allowed to mint anymore
+		#[pallet::weight(<T as Config>::WeightInfo::remove_class_minter())]
+		#[transactional]
+		pub fn remove_class_minter(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			minter: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let minter = T::Lookup::lookup(minter)?;
+			Self::ensure_class_owner(&who, class_id)?;
+			ensure!(ClassMinters::<T>::contains_key(class_id, &minter), Error::<T>::NotMinter);
+
+			ClassMinters::<T>::remove(class_id, &minter);
+			ClassMinterCount::<T>::mutate(class_id, |count| *count = count.saturating_sub(1));
+			Self::deposit_event(Event::ClassMinterRemoved { class_id, minter });
+			Ok(())
+		}
+
+		/// Set the maximum supply of a class
+		///
+		/// - `class_id`: the class ID
+		/// - `max_supply`: maximum total issuance of the class, `None` for an unlimited supply
+		#[pallet::weight(<T as Config>::WeightInfo::set_max_supply())]
+		#[transactional]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			max_supply: Option<TokenIdOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_class_owner(&who, class_id)?;
+			bholdus_support_nft::Pallet::<T>::set_max_supply(&who, class_id, max_supply)?;
+			Self::deposit_event(Event::MaxSupplySet { class_id, max_supply });
+			Ok(())
+		}
+
+		/// Open or close public minting of a class
+		///
+		/// - `class_id`: the class ID
+		/// - `price`: price paid to the class owner for each minted token, `None` to close public
+		///   minting
+		#[pallet::weight(<T as Config>::WeightInfo::set_public_mint())]
+		#[transactional]
+		pub fn set_public_mint(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_class_owner(&who, class_id)?;
+			PublicMintPrice::<T>::set(class_id, price);
+			Self::deposit_event(Event::PublicMintSet { class_id, price });
+			Ok(())
+		}
+
+		/// Set the metadata of a token, only the class owner can call it
+		///
+		/// - `token`: (class ID, token ID)
+		/// - `metadata`: external metadata
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_metadata())]
+		#[transactional]
+		pub fn set_token_metadata(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			metadata: CID,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_class_owner(&who, token.0)?;
+			ensure!(
+				bholdus_support_nft::Pallet::<T>::is_existed(token),
+				Error::<T>::TokenIdNotFound
+			);
+			bholdus_support_nft::Pallet::<T>::set_token_metadata(&who, token, metadata.clone())?;
+			Self::deposit_event(Event::TokenMetadataSet { token, metadata });
+			Ok(())
+		}
+
+		/// Set an attribute of a token, only the class owner can call it
+		///
+		/// - `token`: (class ID, token ID)
+		/// - `key`: the attribute key
+		/// - `value`: the attribute value
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_attribute())]
+		#[transactional]
+		pub fn set_token_attribute(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::mutate_token_attributes(&who, token, |attributes| {
+				attributes.insert(key.clone(), value.clone());
+				Ok(())
+			})?;
+			Self::deposit_event(Event::TokenAttributeSet { token, key, value });
+			Ok(())
+		}
+
+		/// Remove an attribute of a token, only the class owner can call it
+		///
+		/// - `token`: (class ID, token ID)
+		/// - `key`: the attribute key
+		#[pallet::weight(<T as Config>::WeightInfo::remove_token_attribute())]
+		#[transactional]
+		pub fn remove_token_attribute(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::mutate_token_attributes(&who, token, |attributes| {
+				attributes.remove(&key).ok_or(Error::<T>::AttributeNotFound)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::TokenAttributeRemoved { token, key });
+			Ok(())
+		}
+
+		/// Freeze the metadata and attributes of a token, they can't be changed afterwards
+		///
+		/// - `token`: (class ID, token ID)
+		#[pallet::weight(<T as Config>::WeightInfo::freeze_token_metadata())]
+		#[transactional]
+		pub fn freeze_token_metadata(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_class_owner(&who, token.0)?;
+			ensure!(
+				bholdus_support_nft::Pallet::<T>::is_existed(token),
+				Error::<T>::TokenIdNotFound
+			);
+			bholdus_support_nft::Pallet::<T>::freeze_token_metadata(&who, token)?;
+			Self::deposit_event(Event::TokenMetadataFrozen { token });
+			Ok(())
+		}
+
+		/// Create NFT group
+		///
+		/// - `metadata`: external metadata
+		#[pallet::weight(<T as Config>::WeightInfo::create_group())]
+		#[transactional]
+		pub fn create_group(origin: OriginFor<T>, metadata: CID) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let group_id = bholdus_support_nft::Pallet::<T>::create_group(&who, metadata.clone())?;
+			Self::deposit_event(Event::CreatedGroup { owner: who, group_id, metadata });
+			Ok(())
+		}
+
+		/// Set the metadata of a group
+		///
+		/// - `group_id`: the group ID
+		/// - `metadata`: external metadata
+		#[pallet::weight(<T as Config>::WeightInfo::set_group_metadata())]
+		#[transactional]
+		pub fn set_group_metadata(
+			origin: OriginFor<T>,
+			group_id: GroupIdOf<T>,
+			metadata: CID,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_group_owner(&who, group_id)?;
+			bholdus_support_nft::Pallet::<T>::set_group_metadata(&who, group_id, metadata.clone())?;
+			Self::deposit_event(Event::GroupMetadataSet { group_id, metadata });
+			Ok(())
+		}
+
+		/// Destroy NFT group, it must have no tokens
+		///
+		/// - `group_id`: the group ID to destroy
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_group())]
+		#[transactional]
+		pub fn destroy_group(origin: OriginFor<T>, group_id: GroupIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_group_owner(&who, group_id)?;
+			bholdus_support_nft::Pallet::<T>::destroy_group(&who, group_id)?;
+			Self::deposit_event(Event::DestroyedGroup { owner: who, group_id });
+			Ok(())
+		}
+
+		/// Move NFT to a group of the caller. The owner of a group can also remove from it the
+		/// NFTs held by others.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `group_id`: the group ID, `None` to remove the token from its group
+		#[pallet::weight(<T as Config>::WeightInfo::move_to_group())]
+		#[transactional]
+		pub fn move_to_group(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			group_id: Option<GroupIdOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let token_info = bholdus_support_nft::Pallet::<T>::tokens(token.0, token.1)
+				.ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(
+				who == token_info.owner ||
+					(group_id.is_none() &&
+						bholdus_support_nft::Pallet::<T>::is_group_owner(&who, token)),
+				Error::<T>::NoPermission
+			);
+			if let Some(group_id) = group_id {
+				Self::ensure_group_owner(&who, group_id)?;
+			}
+			bholdus_support_nft::Pallet::<T>::move_to_group(&who, token, group_id)?;
+			Self::deposit_event(Event::MovedToGroup { token, group_id });
+			Ok(())
 		}
 	}
 }
@@ -233,16 +739,59 @@ impl<T: Config> Pallet<T> {
 		from: T::AccountId,
 		to: T::AccountId,
 		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		Self::transfer_token(&from, &to, token)?;
+		Self::deposit_event(Event::TransferredToken { from, to, token });
+
+		Ok(())
+	}
+
+	#[require_transactional]
+	fn do_transfer_batch(
+		from: T::AccountId,
+		to: T::AccountId,
+		tokens: Vec<(ClassIdOf<T>, TokenIdOf<T>)>,
+	) -> DispatchResult {
+		ensure!(
+			!tokens.is_empty() && tokens.len() as u32 <= T::MaxQuantity::get(),
+			Error::<T>::InvalidQuantity
+		);
+		for token in tokens.iter() {
+			Self::transfer_token(&from, &to, *token)?;
+		}
+		Self::deposit_event(Event::TransferredTokenBatch { from, to, tokens });
+
+		Ok(())
+	}
+
+	#[require_transactional]
+	fn do_transfer_from(
+		operator: T::AccountId,
+		from: T::AccountId,
+		to: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
 	) -> DispatchResult {
 		let _class_info = bholdus_support_nft::Pallet::<T>::classes(token.0)
 			.ok_or(Error::<T>::ClassIdNotFound)?;
 		let _token_info = bholdus_support_nft::Pallet::<T>::tokens(token.0, token.1)
 			.ok_or(Error::<T>::TokenIdNotFound)?;
-		bholdus_support_nft::Pallet::<T>::transfer(&from, &to, token)?;
+		bholdus_support_nft::Pallet::<T>::transfer_from(&operator, &from, &to, token)?;
 		Self::deposit_event(Event::TransferredToken { from, to, token });
 
 		Ok(())
 	}
+
+	fn transfer_token(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let _class_info = bholdus_support_nft::Pallet::<T>::classes(token.0)
+			.ok_or(Error::<T>::ClassIdNotFound)?;
+		let _token_info = bholdus_support_nft::Pallet::<T>::tokens(token.0, token.1)
+			.ok_or(Error::<T>::TokenIdNotFound)?;
+		bholdus_support_nft::Pallet::<T>::transfer(from, to, token)
+	}
 	#[require_transactional]
 	fn do_mint(
 		who: T::AccountId,
@@ -253,19 +802,13 @@ impl<T: Config> Pallet<T> {
 		quantity: u32,
 	) -> DispatchResult {
 		ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
-		let class_info = bholdus_support_nft::Pallet::<T>::classes(class_id)
-			.ok_or(Error::<T>::ClassIdNotFound)?;
-		if class_id != Default::default() {
-			ensure!(who == class_info.owner, Error::<T>::NoPermission);
-		}
-
 		ensure!(quantity <= T::MaxQuantity::get(), Error::<T>::InvalidQuantity);
+		Self::authorize_mint(&who, class_id, quantity)?;
 		let bounded_metadata: BoundedVec<u8, T::MaxTokenMetadata> =
 			metadata.try_into().map_err(|_| Error::<T>::MaxMetadataExceeded)?;
 
 		let data = TokenData { attributes };
-		let group_id = bholdus_support_nft::Pallet::<T>::next_group_id();
-		bholdus_support_nft::Pallet::<T>::create_group()?;
+		let group_id = bholdus_support_nft::Pallet::<T>::create_group(&to, Default::default())?;
 
 		let token_id = bholdus_support_nft::Pallet::<T>::next_token_id();
 		let token_info = TokenInfo {
@@ -289,6 +832,45 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	#[require_transactional]
+	fn do_mint_batch(
+		who: T::AccountId,
+		to: T::AccountId,
+		class_id: ClassIdOf<T>,
+		tokens: Vec<(CID, Attributes)>,
+	) -> DispatchResult {
+		ensure!(
+			!tokens.is_empty() && tokens.len() as u32 <= T::MaxQuantity::get(),
+			Error::<T>::InvalidQuantity
+		);
+		Self::authorize_mint(&who, class_id, tokens.len() as u32)?;
+
+		let group_id = bholdus_support_nft::Pallet::<T>::create_group(&to, Default::default())?;
+
+		let mut token_ids = Vec::with_capacity(tokens.len());
+		for (metadata, attributes) in tokens {
+			Self::check_attributes(&attributes)?;
+			let bounded_metadata: BoundedVec<u8, T::MaxTokenMetadata> =
+				metadata.try_into().map_err(|_| Error::<T>::MaxMetadataExceeded)?;
+			let token_info = TokenInfo {
+				metadata: bounded_metadata,
+				owner: to.clone(),
+				creator: to.clone(),
+				data: TokenData { attributes },
+			};
+			let token_id = bholdus_support_nft::Pallet::<T>::mint_to_group(
+				&to,
+				class_id,
+				group_id,
+				&token_info,
+			)?;
+			token_ids.push(token_id);
+		}
+
+		Self::deposit_event(Event::MintedTokenBatch { group_id, class_id, owner: to, token_ids });
+		Ok(())
+	}
+
 	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
 		let _class_info = bholdus_support_nft::Pallet::<T>::classes(token.0)
 			.ok_or(Error::<T>::ClassIdNotFound)?;
@@ -300,6 +882,84 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_class_owner(who: &T::AccountId, class_id: ClassIdOf<T>) -> DispatchResult {
+		let class_info = bholdus_support_nft::Pallet::<T>::classes(class_id)
+			.ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(*who == class_info.owner, Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	fn ensure_group_owner(who: &T::AccountId, group_id: GroupIdOf<T>) -> DispatchResult {
+		let group_info = bholdus_support_nft::Pallet::<T>::groups(group_id)
+			.ok_or(Error::<T>::GroupIdNotFound)?;
+		ensure!(*who == group_info.owner, Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	fn mutate_token_attributes(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		f: impl FnOnce(&mut Attributes) -> DispatchResult,
+	) -> DispatchResult {
+		Self::ensure_class_owner(who, token.0)?;
+		let token_info = bholdus_support_nft::Pallet::<T>::tokens(token.0, token.1)
+			.ok_or(Error::<T>::TokenIdNotFound)?;
+		let mut data = token_info.data;
+		f(&mut data.attributes)?;
+		Self::check_attributes(&data.attributes)?;
+		bholdus_support_nft::Pallet::<T>::set_token_data(who, token, data)
+	}
+
+	/// Check that `who` can mint `quantity` tokens of a class.
+	/// The class owner and its minters mint for free, anyone else pays the public mint price to
+	/// the class owner.
+	fn authorize_mint(who: &T::AccountId, class_id: ClassIdOf<T>, quantity: u32) -> DispatchResult {
+		let class_info = bholdus_support_nft::Pallet::<T>::classes(class_id)
+			.ok_or(Error::<T>::ClassIdNotFound)?;
+		if let Some(max_supply) = bholdus_support_nft::Pallet::<T>::class_max_supply(class_id) {
+			ensure!(
+				class_info.total_issuance.saturating_add(quantity.into()) <= max_supply,
+				Error::<T>::MaxSupplyReached
+			);
+		}
+
+		if *who == class_info.owner || ClassMinters::<T>::contains_key(class_id, who) {
+			return Ok(());
+		}
+		if let Some(price) = PublicMintPrice::<T>::get(class_id) {
+			let amount = price.saturating_mul(quantity.into());
+			return T::Currency::transfer(
+				who,
+				&class_info.owner,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			);
+		}
+		ensure!(class_id == Default::default(), Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	fn do_set_class_royalty(
+		who: T::AccountId,
+		class_id: ClassIdOf<T>,
+		royalties: Vec<RoyaltyRecipientOf<T>>,
+	) -> DispatchResult {
+		let class_info = bholdus_support_nft::Pallet::<T>::classes(class_id)
+			.ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(who == class_info.owner, Error::<T>::NoPermission);
+		ensure!(class_info.total_issuance == Zero::zero(), Error::<T>::CannotSetRoyalty);
+		ensure!(
+			royalties.len() as u32 <= T::MaxRoyaltyRecipients::get(),
+			Error::<T>::TooManyRoyaltyRecipients
+		);
+		let total = royalties.iter().fold(0u32, |acc, r| acc.saturating_add(r.basis_points));
+		ensure!(total <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+
+		bholdus_support_nft::Pallet::<T>::set_class_royalty(&who, class_id, royalties.clone())?;
+		Self::deposit_event(Event::ClassRoyaltySet { owner: who, class_id, royalties });
+		Ok(())
+	}
+
 	fn check_attributes(attributes: &Attributes) -> DispatchResult {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes
@@ -330,4 +990,13 @@ impl<T: Config> NFT<T::AccountId> for Pallet<T> {
 	) -> DispatchResult {
 		Self::do_transfer(from, to, token)
 	}
+
+	fn transfer_from(
+		operator: T::AccountId,
+		from: T::AccountId,
+		to: T::AccountId,
+		token: (Self::ClassId, Self::TokenId),
+	) -> DispatchResult {
+		Self::do_transfer_from(operator, from, to, token)
+	}
 }
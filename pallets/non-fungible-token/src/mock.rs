@@ -51,21 +51,20 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = ConstU32<16>;
 }
 
-// parameter_types! {
-//     pub const ExistentialDeposit: u64 = 1;
-//     pub const MaxReserves: u32 = 50;
-// }
-// impl pallet_balances::Config for Runtime {
-//     type Balance = Balance;
-//     type Event = Event;
-//     type DustRemoval = ();
-//     type ExistentialDeposit = ExistentialDeposit;
-//     type AccountStore = frame_system::Pallet<Runtime>;
-//     type MaxLocks = ();
-//     type MaxReserves = MaxReserves;
-//     type ReserveIdentifier = ReserveIdentifier;
-//     type WeightInfo = ();
-// }
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
 
 impl pallet_utility::Config for Runtime {
 	type Event = Event;
@@ -78,6 +77,9 @@ parameter_types! {
 	pub const NftPalletId: PalletId = PalletId(*b"bho/bNFT");
 	pub MaxAttributesBytes: u32 = 10;
 	pub MaxQuantity: u32 = 100;
+	pub MaxRoyaltyRecipients: u32 = 3;
+	pub MaxRoyalty: u32 = 5_000;
+	pub MaxClassMinters: u32 = 2;
 }
 
 impl Config for Runtime {
@@ -85,6 +87,10 @@ impl Config for Runtime {
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
 	type MaxQuantity = MaxQuantity;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxClassMinters = MaxClassMinters;
+	type Currency = Balances;
 	type WeightInfo = ();
 }
 
@@ -117,7 +123,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		NFTModule: nft::{Pallet, Call, Event<T>},
 		BNFT: bholdus_support_nft::{Pallet, Storage},
-		// Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Event},
 	}
 );
@@ -142,11 +148,9 @@ impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
 
-		// pallet_balances::GenesisConfig::<Runtime> {
-		//     balances: vec![(ALICE, 100000)],
-		// }
-		// .assimilate_storage(&mut t)
-		// .unwrap();
+		pallet_balances::GenesisConfig::<Runtime> { balances: vec![(BOB, 100_000)] }
+			.assimilate_storage(&mut t)
+			.unwrap();
 
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
@@ -4,7 +4,7 @@ use super::*;
 use frame_support::{assert_noop, assert_ok, traits::Currency};
 use mock::{Event, *};
 
-use bholdus_support_nft::TokenInfo;
+use bholdus_support_nft::{RoyaltyRecipient, TokenInfo};
 use common_primitives::Balance;
 use sp_runtime::{traits::BlakeTwo256, ArithmeticError};
 use sp_std::convert::TryInto;
@@ -24,7 +24,7 @@ fn test_attr(x: u8) -> Attributes {
 fn create_class_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), test_attr(1)));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), test_attr(1), vec![]));
 		assert_eq!(
 			bholdus_support_nft::Pallet::<Runtime>::classes(0).unwrap().data,
 			ClassData { attributes: test_attr(1) }
@@ -42,7 +42,7 @@ fn mint_should_work() {
 		let token_attr = test_attr(1u8);
 		let data = ClassData { attributes: class_attr.clone() };
 
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), class_attr.clone()));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), class_attr.clone(), vec![]));
 
 		System::assert_last_event(Event::NFTModule(crate::Event::CreatedClass {
 			owner: ALICE,
@@ -81,7 +81,7 @@ fn mint_should_work() {
 fn mint_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 
 		assert_noop!(
 			NFTModule::mint(
@@ -153,7 +153,75 @@ fn mint_should_fail() {
 fn mint_should_fail_without_mintable() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+	});
+}
+
+#[test]
+fn mint_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint_batch(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![(vec![1], test_attr(1)), (vec![2], test_attr(2))]
+		));
+
+		System::assert_last_event(Event::NFTModule(crate::Event::MintedTokenBatch {
+			group_id: GROUP_ID,
+			class_id: CLASS_ID,
+			owner: BOB,
+			token_ids: vec![0, 1],
+		}));
+		let token_info = bholdus_support_nft::Pallet::<Runtime>::tokens(CLASS_ID, 1).unwrap();
+		assert_eq!(token_info.owner, BOB);
+		assert_eq!(token_info.metadata.to_vec(), vec![2]);
+		assert_eq!(token_info.data, TokenData { attributes: test_attr(2) });
+		assert_eq!(
+			bholdus_support_nft::Pallet::<Runtime>::classes(CLASS_ID)
+				.unwrap()
+				.total_issuance,
+			2
+		);
+	});
+}
+
+#[test]
+fn mint_batch_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+
+		assert_noop!(
+			NFTModule::mint_batch(Origin::signed(ALICE), BOB, CLASS_ID, vec![]),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(ALICE),
+				BOB,
+				CLASS_ID,
+				vec![(vec![1], Default::default()); 101]
+			),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_noop!(
+			NFTModule::mint_batch(Origin::signed(BOB), BOB, 1, vec![(vec![1], Default::default())]),
+			Error::<Runtime>::NoPermission
+		);
+
+		let mut large_attr = test_attr(1);
+		large_attr.insert(vec![1, 2, 3], vec![4, 5, 6]);
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(ALICE),
+				BOB,
+				CLASS_ID,
+				vec![(vec![1], test_attr(1)), (vec![2], large_attr)]
+			),
+			Error::<Runtime>::AttributesTooLarge
+		);
 	});
 }
 
@@ -161,7 +229,7 @@ fn mint_should_fail_without_mintable() {
 fn transfer_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1, 2, 3];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 
 		assert_ok!(NFTModule::mint(
 			Origin::signed(ALICE),
@@ -190,11 +258,101 @@ fn transfer_should_work() {
 	});
 }
 
+#[test]
+fn approve_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::approve(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), Some(ALICE)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(ALICE), BOB, ALICE, (CLASS_ID, TOKEN_ID)),
+			bholdus_support_nft::Error::<Runtime>::NotApproved
+		);
+
+		assert_ok!(NFTModule::approve(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), Some(ALICE)));
+		System::assert_last_event(Event::NFTModule(crate::Event::Approved {
+			owner: BOB,
+			token: (CLASS_ID, TOKEN_ID),
+			approved: Some(ALICE),
+		}));
+		assert_ok!(NFTModule::transfer_from(
+			Origin::signed(ALICE),
+			BOB,
+			DAVE,
+			(CLASS_ID, TOKEN_ID)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TransferredToken {
+			from: BOB,
+			to: DAVE,
+			token: (CLASS_ID, TOKEN_ID),
+		}));
+		assert_eq!(NFTModule::owner((CLASS_ID, TOKEN_ID)), Some(DAVE));
+
+		// The approval does not survive the transfer
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(ALICE), DAVE, ALICE, (CLASS_ID, TOKEN_ID)),
+			bholdus_support_nft::Error::<Runtime>::NotApproved
+		);
+	});
+}
+
+#[test]
+fn set_operator_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			2
+		));
+
+		assert_noop!(
+			NFTModule::set_operator(Origin::signed(BOB), CLASS_ID_NOT_EXIST, ALICE, true),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_ok!(NFTModule::set_operator(Origin::signed(BOB), CLASS_ID, ALICE, true));
+		System::assert_last_event(Event::NFTModule(crate::Event::OperatorSet {
+			owner: BOB,
+			class_id: CLASS_ID,
+			operator: ALICE,
+			approved: true,
+		}));
+		assert_ok!(NFTModule::transfer_from(Origin::signed(ALICE), BOB, DAVE, (CLASS_ID, 0)));
+
+		bholdus_support_nft::Pallet::<Runtime>::lock(&BOB, (CLASS_ID, 1));
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(ALICE), BOB, DAVE, (CLASS_ID, 1)),
+			bholdus_support_nft::Error::<Runtime>::IsLocked
+		);
+		bholdus_support_nft::Pallet::<Runtime>::unlock(&BOB, (CLASS_ID, 1));
+
+		assert_ok!(NFTModule::set_operator(Origin::signed(BOB), CLASS_ID, ALICE, false));
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(ALICE), BOB, DAVE, (CLASS_ID, 1)),
+			bholdus_support_nft::Error::<Runtime>::NotApproved
+		);
+	});
+}
+
 #[test]
 fn transfer_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 		assert_ok!(NFTModule::mint(
 			Origin::signed(
                         ALICE, //class_id_account()
@@ -219,15 +377,74 @@ fn transfer_should_fail() {
 
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 	})
 }
 
+#[test]
+fn transfer_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			3
+		));
+
+		let tokens = vec![(CLASS_ID, 0), (CLASS_ID, 2)];
+		assert_ok!(NFTModule::transfer_batch(Origin::signed(BOB), DAVE, tokens.clone()));
+		System::assert_last_event(Event::NFTModule(crate::Event::TransferredTokenBatch {
+			from: BOB,
+			to: DAVE,
+			tokens,
+		}));
+		assert!(bholdus_support_nft::Pallet::<Runtime>::is_owner(&DAVE, (CLASS_ID, 0)));
+		assert!(bholdus_support_nft::Pallet::<Runtime>::is_owner(&BOB, (CLASS_ID, 1)));
+		assert!(bholdus_support_nft::Pallet::<Runtime>::is_owner(&DAVE, (CLASS_ID, 2)));
+	});
+}
+
+#[test]
+fn transfer_batch_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			2
+		));
+
+		assert_noop!(
+			NFTModule::transfer_batch(Origin::signed(BOB), DAVE, vec![]),
+			Error::<Runtime>::InvalidQuantity
+		);
+		// The whole batch fails if one of the tokens can't be transferred
+		assert_noop!(
+			NFTModule::transfer_batch(
+				Origin::signed(BOB),
+				DAVE,
+				vec![(CLASS_ID, 0), (CLASS_ID, TOKEN_ID_NOT_EXIST + 1)]
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+		assert_noop!(
+			NFTModule::transfer_batch(Origin::signed(ALICE), DAVE, vec![(CLASS_ID, 0)]),
+			bholdus_support_nft::Error::<Runtime>::NoPermission
+		);
+	});
+}
+
 #[test]
 fn burn_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 
 		assert_ok!(NFTModule::mint(
 			Origin::signed(ALICE),
@@ -251,7 +468,7 @@ fn burn_should_work() {
 fn burn_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 		assert_ok!(NFTModule::mint(
 			Origin::signed(
 				//class_id_account()
@@ -282,7 +499,7 @@ fn burn_should_fail() {
 #[test]
 fn destroy_class_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 
 		assert_ok!(NFTModule::destroy_class(Origin::signed(ALICE), CLASS_ID));
 		System::assert_last_event(Event::NFTModule(crate::Event::DestroyedClass {
@@ -295,7 +512,7 @@ fn destroy_class_should_work() {
 fn destroy_class_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
 		let metadata = vec![1];
-		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(),));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
 
 		assert_ok!(NFTModule::mint(
 			Origin::signed(ALICE,),
@@ -322,3 +539,490 @@ fn destroy_class_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn set_class_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		let royalties = vec![
+			RoyaltyRecipient { recipient: ALICE, basis_points: 300 },
+			RoyaltyRecipient { recipient: DAVE, basis_points: 200 },
+		];
+
+		assert_ok!(NFTModule::set_class_royalty(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			royalties.clone()
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ClassRoyaltySet {
+			owner: ALICE,
+			class_id: CLASS_ID,
+			royalties: royalties.clone(),
+		}));
+		assert_eq!(bholdus_support_nft::Pallet::<Runtime>::class_royalties(CLASS_ID), royalties);
+	})
+}
+
+#[test]
+fn set_class_royalty_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let royalty = RoyaltyRecipient { recipient: ALICE, basis_points: 1_000 };
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(ALICE), CLASS_ID, vec![royalty.clone()]),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(BOB), CLASS_ID, vec![royalty.clone()]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(ALICE), CLASS_ID, vec![royalty.clone(); 4]),
+			Error::<Runtime>::TooManyRoyaltyRecipients
+		);
+		assert_noop!(
+			NFTModule::set_class_royalty(
+				Origin::signed(ALICE),
+				CLASS_ID,
+				vec![royalty.clone(), RoyaltyRecipient { recipient: BOB, basis_points: 4_001 }]
+			),
+			Error::<Runtime>::RoyaltyTooHigh
+		);
+
+		// Royalty can't change once tokens are minted
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+		assert_noop!(
+			NFTModule::set_class_royalty(Origin::signed(ALICE), CLASS_ID, vec![royalty]),
+			Error::<Runtime>::CannotSetRoyalty
+		);
+	})
+}
+
+#[test]
+fn create_class_with_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let royalties = vec![RoyaltyRecipient { recipient: DAVE, basis_points: 500 }];
+		assert_noop!(
+			NFTModule::create_class(
+				Origin::signed(ALICE),
+				Default::default(),
+				vec![RoyaltyRecipient { recipient: DAVE, basis_points: 5_001 }]
+			),
+			Error::<Runtime>::RoyaltyTooHigh
+		);
+
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			Default::default(),
+			royalties.clone()
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ClassRoyaltySet {
+			owner: ALICE,
+			class_id: CLASS_ID,
+			royalties: royalties.clone(),
+		}));
+		assert_eq!(bholdus_support_nft::Pallet::<Runtime>::class_royalties(CLASS_ID), royalties);
+	})
+}
+
+#[test]
+fn class_minter_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Anyone can mint tokens of the default class
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		let class_id = 1;
+
+		assert_noop!(
+			NFTModule::mint(Origin::signed(BOB), BOB, class_id, vec![1], Default::default(), 1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::add_class_minter(Origin::signed(BOB), class_id, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::add_class_minter(Origin::signed(ALICE), class_id, BOB));
+		System::assert_last_event(Event::NFTModule(crate::Event::ClassMinterAdded {
+			class_id,
+			minter: BOB,
+		}));
+		assert_noop!(
+			NFTModule::add_class_minter(Origin::signed(ALICE), class_id, BOB),
+			Error::<Runtime>::AlreadyMinter
+		);
+
+		assert_ok!(NFTModule::mint(
+			Origin::signed(BOB),
+			DAVE,
+			class_id,
+			vec![1],
+			Default::default(),
+			2
+		));
+		assert_eq!(
+			bholdus_support_nft::Pallet::<Runtime>::classes(class_id)
+				.unwrap()
+				.total_issuance,
+			2
+		);
+
+		assert_ok!(NFTModule::remove_class_minter(Origin::signed(ALICE), class_id, BOB));
+		System::assert_last_event(Event::NFTModule(crate::Event::ClassMinterRemoved {
+			class_id,
+			minter: BOB,
+		}));
+		assert_noop!(
+			NFTModule::remove_class_minter(Origin::signed(ALICE), class_id, BOB),
+			Error::<Runtime>::NotMinter
+		);
+		assert_noop!(
+			NFTModule::mint(Origin::signed(BOB), BOB, class_id, vec![1], Default::default(), 1),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn class_minters_should_be_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::add_class_minter(Origin::signed(ALICE), CLASS_ID, BOB));
+		assert_ok!(NFTModule::add_class_minter(Origin::signed(ALICE), CLASS_ID, DAVE));
+		assert_eq!(NFTModule::class_minter_count(CLASS_ID), 2);
+		assert_noop!(
+			NFTModule::add_class_minter(Origin::signed(ALICE), CLASS_ID, ALICE),
+			Error::<Runtime>::TooManyMinters
+		);
+
+		assert_ok!(NFTModule::remove_class_minter(Origin::signed(ALICE), CLASS_ID, DAVE));
+		assert_eq!(NFTModule::class_minter_count(CLASS_ID), 1);
+		assert_ok!(NFTModule::add_class_minter(Origin::signed(ALICE), CLASS_ID, ALICE));
+
+		assert_ok!(NFTModule::destroy_class(Origin::signed(ALICE), CLASS_ID));
+		assert_eq!(NFTModule::class_minter_count(CLASS_ID), 0);
+		assert!(NFTModule::class_minters(CLASS_ID, BOB).is_none());
+		assert!(NFTModule::class_minters(CLASS_ID, ALICE).is_none());
+	});
+}
+
+#[test]
+fn max_supply_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_noop!(
+			NFTModule::set_max_supply(Origin::signed(BOB), CLASS_ID, Some(2)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::set_max_supply(Origin::signed(ALICE), CLASS_ID, Some(2)));
+		System::assert_last_event(Event::NFTModule(crate::Event::MaxSupplySet {
+			class_id: CLASS_ID,
+			max_supply: Some(2),
+		}));
+
+		assert_noop!(
+			NFTModule::mint(Origin::signed(ALICE), BOB, CLASS_ID, vec![1], Default::default(), 3),
+			Error::<Runtime>::MaxSupplyReached
+		);
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			2
+		));
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(ALICE),
+				BOB,
+				CLASS_ID,
+				vec![(vec![1], Default::default())]
+			),
+			Error::<Runtime>::MaxSupplyReached
+		);
+		assert_noop!(
+			NFTModule::set_max_supply(Origin::signed(ALICE), CLASS_ID, Some(1)),
+			bholdus_support_nft::Error::<Runtime>::InvalidMaxSupply
+		);
+
+		assert_ok!(NFTModule::set_max_supply(Origin::signed(ALICE), CLASS_ID, None));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+	});
+}
+
+#[test]
+fn public_mint_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		let class_id = 1;
+
+		assert_noop!(
+			NFTModule::set_public_mint(Origin::signed(BOB), class_id, Some(100)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::set_public_mint(Origin::signed(ALICE), class_id, Some(100)));
+		System::assert_last_event(Event::NFTModule(crate::Event::PublicMintSet {
+			class_id,
+			price: Some(100),
+		}));
+
+		// The price of each token is paid to the class owner
+		assert_ok!(NFTModule::mint(
+			Origin::signed(BOB),
+			BOB,
+			class_id,
+			vec![1],
+			Default::default(),
+			2
+		));
+		assert_eq!(Balances::free_balance(&BOB), 99_800);
+		assert_eq!(Balances::free_balance(&ALICE), 200);
+		assert!(bholdus_support_nft::Pallet::<Runtime>::is_owner(&BOB, (class_id, 1)));
+		assert_noop!(
+			NFTModule::mint(Origin::signed(DAVE), DAVE, class_id, vec![1], Default::default(), 1),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+
+		assert_ok!(NFTModule::set_public_mint(Origin::signed(ALICE), class_id, None));
+		assert_noop!(
+			NFTModule::mint(Origin::signed(BOB), BOB, class_id, vec![1], Default::default(), 1),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_token_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::set_token_metadata(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), vec![2]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_token_metadata(
+				Origin::signed(ALICE),
+				(CLASS_ID, TOKEN_ID_NOT_EXIST),
+				vec![2]
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+		assert_ok!(NFTModule::set_token_metadata(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			vec![2]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenMetadataSet {
+			token: (CLASS_ID, TOKEN_ID),
+			metadata: vec![2],
+		}));
+		assert_eq!(
+			bholdus_support_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![2]
+		);
+	});
+}
+
+#[test]
+fn set_token_attribute_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(BOB),
+				(CLASS_ID, TOKEN_ID),
+				vec![1],
+				vec![1]
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(ALICE),
+				(CLASS_ID, TOKEN_ID),
+				vec![1],
+				vec![0; 10]
+			),
+			Error::<Runtime>::AttributesTooLarge
+		);
+		assert_ok!(NFTModule::set_token_attribute(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			vec![1],
+			vec![2]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenAttributeSet {
+			token: (CLASS_ID, TOKEN_ID),
+			key: vec![1],
+			value: vec![2],
+		}));
+		let mut attributes: Attributes = BTreeMap::new();
+		attributes.insert(vec![1], vec![2]);
+		assert_eq!(
+			bholdus_support_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().data,
+			TokenData { attributes }
+		);
+
+		assert_ok!(NFTModule::remove_token_attribute(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			vec![1]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenAttributeRemoved {
+			token: (CLASS_ID, TOKEN_ID),
+			key: vec![1],
+		}));
+		assert_noop!(
+			NFTModule::remove_token_attribute(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), vec![1]),
+			Error::<Runtime>::AttributeNotFound
+		);
+	});
+}
+
+#[test]
+fn freeze_token_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::freeze_token_metadata(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::freeze_token_metadata(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::TokenMetadataFrozen {
+			token: (CLASS_ID, TOKEN_ID),
+		}));
+		assert!(bholdus_support_nft::Pallet::<Runtime>::is_metadata_frozen((CLASS_ID, TOKEN_ID)));
+
+		assert_noop!(
+			NFTModule::set_token_metadata(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), vec![2]),
+			bholdus_support_nft::Error::<Runtime>::MetadataFrozen
+		);
+		assert_noop!(
+			NFTModule::set_token_attribute(
+				Origin::signed(ALICE),
+				(CLASS_ID, TOKEN_ID),
+				vec![1],
+				vec![2]
+			),
+			bholdus_support_nft::Error::<Runtime>::MetadataFrozen
+		);
+		assert_noop!(
+			NFTModule::freeze_token_metadata(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			bholdus_support_nft::Error::<Runtime>::MetadataFrozen
+		);
+	});
+}
+
+#[test]
+fn group_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(Origin::signed(ALICE), Default::default(), vec![]));
+		// Minting creates group 0 owned by the receiver
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			2
+		));
+		assert_eq!(bholdus_support_nft::Pallet::<Runtime>::groups(0).unwrap().owner, BOB);
+
+		assert_ok!(NFTModule::create_group(Origin::signed(BOB), vec![1]));
+		System::assert_last_event(Event::NFTModule(crate::Event::CreatedGroup {
+			owner: BOB,
+			group_id: 1,
+			metadata: vec![1],
+		}));
+		assert_noop!(
+			NFTModule::set_group_metadata(Origin::signed(ALICE), 1, vec![2]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::set_group_metadata(Origin::signed(BOB), 1, vec![2]));
+		System::assert_last_event(Event::NFTModule(crate::Event::GroupMetadataSet {
+			group_id: 1,
+			metadata: vec![2],
+		}));
+
+		assert_noop!(
+			NFTModule::move_to_group(Origin::signed(BOB), (CLASS_ID, 0), Some(2)),
+			Error::<Runtime>::GroupIdNotFound
+		);
+		assert_noop!(
+			NFTModule::move_to_group(Origin::signed(ALICE), (CLASS_ID, 0), Some(1)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::move_to_group(Origin::signed(BOB), (CLASS_ID, 0), Some(1)));
+		System::assert_last_event(Event::NFTModule(crate::Event::MovedToGroup {
+			token: (CLASS_ID, 0),
+			group_id: Some(1),
+		}));
+		assert_ok!(NFTModule::move_to_group(Origin::signed(BOB), (CLASS_ID, 1), Some(1)));
+		let mut tokens = bholdus_support_nft::Pallet::<Runtime>::tokens_of_group(1);
+		tokens.sort();
+		assert_eq!(tokens, vec![(CLASS_ID, 0), (CLASS_ID, 1)]);
+
+		assert_ok!(NFTModule::destroy_group(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::NFTModule(crate::Event::DestroyedGroup {
+			owner: BOB,
+			group_id: 0,
+		}));
+		assert_noop!(
+			NFTModule::destroy_group(Origin::signed(BOB), 1),
+			bholdus_support_nft::Error::<Runtime>::GroupNotEmpty
+		);
+
+		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, 0)));
+		// The group is kept on transfer and its owner can remove the tokens held by others
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, 1)));
+		assert_eq!(bholdus_support_nft::Pallet::<Runtime>::tokens_of_group(1), vec![(CLASS_ID, 1)]);
+		assert_ok!(NFTModule::move_to_group(Origin::signed(BOB), (CLASS_ID, 1), None));
+		assert_ok!(NFTModule::destroy_group(Origin::signed(BOB), 1));
+	});
+}
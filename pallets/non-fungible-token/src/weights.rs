@@ -20,6 +20,13 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2021-11-30, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("phoenix-dev"), DB CACHE: 128
+//!
+//! NOTE: the weights of `create_class`, `mint`, `mint_batch`, `transfer_batch`, `approve`,
+//! `set_operator`, `transfer_from`, `destroy_class`, `set_class_royalty`, `add_class_minter`,
+//! `remove_class_minter`, `set_max_supply`, `set_public_mint`, `set_token_metadata`,
+//! `set_token_attribute`, `remove_token_attribute`, `freeze_token_metadata`, `create_group`,
+//! `set_group_metadata`, `destroy_group`, `move_to_group` are placeholder estimates written by
+//! hand, not benchmark output. Regenerate this file with the command below.
 
 // Executed Command:
 
@@ -56,15 +63,51 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for bholdus_nft.
 pub trait WeightInfo {
 	
-	fn create_class() -> Weight;
+	fn create_class(r: u32, ) -> Weight;
 	
 	fn mint(i: u32, ) -> Weight;
 	
+	fn mint_batch(i: u32, ) -> Weight;
+	
 	fn transfer() -> Weight;
 	
+	fn transfer_batch(i: u32, ) -> Weight;
+	
+	fn approve() -> Weight;
+	
+	fn set_operator() -> Weight;
+	
+	fn transfer_from() -> Weight;
+	
 	fn burn() -> Weight;
 	
-	fn destroy_class() -> Weight;
+	fn destroy_class(m: u32, ) -> Weight;
+	
+	fn set_class_royalty(r: u32, ) -> Weight;
+	
+	fn add_class_minter() -> Weight;
+	
+	fn remove_class_minter() -> Weight;
+	
+	fn set_max_supply() -> Weight;
+	
+	fn set_public_mint() -> Weight;
+	
+	fn set_token_metadata() -> Weight;
+	
+	fn set_token_attribute() -> Weight;
+	
+	fn remove_token_attribute() -> Weight;
+	
+	fn freeze_token_metadata() -> Weight;
+	
+	fn create_group() -> Weight;
+	
+	fn set_group_metadata() -> Weight;
+	
+	fn destroy_group() -> Weight;
+	
+	fn move_to_group() -> Weight;
 	
 }
 
@@ -79,15 +122,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: BholdusSupportNFT Classes (r:0 w:1)
 	
-	fn create_class() -> Weight {
-		(29_051_000 as Weight)
+	// Storage: BholdusSupportNFT ClassRoyalties (r:0 w:1)
+	
+	fn create_class(r: u32, ) -> Weight {
+		(30_326_000 as Weight)
+			
+			.saturating_add((425_000 as Weight).saturating_mul(r as Weight))
 			
 			
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 			
 			
 	}
@@ -95,6 +142,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: BholdusSupportNFT Classes (r:1 w:1)
 	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:1 w:0)
+	
 	// Storage: BholdusSupportNFT NextGroupId (r:1 w:1)
 	
 	// Storage: BholdusSupportNFT NextTokenId (r:1 w:1)
@@ -110,11 +159,44 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn mint(i: u32, ) -> Weight {
 		(8_485_000 as Weight)
 			
-			// Standard Error: 274_000
 			.saturating_add((39_371_000 as Weight).saturating_mul(i as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT NextGroupId (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT NextTokenId (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT NextTokenIdByClass (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT TokensByGroup (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT Tokens (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:1)
+	
+	fn mint_batch(i: u32, ) -> Weight {
+		(9_102_000 as Weight)
+			
+			.saturating_add((41_826_000 as Weight).saturating_mul(i as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			
 			
 			
@@ -146,12 +228,70 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	
 	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	fn transfer_batch(i: u32, ) -> Weight {
+		(3_914_000 as Weight)
+			
+			.saturating_add((83_406_000 as Weight).saturating_mul(i as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+			
+	}
+	
+	
 	// Storage: BholdusSupportNFT Classes (r:1 w:1)
 	
 	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
 	
 	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:1)
 	
+	fn approve() -> Weight {
+		(19_740_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn set_operator() -> Weight {
+		(17_903_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn transfer_from() -> Weight {
+		(32_615_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+	}
 	fn burn() -> Weight {
 		(73_628_000 as Weight)
 			
@@ -168,21 +308,227 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: BholdusSupportNFT Classes (r:1 w:1)
 	
+	// Storage: NFT ClassMinterCount (r:1 w:1)
+	
 	// Storage: BholdusSupportNFT NextTokenIdByClass (r:0 w:1)
 	
-	fn destroy_class() -> Weight {
-		(40_871_000 as Weight)
+	// Storage: BholdusSupportNFT ClassRoyalties (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:0 w:1)
+	
+	// Storage: NFT PublicMintPrice (r:0 w:1)
+	
+	// Storage: NFT ClassMinters (r:0 w:1)
+	
+	fn destroy_class(m: u32, ) -> Weight {
+		(43_118_000 as Weight)
+			
+			.saturating_add((1_307_000 as Weight).saturating_mul(m as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			
+			
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassRoyalties (r:0 w:1)
+	
+	fn set_class_royalty(r: u32, ) -> Weight {
+		(18_240_000 as Weight)
+			
+			.saturating_add((412_000 as Weight).saturating_mul(r as Weight))
 			
 			
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			
 			
 			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: NFT ClassMinters (r:1 w:1)
+	
+	// Storage: NFT ClassMinterCount (r:1 w:1)
+	
+	fn add_class_minter() -> Weight {
+		(24_906_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: NFT ClassMinters (r:1 w:1)
+	
+	// Storage: NFT ClassMinterCount (r:1 w:1)
+	
+	fn remove_class_minter() -> Weight {
+		(25_318_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			
 			
 	}
 	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:0 w:1)
+	
+	fn set_max_supply() -> Weight {
+		(19_862_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: NFT PublicMintPrice (r:0 w:1)
+	
+	fn set_public_mint() -> Weight {
+		(17_394_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn set_token_metadata() -> Weight {
+		(21_476_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn set_token_attribute() -> Weight {
+		(22_908_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn remove_token_attribute() -> Weight {
+		(22_517_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn freeze_token_metadata() -> Weight {
+		(20_135_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn create_group() -> Weight {
+		(16_284_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	fn set_group_metadata() -> Weight {
+		(17_052_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn destroy_group() -> Weight {
+		(19_377_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn move_to_group() -> Weight {
+		(26_841_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
 }
 
 // For backwards compatibility and tests
@@ -193,15 +539,19 @@ impl WeightInfo for () {
 	
 	// Storage: BholdusSupportNFT Classes (r:0 w:1)
 	
-	fn create_class() -> Weight {
-		(29_051_000 as Weight)
+	// Storage: BholdusSupportNFT ClassRoyalties (r:0 w:1)
+	
+	fn create_class(r: u32, ) -> Weight {
+		(30_326_000 as Weight)
+			
+			.saturating_add((425_000 as Weight).saturating_mul(r as Weight))
 			
 			
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 			
 			
 	}
@@ -209,6 +559,8 @@ impl WeightInfo for () {
 	
 	// Storage: BholdusSupportNFT Classes (r:1 w:1)
 	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:1 w:0)
+	
 	// Storage: BholdusSupportNFT NextGroupId (r:1 w:1)
 	
 	// Storage: BholdusSupportNFT NextTokenId (r:1 w:1)
@@ -224,11 +576,44 @@ impl WeightInfo for () {
 	fn mint(i: u32, ) -> Weight {
 		(8_485_000 as Weight)
 			
-			// Standard Error: 274_000
 			.saturating_add((39_371_000 as Weight).saturating_mul(i as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT NextGroupId (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT NextTokenId (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT NextTokenIdByClass (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT TokensByGroup (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT Tokens (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:1)
+	
+	fn mint_batch(i: u32, ) -> Weight {
+		(9_102_000 as Weight)
+			
+			.saturating_add((41_826_000 as Weight).saturating_mul(i as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			
 			
 			
@@ -260,12 +645,70 @@ impl WeightInfo for () {
 	}
 	
 	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
+	
+	// Storage: BholdusSupportNFT LockableNFT (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:2)
+	
+	fn transfer_batch(i: u32, ) -> Weight {
+		(3_914_000 as Weight)
+			
+			.saturating_add((83_406_000 as Weight).saturating_mul(i as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+			
+	}
+	
+	
 	// Storage: BholdusSupportNFT Classes (r:1 w:1)
 	
 	// Storage: BholdusSupportNFT Tokens (r:1 w:1)
 	
 	// Storage: BholdusSupportNFT TokensByOwner (r:0 w:1)
 	
+	fn approve() -> Weight {
+		(19_740_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn set_operator() -> Weight {
+		(17_903_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn transfer_from() -> Weight {
+		(32_615_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+	}
 	fn burn() -> Weight {
 		(73_628_000 as Weight)
 			
@@ -282,10 +725,180 @@ impl WeightInfo for () {
 	
 	// Storage: BholdusSupportNFT Classes (r:1 w:1)
 	
+	// Storage: NFT ClassMinterCount (r:1 w:1)
+	
 	// Storage: BholdusSupportNFT NextTokenIdByClass (r:0 w:1)
 	
-	fn destroy_class() -> Weight {
-		(40_871_000 as Weight)
+	// Storage: BholdusSupportNFT ClassRoyalties (r:0 w:1)
+	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:0 w:1)
+	
+	// Storage: NFT PublicMintPrice (r:0 w:1)
+	
+	// Storage: NFT ClassMinters (r:0 w:1)
+	
+	fn destroy_class(m: u32, ) -> Weight {
+		(43_118_000 as Weight)
+			
+			.saturating_add((1_307_000 as Weight).saturating_mul(m as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassRoyalties (r:0 w:1)
+	
+	fn set_class_royalty(r: u32, ) -> Weight {
+		(18_240_000 as Weight)
+			
+			.saturating_add((412_000 as Weight).saturating_mul(r as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: NFT ClassMinters (r:1 w:1)
+	
+	// Storage: NFT ClassMinterCount (r:1 w:1)
+	
+	fn add_class_minter() -> Weight {
+		(24_906_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: NFT ClassMinters (r:1 w:1)
+	
+	// Storage: NFT ClassMinterCount (r:1 w:1)
+	
+	fn remove_class_minter() -> Weight {
+		(25_318_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: BholdusSupportNFT ClassMaxSupply (r:0 w:1)
+	
+	fn set_max_supply() -> Weight {
+		(19_862_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: BholdusSupportNFT Classes (r:1 w:0)
+	
+	// Storage: NFT PublicMintPrice (r:0 w:1)
+	
+	fn set_public_mint() -> Weight {
+		(17_394_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn set_token_metadata() -> Weight {
+		(21_476_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn set_token_attribute() -> Weight {
+		(22_908_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn remove_token_attribute() -> Weight {
+		(22_517_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn freeze_token_metadata() -> Weight {
+		(20_135_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn create_group() -> Weight {
+		(16_284_000 as Weight)
 			
 			
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
@@ -295,6 +908,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			
 			
+	}
+	fn set_group_metadata() -> Weight {
+		(17_052_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn destroy_group() -> Weight {
+		(19_377_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	fn move_to_group() -> Weight {
+		(26_841_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
 	}
 	
 }
\ No newline at end of file
@@ -1,5 +1,10 @@
 use crate::*;
 use codec::{Decode, Encode};
+use frame_support::traits::{
+	BalanceStatus, Currency as PalletCurrency, ExistenceRequirement,
+	ReservableCurrency as PalletReservableCurrency,
+};
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto};
 
 use sp_std::{
 	fmt::{Debug, Display, Formatter},
@@ -35,10 +40,35 @@ pub struct TimeAuctionListingInfo<AccountId, CurrencyId, Moment> {
 	pub auction_end: Moment,
 	pub service_fee: (Numerator, Denominator),
 	pub fee_recipient: AccountId,
-	pub royalty_recipient: AccountId,
+	pub royalty_recipients: Vec<RoyaltyRecipient<AccountId>>,
 	pub bid_count: u32,
 }
 
+/// Dutch auction listing. The price decays linearly from `start_price` at `auction_start` to
+/// `floor_price` at `auction_end`, the first buyer pays the current price.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct DutchAuctionListingInfo<AccountId, CurrencyId, Moment> {
+	pub owner: AccountId,
+	pub start_price: Price,
+	pub floor_price: Price,
+	pub currency_id: CurrencyId,
+	pub royalty: (Numerator, Denominator),
+	pub status: NFTState,
+	pub auction_start: Moment,
+	pub auction_end: Moment,
+	pub service_fee: (Numerator, Denominator),
+	pub fee_recipient: AccountId,
+	pub royalty_recipients: Vec<RoyaltyRecipient<AccountId>>,
+}
+
+/// Highest bid of a time auction. The amount is reserved from the bidder until the bid is
+/// outbid, cancelled or settled.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct AuctionBid<AccountId> {
+	pub bidder: AccountId,
+	pub amount: Balance,
+}
+
 impl<T: Config> Pallet<T> {
 	pub fn approve_time_auction(
 		owner: &T::AccountId,
@@ -52,4 +82,214 @@ impl<T: Config> Pallet<T> {
 			Ok(())
 		})
 	}
+
+	/// Place a new highest bid, releasing the previous one
+	pub fn new_bid(
+		owner: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		bid: AuctionBidOf<T>,
+	) -> DispatchResult {
+		TimeAuction::<T>::try_mutate_exists((owner, token.0, token.1), |info| -> DispatchResult {
+			let listing_info = info.as_mut().ok_or(Error::<T>::NotFound)?;
+			Self::cancel_bid(token, listing_info.currency_id.clone());
+			Self::reserve(listing_info.currency_id.clone(), &bid.bidder, bid.amount)?;
+			listing_info.bid_count = listing_info.bid_count.saturating_add(1);
+			AuctionBids::<T>::insert(token.0, token.1, bid);
+			Ok(())
+		})
+	}
+
+	/// Release the highest bid of a time auction, if any
+	pub fn cancel_bid(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+	) {
+		if let Some(bid) = AuctionBids::<T>::take(token.0, token.1) {
+			Self::unreserve(currency_id, &bid.bidder, bid.amount);
+		}
+	}
+
+	/// Conclude a time auction.
+	/// The highest bid, if any, is paid to the fee recipient, the royalty recipients and the
+	/// owner, and the NFT is transferred to the highest bidder.
+	pub fn conclude_time_auction(
+		owner: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> Result<Option<AuctionBidOf<T>>, DispatchError> {
+		let listing_info =
+			TimeAuction::<T>::take((owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
+		ItemListing::<T>::remove((owner, token.0, token.1));
+		Self::unlock_item(owner, token);
+
+		let bid = match AuctionBids::<T>::take(token.0, token.1) {
+			Some(bid) => bid,
+			None => return Ok(None),
+		};
+		let fee_amount = Self::calc_amount(bid.amount, listing_info.service_fee);
+		let royalty_amount = Self::royalty_amount(bid.amount, &listing_info.royalty_recipients);
+		let actual_price = bid.amount.saturating_sub(fee_amount).saturating_sub(royalty_amount);
+
+		let currency_id = listing_info.currency_id;
+		Self::pay_reserved(
+			currency_id.clone(),
+			&bid.bidder,
+			&listing_info.fee_recipient,
+			fee_amount,
+		)?;
+		Self::pay_reserved_royalties(
+			currency_id.clone(),
+			&bid.bidder,
+			bid.amount,
+			&listing_info.royalty_recipients,
+		)?;
+		Self::pay_reserved(currency_id, &bid.bidder, owner, actual_price)?;
+		Self::transfer(owner, &bid.bidder, token)?;
+
+		Ok(Some(bid))
+	}
+
+	pub fn approve_dutch_auction(
+		owner: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let now = T::Time::now();
+		DutchAuction::<T>::try_mutate_exists((owner, token.0, token.1), |info| -> DispatchResult {
+			let listing_info = info.as_mut().ok_or(Error::<T>::NotFound)?;
+			ensure!(now < listing_info.auction_end, Error::<T>::AuctionAlreadyConcluded);
+			ensure!(listing_info.status == NFTState::Pending, Error::<T>::IsApproved);
+			listing_info.status = NFTState::Listing;
+			Ok(())
+		})
+	}
+
+	/// Current price of a dutch auction
+	pub fn dutch_auction_price(info: &DutchAuctionListingInfoOf<T>) -> Balance {
+		let now = T::Time::now();
+		if now <= info.auction_start {
+			return info.start_price;
+		}
+		if now >= info.auction_end {
+			return info.floor_price;
+		}
+
+		let elapsed: u128 = now.saturating_sub(info.auction_start).unique_saturated_into();
+		let duration: u128 =
+			info.auction_end.saturating_sub(info.auction_start).unique_saturated_into();
+		let decay = U256::from(info.start_price.saturating_sub(info.floor_price))
+			.saturating_mul(U256::from(elapsed))
+			.checked_div(U256::from(duration))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero);
+		info.start_price.saturating_sub(decay)
+	}
+
+	/// Conclude a dutch auction.
+	/// `price` is paid by the buyer to the fee recipient, the royalty recipients and the owner,
+	/// and the NFT is transferred to the buyer.
+	pub fn conclude_dutch_auction(
+		owner: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		buyer: &T::AccountId,
+		price: Balance,
+	) -> DispatchResult {
+		let listing_info =
+			DutchAuction::<T>::take((owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
+		ItemListing::<T>::remove((owner, token.0, token.1));
+		Self::unlock_item(owner, token);
+
+		let fee_amount = Self::calc_amount(price, listing_info.service_fee);
+		let royalty_amount = Self::royalty_amount(price, &listing_info.royalty_recipients);
+		let actual_price = price.saturating_sub(fee_amount).saturating_sub(royalty_amount);
+
+		let currency_id = listing_info.currency_id;
+		Self::transfer_currency(
+			currency_id.clone(),
+			buyer,
+			&listing_info.fee_recipient,
+			fee_amount,
+		)?;
+		Self::pay_royalties(currency_id.clone(), buyer, price, &listing_info.royalty_recipients)?;
+		Self::transfer_currency(currency_id, buyer, owner, actual_price)?;
+		Self::transfer(owner, buyer, token)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn reserve(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		who: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		match currency_id {
+			NFTCurrencyId::Native => <pallet_balances::Pallet<T> as PalletReservableCurrency<
+				T::AccountId,
+			>>::reserve(who, amount),
+			NFTCurrencyId::Token(token_id) => T::Currency::reserve(token_id, who, amount),
+		}
+	}
+
+	pub fn unreserve(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		who: &T::AccountId,
+		amount: Balance,
+	) {
+		match currency_id {
+			NFTCurrencyId::Native => {
+				<pallet_balances::Pallet<T> as PalletReservableCurrency<T::AccountId>>::unreserve(
+					who, amount,
+				);
+			},
+			NFTCurrencyId::Token(token_id) => {
+				T::Currency::unreserve(token_id, who, amount);
+			},
+		}
+	}
+
+	pub fn free_balance(currency_id: NFTCurrencyId<BHC20TokenId>, who: &T::AccountId) -> Balance {
+		match currency_id {
+			NFTCurrencyId::Native =>
+				<pallet_balances::Pallet<T> as PalletCurrency<T::AccountId>>::free_balance(who),
+			NFTCurrencyId::Token(token_id) => T::Currency::free_balance(token_id, who),
+		}
+	}
+
+	/// Pay `amount` from a buyer, never reaping their account when paying in native currency
+	pub fn transfer_currency(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		match currency_id {
+			NFTCurrencyId::Native =>
+				<pallet_balances::Pallet<T> as PalletCurrency<T::AccountId>>::transfer(
+					from,
+					to,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				),
+			NFTCurrencyId::Token(token_id) => T::Currency::transfer(token_id, from, to, amount),
+		}
+	}
+
+	/// Pay `amount` out of the funds reserved by `from`, so that escrowed funds can be paid even
+	/// when they are all `from` holds
+	pub fn pay_reserved(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		let remaining = match currency_id {
+			NFTCurrencyId::Native => <pallet_balances::Pallet<T> as PalletReservableCurrency<
+				T::AccountId,
+			>>::repatriate_reserved(
+				from, to, amount, BalanceStatus::Free
+			)?,
+			NFTCurrencyId::Token(token_id) =>
+				T::Currency::repatriate_reserved(token_id, from, to, amount, BalanceStatus::Free)?,
+		};
+		ensure!(remaining.is_zero(), Error::<T>::InsufficientBalance);
+		Ok(())
+	}
 }
@@ -1,7 +1,7 @@
 use crate::*;
 
 /// Fixed Price Listing Info
-#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct FixedPriceListingInfo<AccountId, CurrencyId, Moment> {
 	pub owner: AccountId,
 	pub buyer: Option<AccountId>,
@@ -15,7 +15,7 @@ pub struct FixedPriceListingInfo<AccountId, CurrencyId, Moment> {
 	pub royalty_amount: Balance,
 	pub fee_amount: Balance,
 	pub fee_recipient: AccountId,
-	pub royalty_recipient: AccountId,
+	pub royalty_recipients: Vec<RoyaltyRecipient<AccountId>>,
 	pub order_time: Option<Moment>,
 }
 
@@ -22,17 +22,21 @@ use scale_info::TypeInfo;
 use sp_core::U256;
 use sp_runtime::{self, traits::Zero, DispatchResult, FixedU128, RuntimeDebug};
 
-use bholdus_support::MultiCurrency;
+use bholdus_support::{MultiCurrency, MultiReservableCurrency};
+use bholdus_support_nft::{RoyaltyRecipient, RoyaltyRecipientOf};
 use common_primitives::{Balance, TokenId as CurrencyId};
 use sp_std::prelude::*;
 
 pub mod access_control;
 pub mod auction;
 pub mod fixed_price;
+mod migrations;
+pub mod offer;
 pub mod traits;
 pub use access_control::*;
 pub use auction::*;
 pub use fixed_price::*;
+pub use offer::*;
 pub use traits::*;
 
 pub type ExchangeRate = FixedU128;
@@ -49,6 +53,9 @@ pub type Denominator = u32;
 pub type Numerator = u32;
 pub type RoyaltyRate = (Numerator, Denominator);
 
+/// Class royalties are expressed in basis points
+pub const ROYALTY_DENOMINATOR: Denominator = 10_000;
+
 /*#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct PaymentInfo<AccountId> {
 	pub actual_price: Balance,
@@ -88,14 +95,17 @@ pub mod support_module {
 	{
 		type GetRoyaltyValue: Get<RoyaltyRate>;
 		type Time: Time;
-		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+		type Currency: MultiReservableCurrency<
+			Self::AccountId,
+			CurrencyId = CurrencyId,
+			Balance = Balance,
+		>;
 		//type NativeCurrency: ReservableCurrency<Self::AccountId>;
 	}
 
 	pub type PalletManagementInfoOf<T> =
 		PalletManagementInfo<<T as frame_system::Config>::AccountId>;
 	pub type MarketplaceFeeInfoOf<T> = MarketplaceFeeInfo<<T as frame_system::Config>::AccountId>;
-	pub type RoyaltyInfoOf<T> = RoyaltyInfo<<T as frame_system::Config>::AccountId>;
 
 	pub type FixedPriceListingInfoOf<T> = FixedPriceListingInfo<
 		<T as frame_system::Config>::AccountId,
@@ -109,6 +119,13 @@ pub mod support_module {
 		NFTCurrencyId<CurrencyId>,
 		MomentOf<T>,
 	>;
+	pub type DutchAuctionListingInfoOf<T> = DutchAuctionListingInfo<
+		<T as frame_system::Config>::AccountId,
+		NFTCurrencyId<CurrencyId>,
+		MomentOf<T>,
+	>;
+	pub type OfferInfoOf<T> = OfferInfo<NFTCurrencyId<CurrencyId>, MomentOf<T>>;
+	pub type AuctionBidOf<T> = AuctionBid<<T as frame_system::Config>::AccountId>;
 	/*pub type FixedPriceOrderInfoOf<T> = FixedPriceOrderInfo<
 		  <T as frame_system::Config>::AccountId,
 		  NFTCurrencyId<CurrencyId>,
@@ -182,6 +199,45 @@ pub mod support_module {
 		TimeAuctionListingInfoOf<T>,
 	>;
 
+	/// Dutch Auction Sale
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_auction)]
+	pub type DutchAuction<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::ClassId>,
+			NMapKey<Blake2_128Concat, T::TokenId>,
+		),
+		DutchAuctionListingInfoOf<T>,
+	>;
+
+	/// Highest bid of each time auction
+	#[pallet::storage]
+	#[pallet::getter(fn auction_bid)]
+	pub type AuctionBids<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::TokenId,
+		AuctionBidOf<T>,
+	>;
+
+	/// Offers on NFTs, by class ID, token ID and offerer, along with the native deposit reserved
+	/// for their storage. \[offer, deposit\]
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::ClassId>,
+			NMapKey<Blake2_128Concat, T::TokenId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // offerer
+		),
+		(OfferInfoOf<T>, Balance),
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn item_listing)]
 	pub type ItemListing<T: Config> = StorageNMap<
@@ -236,19 +292,19 @@ pub mod support_module {
 		Vec<u8>,
 	>;
 
-	/// Royalty
-	#[pallet::storage]
-	#[pallet::getter(fn set_royalty)]
-	// pub type Royalty<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
-	pub type Royalty<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, RoyaltyInfoOf<T>>;
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -283,6 +339,23 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Dutch Auction Create
+	pub fn new_dutch_auction(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		info: &DutchAuctionListingInfoOf<T>,
+	) -> DispatchResult {
+		DutchAuction::<T>::insert((&info.owner, token.0, token.1), info);
+		ItemListing::<T>::insert(
+			(&info.owner, token.0, token.1),
+			ItemListingInfo {
+				owner: info.owner.clone(),
+				mode: MarketMode::Auction(AuctionType::Dutch),
+			},
+		);
+		Self::lock_item(&info.owner, token);
+		Ok(())
+	}
+
 	/// Buy NFT
 	pub fn buy_now(
 		buyer: T::AccountId,
@@ -328,7 +401,8 @@ impl<T: Config> Pallet<T> {
 			ItemListing::<T>::get((&owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
 		match item_info.mode {
 			MarketMode::FixedPrice => Self::approve_fixed_price(&owner, token),
-			MarketMode::Auction(_) => Self::approve_time_auction(&owner, token),
+			MarketMode::Auction(AuctionType::English) => Self::approve_time_auction(&owner, token),
+			MarketMode::Auction(AuctionType::Dutch) => Self::approve_dutch_auction(&owner, token),
 		}
 	}
 
@@ -338,7 +412,9 @@ impl<T: Config> Pallet<T> {
 			ItemListing::<T>::take((owner, token.0, token.1)).ok_or(Error::<T>::NotFound)?;
 		match item_info.mode {
 			MarketMode::FixedPrice => FixedPriceListing::<T>::remove((owner, token.0, token.1)),
-			MarketMode::Auction(_) => TimeAuction::<T>::remove((owner, token.0, token.1)),
+			MarketMode::Auction(AuctionType::English) => Self::remove_time_auction(owner, token),
+			MarketMode::Auction(AuctionType::Dutch) =>
+				DutchAuction::<T>::remove((owner, token.0, token.1)),
 		};
 		Self::unlock_item(&owner, token);
 		Ok(())
@@ -362,7 +438,10 @@ impl<T: Config> Pallet<T> {
 		if let Some(item_info) = ItemListing::<T>::take((owner, token.0, token.1)) {
 			match item_info.mode {
 				MarketMode::FixedPrice => FixedPriceListing::<T>::remove((owner, token.0, token.1)),
-				MarketMode::Auction(_) => TimeAuction::<T>::remove((owner, token.0, token.1)),
+				MarketMode::Auction(AuctionType::English) =>
+					Self::remove_time_auction(owner, token),
+				MarketMode::Auction(AuctionType::Dutch) =>
+					DutchAuction::<T>::remove((owner, token.0, token.1)),
 			}
 		};
 
@@ -381,6 +460,16 @@ impl<T: Config> Pallet<T> {
 	pub fn remove_by_owner(owner: &T::AccountId) {
 		ItemListing::<T>::remove_prefix((owner,), None);
 		FixedPriceListing::<T>::remove_prefix((owner,), None);
-		TimeAuction::<T>::remove_prefix((owner,), None);
+		DutchAuction::<T>::remove_prefix((owner,), None);
+		for (token, listing_info) in TimeAuction::<T>::drain_prefix((owner,)) {
+			Self::cancel_bid(token, listing_info.currency_id);
+		}
+	}
+
+	/// Remove a time auction and release its highest bid
+	pub fn remove_time_auction(owner: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) {
+		if let Some(listing_info) = TimeAuction::<T>::take((owner, token.0, token.1)) {
+			Self::cancel_bid(token, listing_info.currency_id);
+		}
 	}
 }
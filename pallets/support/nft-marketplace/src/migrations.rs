@@ -0,0 +1,189 @@
+use frame_support::{dispatch::Weight, storage::migration};
+
+use crate::*;
+
+pub fn migrate<T: Config>() -> Weight {
+	let storage_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight: Weight = 0;
+	if storage_version < 1 {
+		weight = weight.saturating_add(v1::migrate_to_v1::<T>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+	}
+	weight
+}
+
+pub mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldFixedPriceListingInfo<AccountId, CurrencyId, Moment> {
+		owner: AccountId,
+		buyer: Option<AccountId>,
+		price: Price,
+		currency_id: CurrencyId,
+		royalty: RoyaltyRate,
+		status: NFTState,
+		expired_time: Moment,
+		service_fee: (Numerator, Denominator),
+		actual_price: Balance,
+		royalty_amount: Balance,
+		fee_amount: Balance,
+		fee_recipient: AccountId,
+		royalty_recipient: AccountId,
+		order_time: Option<Moment>,
+	}
+
+	#[derive(Decode)]
+	struct OldTimeAuctionListingInfo<AccountId, CurrencyId, Moment> {
+		owner: AccountId,
+		min_price: Price,
+		currency_id: CurrencyId,
+		_royalty: (Numerator, Denominator),
+		status: NFTState,
+		auction_end: Moment,
+		service_fee: (Numerator, Denominator),
+		fee_recipient: AccountId,
+		_royalty_recipient: AccountId,
+		bid_count: u32,
+	}
+
+	#[derive(Decode)]
+	struct OldDutchAuctionListingInfo<AccountId, CurrencyId, Moment> {
+		owner: AccountId,
+		start_price: Price,
+		floor_price: Price,
+		currency_id: CurrencyId,
+		_royalty: (Numerator, Denominator),
+		status: NFTState,
+		auction_start: Moment,
+		auction_end: Moment,
+		service_fee: (Numerator, Denominator),
+		fee_recipient: AccountId,
+		_royalty_recipient: AccountId,
+	}
+
+	type OldFixedPriceListingInfoOf<T> = OldFixedPriceListingInfo<
+		<T as frame_system::Config>::AccountId,
+		NFTCurrencyId<CurrencyId>,
+		MomentOf<T>,
+	>;
+	type OldTimeAuctionListingInfoOf<T> = OldTimeAuctionListingInfo<
+		<T as frame_system::Config>::AccountId,
+		NFTCurrencyId<CurrencyId>,
+		MomentOf<T>,
+	>;
+	type OldDutchAuctionListingInfoOf<T> = OldDutchAuctionListingInfo<
+		<T as frame_system::Config>::AccountId,
+		NFTCurrencyId<CurrencyId>,
+		MomentOf<T>,
+	>;
+
+	/// Listings made before class royalties pay the royalties of their class, if any, and the
+	/// per-token royalties are dropped. Settled orders keep the royalty they paid.
+	pub fn migrate_to_v1<T: Config>() -> Weight {
+		let mut translated: Weight = 0;
+
+		FixedPriceListing::<T>::translate::<OldFixedPriceListingInfoOf<T>, _>(
+			|(_, class_id, token_id), old| {
+				translated = translated.saturating_add(1);
+				let (royalty_recipients, royalty) =
+					Pallet::<T>::get_royalty_value((class_id, token_id));
+				let royalty_amount = Pallet::<T>::royalty_amount(old.price, &royalty_recipients);
+				Some(FixedPriceListingInfo {
+					owner: old.owner,
+					buyer: old.buyer,
+					price: old.price,
+					currency_id: old.currency_id,
+					royalty,
+					status: old.status,
+					expired_time: old.expired_time,
+					service_fee: old.service_fee,
+					actual_price: old
+						.price
+						.saturating_sub(old.fee_amount)
+						.saturating_sub(royalty_amount),
+					royalty_amount,
+					fee_amount: old.fee_amount,
+					fee_recipient: old.fee_recipient,
+					royalty_recipients,
+					order_time: old.order_time,
+				})
+			},
+		);
+
+		FixedPriceOrder::<T>::translate::<OldFixedPriceListingInfoOf<T>, _>(|_, old| {
+			translated = translated.saturating_add(1);
+			let basis_points =
+				Pallet::<T>::calc_amount(ROYALTY_DENOMINATOR.into(), old.royalty) as u32;
+			let royalty_recipients = if basis_points == 0 {
+				Vec::new()
+			} else {
+				vec![RoyaltyRecipient { recipient: old.royalty_recipient, basis_points }]
+			};
+			Some(FixedPriceListingInfo {
+				owner: old.owner,
+				buyer: old.buyer,
+				price: old.price,
+				currency_id: old.currency_id,
+				royalty: old.royalty,
+				status: old.status,
+				expired_time: old.expired_time,
+				service_fee: old.service_fee,
+				actual_price: old.actual_price,
+				royalty_amount: old.royalty_amount,
+				fee_amount: old.fee_amount,
+				fee_recipient: old.fee_recipient,
+				royalty_recipients,
+				order_time: old.order_time,
+			})
+		});
+
+		TimeAuction::<T>::translate::<OldTimeAuctionListingInfoOf<T>, _>(
+			|(_, class_id, token_id), old| {
+				translated = translated.saturating_add(1);
+				let (royalty_recipients, royalty) =
+					Pallet::<T>::get_royalty_value((class_id, token_id));
+				Some(TimeAuctionListingInfo {
+					owner: old.owner,
+					min_price: old.min_price,
+					currency_id: old.currency_id,
+					royalty,
+					status: old.status,
+					auction_end: old.auction_end,
+					service_fee: old.service_fee,
+					fee_recipient: old.fee_recipient,
+					royalty_recipients,
+					bid_count: old.bid_count,
+				})
+			},
+		);
+
+		DutchAuction::<T>::translate::<OldDutchAuctionListingInfoOf<T>, _>(
+			|(_, class_id, token_id), old| {
+				translated = translated.saturating_add(1);
+				let (royalty_recipients, royalty) =
+					Pallet::<T>::get_royalty_value((class_id, token_id));
+				Some(DutchAuctionListingInfo {
+					owner: old.owner,
+					start_price: old.start_price,
+					floor_price: old.floor_price,
+					currency_id: old.currency_id,
+					royalty,
+					status: old.status,
+					auction_start: old.auction_start,
+					auction_end: old.auction_end,
+					service_fee: old.service_fee,
+					fee_recipient: old.fee_recipient,
+					royalty_recipients,
+				})
+			},
+		);
+
+		migration::remove_storage_prefix(Pallet::<T>::name().as_bytes(), b"Royalty", b"");
+
+		// Each listing also reads the royalties of its class
+		T::DbWeight::get()
+			.reads_writes(translated.saturating_mul(2), translated)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
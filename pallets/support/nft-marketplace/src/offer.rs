@@ -0,0 +1,76 @@
+use crate::*;
+
+/// Offer on a NFT, listed or not. The amount is reserved from the offerer until the offer is
+/// accepted or withdrawn.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct OfferInfo<CurrencyId, Moment> {
+	pub amount: Balance,
+	pub currency_id: CurrencyId,
+	pub expired_time: Moment,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Make an offer, replacing the previous offer of `offerer`. `deposit` is reserved in native
+	/// currency for the storage of the offer.
+	pub fn new_offer(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		offerer: &T::AccountId,
+		info: &OfferInfoOf<T>,
+		deposit: Balance,
+	) -> DispatchResult {
+		if let Some((previous_offer, previous_deposit)) =
+			Offers::<T>::take((token.0, token.1, offerer))
+		{
+			Self::unreserve(previous_offer.currency_id, offerer, previous_offer.amount);
+			Self::unreserve(NFTCurrencyId::Native, offerer, previous_deposit);
+		}
+		Self::reserve(NFTCurrencyId::Native, offerer, deposit)?;
+		Self::reserve(info.currency_id.clone(), offerer, info.amount)?;
+		Offers::<T>::insert((token.0, token.1, offerer), (info.clone(), deposit));
+		Ok(())
+	}
+
+	/// Remove an offer and release its amount and deposit
+	pub fn remove_offer(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		offerer: &T::AccountId,
+	) -> Result<OfferInfoOf<T>, DispatchError> {
+		let (offer, deposit) =
+			Offers::<T>::take((token.0, token.1, offerer)).ok_or(Error::<T>::NotFound)?;
+		Self::unreserve(offer.currency_id.clone(), offerer, offer.amount);
+		Self::unreserve(NFTCurrencyId::Native, offerer, deposit);
+		Ok(offer)
+	}
+
+	/// Accept an offer.
+	/// The reserved offer amount is paid to the fee recipient, the royalty recipients and the
+	/// owner, and the NFT is transferred to the offerer.
+	pub fn accept_offer(
+		owner: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		offerer: &T::AccountId,
+	) -> Result<OfferInfoOf<T>, DispatchError> {
+		let fee_info = MarketplaceFee::<T>::get().ok_or(Error::<T>::NotFound)?;
+		let (royalty_recipients, _) = Self::get_royalty_value(token);
+		let (offer, deposit) =
+			Offers::<T>::take((token.0, token.1, offerer)).ok_or(Error::<T>::NotFound)?;
+		Self::unreserve(NFTCurrencyId::Native, offerer, deposit);
+
+		let fee_amount = Self::calc_amount(offer.amount, fee_info.service_fee);
+		let royalty_amount = Self::royalty_amount(offer.amount, &royalty_recipients);
+		let actual_price = offer.amount.saturating_sub(fee_amount).saturating_sub(royalty_amount);
+
+		let currency_id = offer.currency_id.clone();
+		Self::pay_reserved(currency_id.clone(), offerer, &fee_info.beneficiary, fee_amount)?;
+		Self::pay_reserved_royalties(
+			currency_id.clone(),
+			offerer,
+			offer.amount,
+			&royalty_recipients,
+		)?;
+		Self::pay_reserved(currency_id, offerer, owner, actual_price)?;
+		Self::transfer(owner, offerer, token)?;
+
+		Ok(offer)
+	}
+}
@@ -1,12 +1,5 @@
 use crate::*;
 
-/// Royalty Information
-#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-pub struct RoyaltyInfo<AccountId> {
-	pub value: (Numerator, Denominator),
-	pub creator: AccountId,
-}
-
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub struct PalletManagementInfo<AccountId> {
 	pub controller: AccountId,
@@ -54,28 +47,51 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn calculate_royalty_amount(data: &FixedPriceListingInfoOf<T>) -> Balance {
-		let price = data.price;
-		let (royalty_numerator, royalty_denominator) = data.royalty;
-		royalty_numerator
-			.checked_div(royalty_denominator)
-			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
-			.unwrap_or_else(Zero::zero)
-			.saturating_mul(price)
+		Self::royalty_amount(data.price, &data.royalty_recipients)
 	}
 
+	/// Royalty recipients of the class of `token` and their total royalty
 	pub fn get_royalty_value(
 		token: (ClassIdOf<T>, TokenIdOf<T>),
-		account: &T::AccountId,
-		value: Option<(Numerator, Denominator)>,
-	) -> (T::AccountId, (Numerator, Denominator)) {
-		if let Some(royalty) = Royalty::<T>::get(token.0, token.1) {
-			(royalty.creator, royalty.value)
-		} else {
-			let value = value.unwrap_or((0u32, 10_000u32));
-			let data = RoyaltyInfo { value, creator: account.clone() };
-			Royalty::<T>::insert(token.0, token.1, data);
-			(account.clone(), value.clone())
+	) -> (Vec<RoyaltyRecipientOf<T>>, RoyaltyRate) {
+		let recipients = bholdus_support_nft::Pallet::<T>::class_royalties(token.0);
+		let total = recipients.iter().fold(0u32, |acc, r| acc.saturating_add(r.basis_points));
+		(recipients, (total, ROYALTY_DENOMINATOR))
+	}
+
+	/// Total royalty paid on a sale at `price`
+	pub fn royalty_amount(price: Balance, recipients: &[RoyaltyRecipientOf<T>]) -> Balance {
+		recipients.iter().fold(Zero::zero(), |acc: Balance, r| {
+			acc.saturating_add(Self::calc_amount(price, (r.basis_points, ROYALTY_DENOMINATOR)))
+		})
+	}
+
+	/// Pay the royalty of a sale at `price` from `from` to each recipient
+	pub fn pay_royalties(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		from: &T::AccountId,
+		price: Balance,
+		recipients: &[RoyaltyRecipientOf<T>],
+	) -> DispatchResult {
+		for r in recipients {
+			let amount = Self::calc_amount(price, (r.basis_points, ROYALTY_DENOMINATOR));
+			Self::transfer_currency(currency_id.clone(), from, &r.recipient, amount)?;
+		}
+		Ok(())
+	}
+
+	/// Pay the royalty of a sale at `price` to each recipient out of the funds reserved by `from`
+	pub fn pay_reserved_royalties(
+		currency_id: NFTCurrencyId<BHC20TokenId>,
+		from: &T::AccountId,
+		price: Balance,
+		recipients: &[RoyaltyRecipientOf<T>],
+	) -> DispatchResult {
+		for r in recipients {
+			let amount = Self::calc_amount(price, (r.basis_points, ROYALTY_DENOMINATOR));
+			Self::pay_reserved(currency_id.clone(), from, &r.recipient, amount)?;
 		}
+		Ok(())
 	}
 }
 
@@ -87,6 +103,11 @@ impl<T: Config> Pallet<T> {
 	pub fn is_existed(token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
 		bholdus_support_nft::Pallet::<T>::is_existed(token)
 	}
+
+	pub fn is_metadata_frozen(token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		bholdus_support_nft::Pallet::<T>::is_metadata_frozen(token)
+	}
+
 	pub fn is_lock(account: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
 		bholdus_support_nft::Pallet::<T>::is_lock(account, token)
 	}
@@ -104,7 +125,10 @@ impl<T: Config> Pallet<T> {
 			MarketMode::FixedPrice =>
 				FixedPriceListing::<T>::contains_key((owner, token.0, token.1)),
 
-			MarketMode::Auction(_) => TimeAuction::<T>::contains_key((owner, token.0, token.1)),
+			MarketMode::Auction(AuctionType::English) =>
+				TimeAuction::<T>::contains_key((owner, token.0, token.1)),
+			MarketMode::Auction(AuctionType::Dutch) =>
+				DutchAuction::<T>::contains_key((owner, token.0, token.1)),
 		}
 	}
 
@@ -121,13 +145,20 @@ impl<T: Config> Pallet<T> {
 					false
 				}
 			},
-			MarketMode::Auction(_) => {
+			MarketMode::Auction(AuctionType::English) => {
 				if let Some(listing_info) = TimeAuction::<T>::get((owner, token.0, token.1)) {
 					listing_info.status == NFTState::Listing
 				} else {
 					false
 				}
 			},
+			MarketMode::Auction(AuctionType::Dutch) => {
+				if let Some(listing_info) = DutchAuction::<T>::get((owner, token.0, token.1)) {
+					listing_info.status == NFTState::Listing
+				} else {
+					false
+				}
+			},
 		}
 	}
 
@@ -5,6 +5,8 @@
 //! - `create_clas` - Create NFT(non fungible token) class
 //! - `transfer` - Transfer NFT to another account.
 //! - `mint` - Mint NFT
+//! - `create_group` - Create a group of NFTs
+//! - `move_to_group` - Move NFT to another group
 //! - `burn` - Burn NFT
 //! - `destroy_class` - Destroy NFT
 
@@ -25,6 +27,7 @@ use sp_runtime::{
 
 use sp_std::{convert::TryInto, vec::Vec};
 
+mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -58,6 +61,24 @@ pub struct TokenInfo<AccountId, Data, Metadata> {
 	pub data: Data,
 }
 
+/// Group info
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct GroupInfo<AccountId, Metadata> {
+	/// Group owner
+	pub owner: AccountId,
+	/// Group metadata
+	pub metadata: Metadata,
+}
+
+/// Royalty recipient of a class
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct RoyaltyRecipient<AccountId> {
+	/// Account receiving the royalty
+	pub recipient: AccountId,
+	/// Share of the sale price, in basis points (1/10_000)
+	pub basis_points: u32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -93,6 +114,8 @@ pub mod pallet {
 		<T as Config>::TokenData,
 		TokenMetadataOf<T>,
 	>;
+	pub type GroupInfoOf<T> = GroupInfo<<T as frame_system::Config>::AccountId, ClassMetadataOf<T>>;
+	pub type RoyaltyRecipientOf<T> = RoyaltyRecipient<<T as frame_system::Config>::AccountId>;
 
 	pub type GenesisTokenData<T> = (
 		<T as frame_system::Config>::AccountId, // Token owner
@@ -129,6 +152,19 @@ pub mod pallet {
 		AlreadyUnlocked,
 		/// Failed because the Maximum amount of metadata was exceeded
 		MaxMetadataExceeded,
+		/// The class reached its maximum supply
+		MaxSupplyReached,
+		/// The maximum supply is lower than the total issuance
+		InvalidMaxSupply,
+		/// Token metadata is frozen and can not be changed
+		MetadataFrozen,
+		/// The operator is neither the owner of the token nor approved by the owner
+		NotApproved,
+		/// Group not found
+		GroupNotFound,
+		/// Can not destroy group
+		/// The group still has tokens
+		GroupNotEmpty,
 	}
 
 	/// Next available class ID.
@@ -195,6 +231,19 @@ pub mod pallet {
 		//ValueQuery,
 	>;
 
+	/// Store group info.
+	///
+	/// Returns `None` if group info not set or removed.
+	#[pallet::storage]
+	#[pallet::getter(fn groups)]
+	pub type Groups<T: Config> = StorageMap<_, Twox64Concat, T::GroupId, GroupInfoOf<T>>;
+
+	/// Group of each token, reverse index of `TokensByGroup`
+	#[pallet::storage]
+	#[pallet::getter(fn token_group)]
+	pub type TokenGroup<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, T::GroupId>;
+
 	/// Lock NFT on account
 	#[pallet::storage]
 	#[pallet::getter(fn set_lock)]
@@ -209,6 +258,42 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Royalty recipients of each class, paid on every marketplace sale of its tokens.
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalties)]
+	pub type ClassRoyalties<T: Config> =
+		StorageMap<_, Twox64Concat, T::ClassId, Vec<RoyaltyRecipientOf<T>>, ValueQuery>;
+
+	/// Maximum total issuance of each class, unlimited if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn class_max_supply)]
+	pub type ClassMaxSupply<T: Config> = StorageMap<_, Twox64Concat, T::ClassId, T::TokenId>;
+
+	/// Account approved to transfer a token on behalf of its owner, cleared on transfer
+	#[pallet::storage]
+	#[pallet::getter(fn token_approvals)]
+	pub type TokenApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, T::AccountId>;
+
+	/// Operators allowed to transfer all tokens of a class on behalf of their owner
+	#[pallet::storage]
+	#[pallet::getter(fn class_operators)]
+	pub type ClassOperators<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>, // owner
+			NMapKey<Twox64Concat, T::ClassId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // operator
+		),
+		(),
+	>;
+
+	/// Tokens whose metadata and data can no longer be changed
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_token_metadata)]
+	pub type FrozenTokenMetadata<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, ()>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub tokens: Vec<GenesisTokens<T>>,
@@ -240,11 +325,18 @@ pub mod pallet {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
 }
@@ -277,16 +369,159 @@ impl<T: Config> Pallet<T> {
 		Ok(class_id)
 	}
 
+	/// Set the royalty recipients of a class, replacing the previous ones
+	pub fn set_class_royalty(
+		owner: &T::AccountId,
+		class_id: T::ClassId,
+		royalties: Vec<RoyaltyRecipientOf<T>>,
+	) -> DispatchResult {
+		let info = Classes::<T>::get(class_id).ok_or(Error::<T>::ClassNotFound)?;
+		ensure!(info.owner == *owner, Error::<T>::NoPermission);
+		if royalties.is_empty() {
+			ClassRoyalties::<T>::remove(class_id);
+		} else {
+			ClassRoyalties::<T>::insert(class_id, royalties);
+		}
+		Ok(())
+	}
+
+	/// Set the maximum supply of a class, `None` for an unlimited supply
+	pub fn set_max_supply(
+		owner: &T::AccountId,
+		class_id: T::ClassId,
+		max_supply: Option<T::TokenId>,
+	) -> DispatchResult {
+		let info = Classes::<T>::get(class_id).ok_or(Error::<T>::ClassNotFound)?;
+		ensure!(info.owner == *owner, Error::<T>::NoPermission);
+		ensure!(
+			max_supply.map_or(true, |max_supply| info.total_issuance <= max_supply),
+			Error::<T>::InvalidMaxSupply
+		);
+		ClassMaxSupply::<T>::set(class_id, max_supply);
+		Ok(())
+	}
+
+	/// Set the metadata of a token, `owner` must be the class owner
+	pub fn set_token_metadata(
+		owner: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+		metadata: Vec<u8>,
+	) -> DispatchResult {
+		let bounded_metadata: BoundedVec<u8, T::MaxTokenMetadata> =
+			metadata.try_into().map_err(|_| Error::<T>::MaxMetadataExceeded)?;
+		Self::mutate_token(owner, token, |token_info| token_info.metadata = bounded_metadata)
+	}
+
+	/// Set the data of a token, `owner` must be the class owner
+	pub fn set_token_data(
+		owner: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+		data: T::TokenData,
+	) -> DispatchResult {
+		Self::mutate_token(owner, token, |token_info| token_info.data = data)
+	}
+
+	/// Freeze the metadata and data of a token, `owner` must be the class owner
+	pub fn freeze_token_metadata(
+		owner: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+	) -> DispatchResult {
+		Self::mutate_token(owner, token, |_| ())?;
+		FrozenTokenMetadata::<T>::insert(token.0, token.1, ());
+		Ok(())
+	}
+
+	fn mutate_token(
+		owner: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+		f: impl FnOnce(&mut TokenInfoOf<T>),
+	) -> DispatchResult {
+		let class_info = Classes::<T>::get(token.0).ok_or(Error::<T>::ClassNotFound)?;
+		ensure!(class_info.owner == *owner, Error::<T>::NoPermission);
+		ensure!(!Self::is_metadata_frozen(token), Error::<T>::MetadataFrozen);
+		Tokens::<T>::try_mutate(token.0, token.1, |token_info| -> DispatchResult {
+			let info = token_info.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+			f(info);
+			Ok(())
+		})
+	}
+
 	/// Create group
-	pub fn create_group() -> Result<T::GroupId, DispatchError> {
+	pub fn create_group(
+		owner: &T::AccountId,
+		metadata: Vec<u8>,
+	) -> Result<T::GroupId, DispatchError> {
+		let bounded_metadata: BoundedVec<u8, T::MaxClassMetadata> =
+			metadata.try_into().map_err(|_| Error::<T>::MaxMetadataExceeded)?;
 		let group_id = NextGroupId::<T>::try_mutate(|id| -> Result<T::GroupId, DispatchError> {
 			let current_id = *id;
 			*id = id.checked_add(&One::one()).ok_or(Error::<T>::NoAvailableGroupId)?;
 			Ok(current_id)
 		})?;
+		Groups::<T>::insert(
+			group_id,
+			GroupInfo { owner: owner.clone(), metadata: bounded_metadata },
+		);
 		Ok(group_id)
 	}
 
+	/// Set the metadata of a group
+	pub fn set_group_metadata(
+		owner: &T::AccountId,
+		group_id: T::GroupId,
+		metadata: Vec<u8>,
+	) -> DispatchResult {
+		let bounded_metadata: BoundedVec<u8, T::MaxClassMetadata> =
+			metadata.try_into().map_err(|_| Error::<T>::MaxMetadataExceeded)?;
+		Groups::<T>::try_mutate(group_id, |group_info| -> DispatchResult {
+			let info = group_info.as_mut().ok_or(Error::<T>::GroupNotFound)?;
+			ensure!(info.owner == *owner, Error::<T>::NoPermission);
+			info.metadata = bounded_metadata;
+			Ok(())
+		})
+	}
+
+	/// Destroy group, it must have no tokens
+	pub fn destroy_group(owner: &T::AccountId, group_id: T::GroupId) -> DispatchResult {
+		Groups::<T>::try_mutate_exists(group_id, |group_info| -> DispatchResult {
+			let info = group_info.take().ok_or(Error::<T>::GroupNotFound)?;
+			ensure!(info.owner == *owner, Error::<T>::NoPermission);
+			ensure!(
+				TokensByGroup::<T>::iter_prefix((group_id,)).next().is_none(),
+				Error::<T>::GroupNotEmpty
+			);
+			Ok(())
+		})
+	}
+
+	/// Move NFT of `who` to a group of `who`, `None` removes it from its group. The owner of a
+	/// group can also remove from it the NFTs held by others.
+	pub fn move_to_group(
+		who: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+		group_id: Option<T::GroupId>,
+	) -> DispatchResult {
+		let info = Tokens::<T>::get(token.0, token.1).ok_or(Error::<T>::TokenNotFound)?;
+		ensure!(
+			info.owner == *who || (group_id.is_none() && Self::is_group_owner(who, token)),
+			Error::<T>::NoPermission
+		);
+		ensure!(!Self::is_lock(&info.owner, token), Error::<T>::IsLocked);
+		if let Some(group_id) = group_id {
+			let group_info = Groups::<T>::get(group_id).ok_or(Error::<T>::GroupNotFound)?;
+			ensure!(group_info.owner == *who, Error::<T>::NoPermission);
+		}
+
+		if let Some(previous_group_id) = TokenGroup::<T>::take(token.0, token.1) {
+			TokensByGroup::<T>::remove((previous_group_id, token.0, token.1));
+		}
+		if let Some(group_id) = group_id {
+			TokensByGroup::<T>::insert((group_id, token.0, token.1), ());
+			TokenGroup::<T>::insert(token.0, token.1, group_id);
+		}
+		Ok(())
+	}
+
 	/// Transfer NFT
 	pub fn transfer(
 		from: &T::AccountId,
@@ -306,11 +541,52 @@ impl<T: Config> Pallet<T> {
 			TokensByOwner::<T>::remove((from, token.0, token.1));
 
 			TokensByOwner::<T>::insert((to, token.0, token.1), ());
+			TokenApprovals::<T>::remove(token.0, token.1);
 
 			Ok(())
 		})
 	}
 
+	/// Transfer NFT from `from` to `to` on behalf of `from`, `operator` must be approved
+	pub fn transfer_from(
+		operator: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+	) -> DispatchResult {
+		ensure!(Self::is_approved(operator, from, token), Error::<T>::NotApproved);
+		Self::transfer(from, to, token)
+	}
+
+	/// Approve `approved` to transfer a token of `owner`, `None` removes the approval
+	pub fn approve(
+		owner: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+		approved: Option<T::AccountId>,
+	) -> DispatchResult {
+		let info = Tokens::<T>::get(token.0, token.1).ok_or(Error::<T>::TokenNotFound)?;
+		ensure!(info.owner == *owner, Error::<T>::NoPermission);
+		ensure!(!Self::is_lock(owner, token), Error::<T>::IsLocked);
+		TokenApprovals::<T>::set(token.0, token.1, approved);
+		Ok(())
+	}
+
+	/// Allow or disallow `operator` to transfer all tokens of a class owned by `owner`
+	pub fn set_operator(
+		owner: &T::AccountId,
+		class_id: T::ClassId,
+		operator: &T::AccountId,
+		approved: bool,
+	) -> DispatchResult {
+		ensure!(Classes::<T>::contains_key(class_id), Error::<T>::ClassNotFound);
+		if approved {
+			ClassOperators::<T>::insert((owner, class_id, operator), ());
+		} else {
+			ClassOperators::<T>::remove((owner, class_id, operator));
+		}
+		Ok(())
+	}
+
 	/// Mint NFT to `owner`
 	pub fn mint(
 		owner: &T::AccountId,
@@ -330,6 +606,11 @@ impl<T: Config> Pallet<T> {
 					.total_issuance
 					.checked_add(&One::one())
 					.ok_or(ArithmeticError::Overflow)?;
+				ensure!(
+					ClassMaxSupply::<T>::get(class_id)
+						.map_or(true, |max_supply| info.total_issuance <= max_supply),
+					Error::<T>::MaxSupplyReached
+				);
 				Ok(())
 			})?;
 			let token_info = TokenInfo {
@@ -352,6 +633,7 @@ impl<T: Config> Pallet<T> {
 		group_id: T::GroupId,
 		info: &TokenInfoOf<T>,
 	) -> Result<T::TokenId, DispatchError> {
+		ensure!(Groups::<T>::contains_key(group_id), Error::<T>::GroupNotFound);
 		NextTokenIdByClass::<T>::try_mutate(
 			class_id,
 			|class_token_id| -> Result<T::TokenId, DispatchError> {
@@ -367,14 +649,20 @@ impl<T: Config> Pallet<T> {
 							.total_issuance
 							.checked_add(&One::one())
 							.ok_or(ArithmeticError::Overflow)?;
+						ensure!(
+							ClassMaxSupply::<T>::get(class_id)
+								.map_or(true, |max_supply| info.total_issuance <= max_supply),
+							Error::<T>::MaxSupplyReached
+						);
 						Ok(())
 					})?;
 
 					Tokens::<T>::insert(class_id, token_id, info);
 					TokensByOwner::<T>::insert((owner, class_id, token_id), ());
 					TokensByGroup::<T>::insert((group_id, class_id, token_id), ());
+					TokenGroup::<T>::insert(class_id, token_id, group_id);
 					Ok(token_id)
-				});
+				})?;
 				Ok(*class_token_id)
 			},
 		)
@@ -398,6 +686,11 @@ impl<T: Config> Pallet<T> {
 			})?;
 
 			TokensByOwner::<T>::remove((owner, token.0, token.1));
+			if let Some(group_id) = TokenGroup::<T>::take(token.0, token.1) {
+				TokensByGroup::<T>::remove((group_id, token.0, token.1));
+			}
+			TokenApprovals::<T>::remove(token.0, token.1);
+			FrozenTokenMetadata::<T>::remove(token.0, token.1);
 			Ok(())
 		})
 	}
@@ -410,6 +703,8 @@ impl<T: Config> Pallet<T> {
 			ensure!(info.total_issuance == Zero::zero(), Error::<T>::CannotDestroyClass);
 
 			NextTokenIdByClass::<T>::remove(class_id);
+			ClassRoyalties::<T>::remove(class_id);
+			ClassMaxSupply::<T>::remove(class_id);
 			Ok(())
 		})
 	}
@@ -439,4 +734,31 @@ impl<T: Config> Pallet<T> {
 	pub fn is_existed(token: (T::ClassId, T::TokenId)) -> bool {
 		Tokens::<T>::contains_key(token.0, token.1)
 	}
+
+	/// Whether `account` owns the group of a token
+	pub fn is_group_owner(account: &T::AccountId, token: (T::ClassId, T::TokenId)) -> bool {
+		TokenGroup::<T>::get(token.0, token.1)
+			.and_then(Groups::<T>::get)
+			.map_or(false, |info| info.owner == *account)
+	}
+
+	/// Tokens of a group
+	pub fn tokens_of_group(group_id: T::GroupId) -> Vec<(T::ClassId, T::TokenId)> {
+		TokensByGroup::<T>::iter_key_prefix((group_id,)).collect()
+	}
+
+	pub fn is_metadata_frozen(token: (T::ClassId, T::TokenId)) -> bool {
+		FrozenTokenMetadata::<T>::contains_key(token.0, token.1)
+	}
+
+	/// Whether `operator` can transfer a token on behalf of `owner`
+	pub fn is_approved(
+		operator: &T::AccountId,
+		owner: &T::AccountId,
+		token: (T::ClassId, T::TokenId),
+	) -> bool {
+		operator == owner ||
+			TokenApprovals::<T>::get(token.0, token.1).as_ref() == Some(operator) ||
+			ClassOperators::<T>::contains_key((owner, token.0, operator))
+	}
 }
@@ -0,0 +1,44 @@
+use frame_support::dispatch::Weight;
+
+use crate::*;
+
+pub fn migrate<T: Config>() -> Weight {
+	let storage_version = Pallet::<T>::on_chain_storage_version();
+	let mut weight: Weight = 0;
+	if storage_version < 1 {
+		weight = weight.saturating_add(v1::migrate_to_v1::<T>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+	}
+	weight
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Groups made before group info was stored only have their `TokensByGroup` entries. Each
+	/// of them is given to the creator of its tokens, who minted them into it, and each token
+	/// gets its `TokenGroup` entry. Groups left without tokens have nothing to recover.
+	pub fn migrate_to_v1<T: Config>() -> Weight {
+		let mut reads: Weight = 0;
+		let mut writes: Weight = 0;
+
+		for (group_id, class_id, token_id) in TokensByGroup::<T>::iter_keys() {
+			reads = reads.saturating_add(3);
+			TokenGroup::<T>::insert(class_id, token_id, group_id);
+			writes = writes.saturating_add(1);
+
+			if Groups::<T>::contains_key(group_id) {
+				continue;
+			}
+			if let Some(token_info) = Tokens::<T>::get(class_id, token_id) {
+				Groups::<T>::insert(
+					group_id,
+					GroupInfo { owner: token_info.creator, metadata: Default::default() },
+				);
+				writes = writes.saturating_add(1);
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
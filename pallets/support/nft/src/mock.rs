@@ -77,6 +77,7 @@ construct_runtime!(
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const CLASS_ID: <Runtime as Config>::ClassId = 0;
 pub const GROUP_ID: <Runtime as Config>::GroupId = 0;
 pub const CLASS_ID_NOT_EXIST: <Runtime as Config>::ClassId = 100;
@@ -24,7 +24,12 @@ fn create_class_should_fail() {
 #[test]
 fn create_group_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(BholdusNFT::create_group());
+		assert_ok!(BholdusNFT::create_group(&ALICE, vec![1]));
+		assert_eq!(BholdusNFT::groups(GROUP_ID).unwrap().owner, ALICE);
+		assert_noop!(
+			BholdusNFT::create_group(&ALICE, vec![1, 2]),
+			Error::<Runtime>::MaxMetadataExceeded
+		);
 	})
 }
 
@@ -66,8 +71,12 @@ fn mint_to_group_should_work() {
 		assert_eq!(BholdusNFT::next_class_id(), CLASS_ID);
 		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
 
+		assert_noop!(
+			BholdusNFT::mint_to_group(&ALICE, CLASS_ID, GROUP_ID, &token_info),
+			Error::<Runtime>::GroupNotFound
+		);
 		assert_eq!(BholdusNFT::next_group_id(), GROUP_ID);
-		assert_ok!(BholdusNFT::create_group());
+		assert_ok!(BholdusNFT::create_group(&ALICE, vec![]));
 
 		assert_ok!(BholdusNFT::mint_to_group(&ALICE, CLASS_ID, GROUP_ID, &token_info));
 
@@ -76,6 +85,114 @@ fn mint_to_group_should_work() {
 
 		assert_ok!(BholdusNFT::mint_to_group(&ALICE, CLASS_ID, GROUP_ID, &token_info));
 		assert_eq!(TokensByGroup::<Runtime>::contains_key((GROUP_ID, CLASS_ID, TOKEN_ID)), true);
+		assert_eq!(BholdusNFT::token_group(CLASS_ID, TOKEN_ID), Some(GROUP_ID));
+	});
+}
+
+#[test]
+fn mint_to_group_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bounded_metadata: BoundedVec<u8, MaxTokenMetadata> = vec![1].try_into().unwrap();
+		let token_info =
+			TokenInfo { metadata: bounded_metadata, owner: BOB, creator: BOB, data: () };
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::create_group(&ALICE, vec![]));
+		assert_ok!(BholdusNFT::set_max_supply(&ALICE, CLASS_ID, Some(1)));
+		assert_ok!(BholdusNFT::mint_to_group(&ALICE, CLASS_ID, GROUP_ID, &token_info));
+
+		assert_noop!(
+			BholdusNFT::mint_to_group(&ALICE, CLASS_ID, GROUP_ID, &token_info),
+			Error::<Runtime>::MaxSupplyReached
+		);
+		assert_eq!(BholdusNFT::next_token_id(), 1);
+	});
+}
+
+#[test]
+fn move_to_group_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bounded_metadata: BoundedVec<u8, MaxTokenMetadata> = vec![1].try_into().unwrap();
+		let token_info =
+			TokenInfo { metadata: bounded_metadata, owner: BOB, creator: BOB, data: () };
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::create_group(&BOB, vec![]));
+		assert_ok!(BholdusNFT::create_group(&BOB, vec![]));
+		assert_ok!(BholdusNFT::create_group(&ALICE, vec![]));
+		assert_ok!(BholdusNFT::mint_to_group(&BOB, CLASS_ID, GROUP_ID, &token_info));
+
+		assert_noop!(
+			BholdusNFT::move_to_group(&ALICE, (CLASS_ID, TOKEN_ID), Some(2)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			BholdusNFT::move_to_group(&BOB, (CLASS_ID, TOKEN_ID), Some(2)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			BholdusNFT::move_to_group(&BOB, (CLASS_ID, TOKEN_ID), Some(100)),
+			Error::<Runtime>::GroupNotFound
+		);
+		assert_noop!(BholdusNFT::destroy_group(&BOB, GROUP_ID), Error::<Runtime>::GroupNotEmpty);
+
+		assert_ok!(BholdusNFT::move_to_group(&BOB, (CLASS_ID, TOKEN_ID), Some(1)));
+		assert_eq!(BholdusNFT::tokens_of_group(GROUP_ID), vec![]);
+		assert_eq!(BholdusNFT::tokens_of_group(1), vec![(CLASS_ID, TOKEN_ID)]);
+		assert_ok!(BholdusNFT::destroy_group(&BOB, GROUP_ID));
+		assert_eq!(Groups::<Runtime>::contains_key(GROUP_ID), false);
+
+		assert_ok!(BholdusNFT::burn(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(BholdusNFT::tokens_of_group(1), vec![]);
+		assert_eq!(BholdusNFT::token_group(CLASS_ID, TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn transfer_should_keep_group() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bounded_metadata: BoundedVec<u8, MaxTokenMetadata> = vec![1].try_into().unwrap();
+		let token_info =
+			TokenInfo { metadata: bounded_metadata, owner: BOB, creator: BOB, data: () };
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::create_group(&BOB, vec![]));
+		assert_ok!(BholdusNFT::mint_to_group(&BOB, CLASS_ID, GROUP_ID, &token_info));
+
+		assert_ok!(BholdusNFT::transfer(&BOB, &ALICE, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(BholdusNFT::tokens_of_group(GROUP_ID), vec![(CLASS_ID, TOKEN_ID)]);
+		assert_eq!(BholdusNFT::token_group(CLASS_ID, TOKEN_ID), Some(GROUP_ID));
+		assert_noop!(BholdusNFT::destroy_group(&BOB, GROUP_ID), Error::<Runtime>::GroupNotEmpty);
+
+		// Tokens held by others cannot be moved in, nor moved to a group of others
+		assert_noop!(
+			BholdusNFT::move_to_group(&BOB, (CLASS_ID, TOKEN_ID), Some(GROUP_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			BholdusNFT::move_to_group(&ALICE, (CLASS_ID, TOKEN_ID), Some(GROUP_ID)),
+			Error::<Runtime>::NoPermission
+		);
+
+		// The group owner can remove the tokens held by others
+		assert_ok!(BholdusNFT::move_to_group(&BOB, (CLASS_ID, TOKEN_ID), None));
+		assert_eq!(BholdusNFT::token_group(CLASS_ID, TOKEN_ID), None);
+		assert_ok!(BholdusNFT::destroy_group(&BOB, GROUP_ID));
+	});
+}
+
+#[test]
+fn migrate_to_v1_should_back_fill_groups() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::mint(&BOB, CLASS_ID, vec![1], ()));
+		// Groups made before group info was stored
+		NextGroupId::<Runtime>::put(1);
+		TokensByGroup::<Runtime>::insert((GROUP_ID, CLASS_ID, TOKEN_ID), ());
+
+		migrations::v1::migrate_to_v1::<Runtime>();
+		assert_eq!(BholdusNFT::token_group(CLASS_ID, TOKEN_ID), Some(GROUP_ID));
+		assert_eq!(BholdusNFT::groups(GROUP_ID).unwrap().owner, BOB);
+
+		assert_ok!(BholdusNFT::move_to_group(&BOB, (CLASS_ID, TOKEN_ID), None));
+		assert_ok!(BholdusNFT::destroy_group(&BOB, GROUP_ID));
 	});
 }
 
@@ -89,6 +206,64 @@ fn mint_should_fail() {
 	});
 }
 
+#[test]
+fn max_supply_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_noop!(
+			BholdusNFT::set_max_supply(&BOB, CLASS_ID, Some(1)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(BholdusNFT::set_max_supply(&ALICE, CLASS_ID, Some(1)));
+		assert_eq!(BholdusNFT::class_max_supply(CLASS_ID), Some(1));
+		assert_ok!(BholdusNFT::mint(&BOB, CLASS_ID, vec![1], ()));
+		assert_noop!(
+			BholdusNFT::mint(&BOB, CLASS_ID, vec![1], ()),
+			Error::<Runtime>::MaxSupplyReached
+		);
+		assert_noop!(
+			BholdusNFT::set_max_supply(&ALICE, CLASS_ID, Some(0)),
+			Error::<Runtime>::InvalidMaxSupply
+		);
+	});
+}
+
+#[test]
+fn set_token_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::mint(&BOB, CLASS_ID, vec![1], ()));
+		assert_noop!(
+			BholdusNFT::set_token_metadata(&BOB, (CLASS_ID, TOKEN_ID), vec![2]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			BholdusNFT::set_token_metadata(&ALICE, (CLASS_ID, TOKEN_ID), vec![2, 3]),
+			Error::<Runtime>::MaxMetadataExceeded
+		);
+		assert_noop!(
+			BholdusNFT::set_token_metadata(&ALICE, (CLASS_ID, TOKEN_ID_NOT_EXIST), vec![2]),
+			Error::<Runtime>::TokenNotFound
+		);
+		assert_ok!(BholdusNFT::set_token_metadata(&ALICE, (CLASS_ID, TOKEN_ID), vec![2]));
+		assert_eq!(BholdusNFT::tokens(CLASS_ID, TOKEN_ID).unwrap().metadata.to_vec(), vec![2]);
+
+		assert_ok!(BholdusNFT::freeze_token_metadata(&ALICE, (CLASS_ID, TOKEN_ID)));
+		assert!(BholdusNFT::is_metadata_frozen((CLASS_ID, TOKEN_ID)));
+		assert_noop!(
+			BholdusNFT::set_token_metadata(&ALICE, (CLASS_ID, TOKEN_ID), vec![3]),
+			Error::<Runtime>::MetadataFrozen
+		);
+		assert_noop!(
+			BholdusNFT::set_token_data(&ALICE, (CLASS_ID, TOKEN_ID), ()),
+			Error::<Runtime>::MetadataFrozen
+		);
+
+		assert_ok!(BholdusNFT::burn(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert!(!BholdusNFT::is_metadata_frozen((CLASS_ID, TOKEN_ID)));
+	});
+}
+
 #[test]
 fn transfer_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -145,6 +320,65 @@ fn transfer_should_fail() {
 	});
 }
 
+#[test]
+fn approve_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::mint(&BOB, CLASS_ID, vec![1], ()));
+		assert_noop!(
+			BholdusNFT::approve(&ALICE, (CLASS_ID, TOKEN_ID), Some(ALICE)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			BholdusNFT::transfer_from(&ALICE, &BOB, &ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotApproved
+		);
+
+		assert_ok!(BholdusNFT::approve(&BOB, (CLASS_ID, TOKEN_ID), Some(ALICE)));
+		assert_eq!(BholdusNFT::token_approvals(CLASS_ID, TOKEN_ID), Some(ALICE));
+		assert_ok!(BholdusNFT::transfer_from(&ALICE, &BOB, &CHARLIE, (CLASS_ID, TOKEN_ID)));
+		assert!(BholdusNFT::is_owner(&CHARLIE, (CLASS_ID, TOKEN_ID)));
+		// The approval is cleared on transfer
+		assert_eq!(BholdusNFT::token_approvals(CLASS_ID, TOKEN_ID), None);
+		assert_noop!(
+			BholdusNFT::transfer_from(&ALICE, &CHARLIE, &ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotApproved
+		);
+
+		LockableNFT::<Runtime>::insert((&CHARLIE, CLASS_ID, TOKEN_ID), ());
+		assert_noop!(
+			BholdusNFT::approve(&CHARLIE, (CLASS_ID, TOKEN_ID), Some(ALICE)),
+			Error::<Runtime>::IsLocked
+		);
+	});
+}
+
+#[test]
+fn set_operator_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			BholdusNFT::set_operator(&BOB, CLASS_ID, &ALICE, true),
+			Error::<Runtime>::ClassNotFound
+		);
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_ok!(BholdusNFT::mint(&BOB, CLASS_ID, vec![1], ()));
+		assert_ok!(BholdusNFT::set_operator(&BOB, CLASS_ID, &ALICE, true));
+		assert!(BholdusNFT::is_approved(&ALICE, &BOB, (CLASS_ID, TOKEN_ID)));
+
+		LockableNFT::<Runtime>::insert((&BOB, CLASS_ID, TOKEN_ID), ());
+		assert_noop!(
+			BholdusNFT::transfer_from(&ALICE, &BOB, &CHARLIE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::IsLocked
+		);
+		BholdusNFT::unlock(&BOB, (CLASS_ID, TOKEN_ID));
+		assert_ok!(BholdusNFT::transfer_from(&ALICE, &BOB, &CHARLIE, (CLASS_ID, TOKEN_ID)));
+		assert!(BholdusNFT::is_owner(&CHARLIE, (CLASS_ID, TOKEN_ID)));
+
+		assert_ok!(BholdusNFT::set_operator(&BOB, CLASS_ID, &ALICE, false));
+		assert!(!BholdusNFT::is_approved(&ALICE, &BOB, (CLASS_ID, TOKEN_ID)));
+	});
+}
+
 #[test]
 fn burn_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -203,6 +437,27 @@ fn destroy_class_should_work() {
 	});
 }
 
+#[test]
+fn set_class_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let royalties = vec![RoyaltyRecipient { recipient: BOB, basis_points: 500 }];
+		assert_noop!(
+			BholdusNFT::set_class_royalty(&ALICE, CLASS_ID, royalties.clone()),
+			Error::<Runtime>::ClassNotFound
+		);
+		assert_ok!(BholdusNFT::create_class(&ALICE, ()));
+		assert_noop!(
+			BholdusNFT::set_class_royalty(&BOB, CLASS_ID, royalties.clone()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(BholdusNFT::set_class_royalty(&ALICE, CLASS_ID, royalties.clone()));
+		assert_eq!(BholdusNFT::class_royalties(CLASS_ID), royalties);
+
+		assert_ok!(BholdusNFT::destroy_class(&ALICE, CLASS_ID));
+		assert_eq!(ClassRoyalties::<Runtime>::contains_key(CLASS_ID), false);
+	});
+}
+
 #[test]
 fn destroy_class_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
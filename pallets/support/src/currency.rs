@@ -402,16 +402,32 @@ impl<AccountId, CurrencyId, Balance> OnDust<AccountId, CurrencyId, Balance> for
 	fn on_dust(_: &AccountId, _: CurrencyId, _: Balance) {}
 }
 
-pub trait TransferAll<AccountId> {
-	fn transfer_all(source: &AccountId, dest: &AccountId) -> DispatchResult;
+/// Move the transferable balances of an account in an explicit list of currencies to another
+/// account. Callers bound the list, the currencies an account holds are not enumerated.
+pub trait TransferAll<AccountId, CurrencyId> {
+	/// Transfer all free balances of `source` in `currency_ids` that are not reserved, locked or
+	/// frozen to `dest`.
+	///
+	/// If `keep_alive` is `true`, `source` is left with the minimum balance of each currency.
+	fn transfer_all(
+		source: &AccountId,
+		dest: &AccountId,
+		currency_ids: &[CurrencyId],
+		keep_alive: bool,
+	) -> DispatchResult;
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(5)]
-impl<AccountId> TransferAll<AccountId> for Tuple {
+impl<AccountId, CurrencyId> TransferAll<AccountId, CurrencyId> for Tuple {
 	#[transactional]
-	fn transfer_all(source: &AccountId, dest: &AccountId) -> DispatchResult {
+	fn transfer_all(
+		source: &AccountId,
+		dest: &AccountId,
+		currency_ids: &[CurrencyId],
+		keep_alive: bool,
+	) -> DispatchResult {
 		for_tuples!( #( {
-			Tuple::transfer_all(source, dest)?;
+			Tuple::transfer_all(source, dest, currency_ids, keep_alive)?;
 		} )* );
 		Ok(())
 	}
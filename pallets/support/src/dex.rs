@@ -0,0 +1,22 @@
+use sp_runtime::FixedU128;
+
+/// Abstraction over a time-weighted average price oracle.
+pub trait TwapProvider<CurrencyId, BlockNumber> {
+	/// The time-weighted average price of `currency_id_a` in `currency_id_b` over the last
+	/// `window` blocks. Returns `None` if there isn't enough price history.
+	fn get_twap(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: BlockNumber,
+	) -> Option<FixedU128>;
+}
+
+impl<CurrencyId, BlockNumber> TwapProvider<CurrencyId, BlockNumber> for () {
+	fn get_twap(
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_window: BlockNumber,
+	) -> Option<FixedU128> {
+		None
+	}
+}
@@ -5,10 +5,12 @@ pub use currency::{
 	BasicReservableCurrency, LockIdentifier, MultiCurrency, MultiCurrencyExtended,
 	MultiLockableCurrency, MultiReservableCurrency, OnDust,
 };
+pub use dex::TwapProvider;
 pub use get_by_key::GetByKey;
 pub use nft::NFT;
 
 pub mod arithmetic;
 pub mod currency;
+pub mod dex;
 pub mod get_by_key;
 pub mod nft;
@@ -32,4 +32,12 @@ pub trait NFT<AccountId> {
 		to: AccountId,
 		token: (Self::ClassId, Self::TokenId),
 	) -> DispatchResult;
+	/// Transfer the given token ID from one account to another on behalf of its owner. `operator`
+	/// must be approved for the token or be an operator of its class.
+	fn transfer_from(
+		operator: AccountId,
+		from: AccountId,
+		to: AccountId,
+		token: (Self::ClassId, Self::TokenId),
+	) -> DispatchResult;
 }
@@ -491,6 +491,241 @@ benchmarks_instance_pallet! {
 	// verify {
 	//     assert_last_event::<T, I>(Event::AssetVerified(Default::default()).into());
 	// }
+
+	approve_transfer {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let approve_amount = T::Balance::from(50u32);
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, delegate_lookup, approve_amount)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovedTransfer(asset_id, caller, delegate, approve_amount).into());
+	}
+
+	transfer_approved {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let approve_amount = T::Balance::from(50u32);
+		Assets::<T, I>::approve_transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			delegate_lookup,
+			approve_amount)?;
+
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+	}: _(SystemOrigin::Signed(delegate.clone()), asset_id, caller_lookup, dest_lookup, approve_amount)
+	verify {
+		assert_last_event::<T, I>(Event::TransferredApproved(asset_id, caller, delegate, dest, approve_amount).into());
+	}
+
+	cancel_approval {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let approve_amount = T::Balance::from(50u32);
+		Assets::<T, I>::approve_transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			delegate_lookup.clone(),
+			approve_amount)?;
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, delegate_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalCancelled(asset_id, caller, delegate).into());
+	}
+
+	force_cancel_approval {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let approve_amount = T::Balance::from(50u32);
+		Assets::<T, I>::approve_transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id,
+			delegate_lookup.clone(),
+			approve_amount)?;
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, delegate_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalCancelled(asset_id, caller, delegate).into());
+	}
+
+	transfer_ownership {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller), asset_id, target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::OwnerChanged(asset_id, target).into());
+	}
+
+	set_team {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let target0 = T::Lookup::unlookup(account("target", 0, SEED));
+		let target1 = T::Lookup::unlookup(account("target", 1, SEED));
+		let target2 = T::Lookup::unlookup(account("target", 2, SEED));
+	}: _(SystemOrigin::Signed(caller), asset_id, target0, target1, target2)
+	verify {
+		assert_last_event::<T, I>(Event::TeamChanged(
+			asset_id,
+			account("target", 0, SEED),
+			account("target", 1, SEED),
+			account("target", 2, SEED),
+		).into());
+	}
+
+	force_asset_status {
+		let name = vec![0u8; 1];
+		let symbol = vec![0u8; 2];
+		let decimals = 12;
+		let amount = T::Balance::from(100u32);
+
+		let asset_id = Assets::<T, I>::next_asset_id();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		Assets::<T, I>::create_and_mint(
+			SystemOrigin::Signed(caller.clone()).into(), // origin
+			caller_lookup.clone(),                       // admin
+			name,                                        // name
+			symbol,                                      // symbol
+			decimals,                                    // decimal
+			caller_lookup.clone(),                       // beneficiary
+			amount,                                      // supply
+			1u32.into());
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_asset_status {
+			id: asset_id,
+			owner: target_lookup.clone(),
+			issuer: caller_lookup.clone(),
+			admin: caller_lookup.clone(),
+			freezer: caller_lookup,
+			min_balance: 100u32.into(),
+			is_sufficient: true,
+			is_frozen: false,
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::AssetStatusChanged(asset_id).into());
+	}
 }
 
 impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Runtime);
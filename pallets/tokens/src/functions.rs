@@ -67,6 +67,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})
 	}
+
+	/// Move the asset and metadata deposits of asset `id` from its current owner to `new_owner`.
+	/// The identity deposit is excluded, `set_identity` records it without reserving it.
+	pub(super) fn move_deposits(
+		id: T::AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		let metadata_deposit = Metadata::<T, I>::get(id).deposit;
+		let deposit = details.deposit.saturating_add(metadata_deposit);
+		T::Currency::repatriate_reserved(&details.owner, new_owner, deposit, Status::Reserved)?;
+		Ok(())
+	}
+
 	// Public immutables
 
 	/// Return the extra "sid-car" data for `id`/`who`, or `None` if the account doesn't exist.
@@ -82,6 +96,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Asset::<T, I>::get(id).map(|x| x.supply).unwrap_or_else(Zero::zero)
 	}
 
+	/// The amount of free balance of `who` that is kept frozen by liquidity locks on asset `id`.
+	pub fn locked_balance(id: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Locks::<T, I>::get(id, who)
+			.iter()
+			.map(|lock| lock.amount)
+			.max()
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Replace the liquidity locks of `who` on asset `id` with `locks`.
+	///
+	/// Fails with `MaxLocksExceeded` if there are more than `MaxLocks` locks.
+	pub(super) fn update_locks(
+		id: T::AssetId,
+		who: &T::AccountId,
+		locks: &[BalanceLock<T::Balance>],
+	) -> DispatchResult {
+		if locks.is_empty() {
+			Locks::<T, I>::remove(id, who);
+		} else {
+			let bounded_locks: BoundedVec<BalanceLock<T::Balance>, T::MaxLocks> =
+				locks.to_vec().try_into().map_err(|_| Error::<T, I>::MaxLocksExceeded)?;
+			Locks::<T, I>::insert(id, who, bounded_locks);
+		}
+		Ok(())
+	}
+
 	pub(super) fn new_account(
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
@@ -174,6 +215,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen;
 		}
 		if let Some(rest) = account.free.checked_sub(&amount) {
+			if rest < Self::locked_balance(id, who) {
+				return Frozen;
+			}
 			if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
@@ -238,7 +282,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				account.free
 			}
 		};
-		Ok(amount.min(details.supply))
+		// Locked funds can never leave the account.
+		let unlocked = account.free.saturating_sub(Self::locked_balance(id, who));
+		Ok(amount.min(unlocked).min(details.supply))
 	}
 
 	/// Make preparatory checks for debiting some funds from an account. Flags indicate requirements
@@ -265,7 +311,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> Result<T::Balance, DispatchError> {
 		let asset_details = Asset::<T, I>::get(id).ok_or_else(|| Error::<T, I>::Unknown)?;
 		ensure!(asset_details.supply >= amount, Error::<T, I>::ExceedTotalSupply);
-		let actual = Self::reducible_balance(id, target, f.keep_alive)?.min(amount);
+		let reducible = Self::reducible_balance(id, target, f.keep_alive)?;
+		if !f.best_effort {
+			Self::ensure_can_withdraw(id, target, amount)?;
+		}
+		let actual = reducible.min(amount);
 		ensure!(f.best_effort || actual >= amount, Error::<T, I>::BalanceLow);
 
 		let conseq = Self::can_decrease(id, target, actual, f.keep_alive, action);
@@ -564,4 +614,117 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})
 	}
+
+	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
+	/// while reserving `T::ApprovalDeposit` from owner
+	///
+	/// If an approval already exists, the new amount is added to such existing approval
+	pub(super) fn do_approve_transfer(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(!d.is_frozen, Error::<T, I>::Frozen);
+		Approvals::<T, I>::try_mutate(
+			(id, &owner, &delegate),
+			|maybe_approved| -> DispatchResult {
+				let mut approved = match maybe_approved.take() {
+					// an approval already exists and is being updated
+					Some(a) => a,
+					// a new approval is created
+					None => {
+						d.approvals.saturating_inc();
+						Default::default()
+					},
+				};
+				let deposit_required = T::ApprovalDeposit::get();
+				if approved.deposit < deposit_required {
+					T::Currency::reserve(&owner, deposit_required - approved.deposit)?;
+					approved.deposit = deposit_required;
+				}
+				approved.amount = approved.amount.saturating_add(amount);
+				*maybe_approved = Some(approved);
+				Ok(())
+			},
+		)?;
+		Asset::<T, I>::insert(id, d);
+		Self::deposit_event(Event::ApprovedTransfer(id, owner.clone(), delegate.clone(), amount));
+
+		Ok(())
+	}
+
+	/// Removes the approval from `owner` to `delegate` and returns its deposit to `owner`.
+	pub(super) fn do_cancel_approval(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) -> DispatchResult {
+		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let approval =
+			Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
+		T::Currency::unreserve(&owner, approval.deposit);
+
+		d.approvals.saturating_dec();
+		Asset::<T, I>::insert(id, d);
+
+		Self::deposit_event(Event::ApprovalCancelled(id, owner.clone(), delegate.clone()));
+		Ok(())
+	}
+
+	/// Reduces the asset `id` balance of `owner` by some `amount` and increases the balance of
+	/// `dest` by (similar) amount, checking that 'delegate' has an existing approval from `owner`
+	/// to spend`amount`.
+	///
+	/// Will fail if `amount` is greater than the approval from `owner` to 'delegate'
+	/// Will unreserve the deposit from `owner` if the entire approved `amount` is spent by
+	/// 'delegate'
+	pub(super) fn do_transfer_approved(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		destination: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Approvals::<T, I>::try_mutate_exists(
+			(id, &owner, delegate),
+			|maybe_approved| -> DispatchResult {
+				let mut approved = maybe_approved.take().ok_or(Error::<T, I>::Unapproved)?;
+				let remaining =
+					approved.amount.checked_sub(&amount).ok_or(Error::<T, I>::Unapproved)?;
+
+				let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+				Self::do_transfer(
+					id,
+					&owner,
+					&destination,
+					amount,
+					ExistenceRequirement::AllowDeath,
+					f,
+				)?;
+
+				if remaining.is_zero() {
+					T::Currency::unreserve(&owner, approved.deposit);
+					Asset::<T, I>::mutate(id, |maybe_details| {
+						if let Some(details) = maybe_details {
+							details.approvals.saturating_dec();
+						}
+					});
+				} else {
+					approved.amount = remaining;
+					*maybe_approved = Some(approved);
+				}
+				Ok(())
+			},
+		)?;
+		Self::deposit_event(Event::TransferredApproved(
+			id,
+			owner.clone(),
+			delegate.clone(),
+			destination.clone(),
+			amount,
+		));
+		Ok(())
+	}
 }
@@ -49,6 +49,9 @@
 //! * `set_identity`: Set the associated identity of an asset; a small deposit is reserved if not
 //! already taken.
 //! * `clear_identity`: Remove an asset's associated identity; the deposit is returned.
+//! * `approve_transfer`: Create or increase an delegated transfer.
+//! * `cancel_approval`: Rescind a previous approval.
+//! * `transfer_approved`: Transfer third-party's assets to another account.
 //!
 //! ### Permissioned Functions
 //!
@@ -59,6 +62,11 @@
 //! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer.
 //! * `freeze`: Disallows further `transfer`'s from an accounts; called by the asset class's Admin.
 //! * `thaw`: Allows further `transfer`s from an account; called by the asset class's Owner.
+//! * `force_cancel_approval`: Rescind a previous approval; called by the asset class's Admin.
+//! * `transfer_ownership`: Changes an asset class's Owner; called by the asset class's Owner.
+//! * `set_team`: Changes an asset class's Admin, Freezer and Issuer; called by the asset class's
+//! Owner.
+//! * `force_asset_status`: Alter the status of an asset class; called by `ForceOrigin`.
 //!
 //! ### Public Functions
 //!
@@ -285,6 +293,10 @@ pub mod pallet {
 
 		/// The minimum amount required to keep an account.
 		type ExistentialDeposits: GetByKey<Self::AssetId, Self::Balance>;
+
+		/// The maximum number of locks that should exist on an account for a single asset.
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -357,20 +369,20 @@ pub mod pallet {
 	pub(super) type IdentityOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, Registration<BalanceOf<T, I>>, OptionQuery>;
 
-	// /// Any liquidity locks of a token type under an account.
-	// /// NOTE: Should only be accessed when setting, changing and freeing a lock.
-	// #[pallet::storage]
-	// #[pallet::getter(fn locks)]
-	// pub type Locks<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
-	//     _,
-	//     Blake2_128Concat,
-	//     T::AccountId,
-	//     Twox64Concat,
-	//     T::AssetId,
-	//     BoundedVec<BalanceLock<T::Balance>, T::MaxLocks>,
-	//     ValueQuery,
-	// >;
-	//
+	/// Any liquidity locks of a token type under an account.
+	/// First key is the asset ID, so that the locks can be cleared when the asset is destroyed.
+	/// NOTE: Should only be accessed when setting, changing and freeing a lock.
+	#[pallet::storage]
+	#[pallet::getter(fn locks)]
+	pub type Locks<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<BalanceLock<T::Balance>, T::MaxLocks>,
+		ValueQuery,
+	>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
@@ -420,6 +432,26 @@ pub mod pallet {
 		MetadataCleared(T::AssetId),
 		/// New identity has been set for an asset. \[asset_id, name\]
 		ProfileSet(T::AssetId, Vec<u8>, bool),
+		/// Some balance was locked. \[lock_id, asset_id, who, amount\]
+		LockSet(LockIdentifier, T::AssetId, T::AccountId, T::Balance),
+		/// Some locked balance was freed. \[lock_id, asset_id, who\]
+		LockRemoved(LockIdentifier, T::AssetId, T::AccountId),
+		/// (Additional) funds have been approved for transfer to a destination account.
+		/// \[asset_id, source, delegate, amount\]
+		ApprovedTransfer(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// An approval for account `delegate` was cancelled by `owner`.
+		/// \[asset_id, owner, delegate\]
+		ApprovalCancelled(T::AssetId, T::AccountId, T::AccountId),
+		/// An `amount` was transferred in its entirety from `owner` to `destination` by
+		/// the approved `delegate`.
+		/// \[asset_id, owner, delegate, destination, amount\]
+		TransferredApproved(T::AssetId, T::AccountId, T::AccountId, T::AccountId, T::Balance),
+		/// The owner changed. \[asset_id, owner\]
+		OwnerChanged(T::AssetId, T::AccountId),
+		/// The management team changed. \[asset_id, issuer, admin, freezer\]
+		TeamChanged(T::AssetId, T::AccountId, T::AccountId, T::AccountId),
+		/// An asset class was altered by a privileged origin. \[asset_id\]
+		AssetStatusChanged(T::AssetId),
 	}
 
 	#[pallet::error]
@@ -746,24 +778,16 @@ pub mod pallet {
 				for (who, v) in Account::<T, I>::drain_prefix(id) {
 					Self::dead_account(id, &who, &mut details, v.sufficient);
 				}
+				Locks::<T, I>::remove_prefix(id, None);
 				debug_assert_eq!(details.accounts, 0);
 				debug_assert_eq!(details.sufficients, 0);
 
 				let metadata = Metadata::<T, I>::take(&id);
-				//let identity = <IdentityOf<T, I>>::take(&id).ok_or(Error::<T, I>::Unknown)?;
-				let identity_deposit = match <IdentityOf<T, I>>::take(&id) {
-					Some(identity) => identity.total_deposit(),
-					None => Zero::zero(),
-				};
-				let deposit = details.deposit + metadata.deposit + identity_deposit;
-
-				T::Currency::unreserve(
-					&details.owner,
-					deposit, /* details
-					          *     .deposit
-					          *     .saturating_add(metadata.deposit)
-					          *     .saturating_add(identity.total_deposit()), */
-				);
+				// The identity deposit was never reserved, see `set_identity`
+				<IdentityOf<T, I>>::remove(&id);
+				let deposit = details.deposit + metadata.deposit;
+
+				T::Currency::unreserve(&details.owner, deposit);
 
 				for ((owner, _), approval) in Approvals::<T, I>::drain_prefix((&id,)) {
 					T::Currency::unreserve(&owner, approval.deposit);
@@ -964,6 +988,140 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		/// Change the Owner of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The asset and metadata deposits are moved to the new Owner.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+				if details.owner == owner {
+					return Ok(());
+				}
+
+				Self::move_deposits(id, details, &owner)?;
+				details.owner = owner.clone();
+
+				Self::deposit_event(Event::OwnerChanged(id, owner));
+				Ok(())
+			})
+		}
+
+		/// Change the Issuer, Admin and Freezer of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `issuer`: The new Issuer of this asset.
+		/// - `admin`: The new Admin of this asset.
+		/// - `freezer`: The new Freezer of this asset.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub fn set_team(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+
+				details.issuer = issuer.clone();
+				details.admin = admin.clone();
+				details.freezer = freezer.clone();
+
+				Self::deposit_event(Event::TeamChanged(id, issuer, admin, freezer));
+				Ok(())
+			})
+		}
+
+		/// Alter the attributes of a given asset.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// If the Owner changes, the asset and metadata deposits are moved to the new Owner.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		/// - `issuer`: The new Issuer of this asset.
+		/// - `admin`: The new Admin of this asset.
+		/// - `freezer`: The new Freezer of this asset.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `is_sufficient`: Whether a non-zero balance of this asset is deposit of sufficient
+		/// value to account for the state bloat associated with its balance storage. If set to
+		/// `true`, then non-zero balances may be stored without a `consumer` reference (and thus
+		/// an ED in the Balances pallet or whatever else is used to control user-account state
+		/// growth).
+		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
+		/// instructions.
+		///
+		/// Emits `AssetStatusChanged` with the identity of the asset.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_asset_status())]
+		pub fn force_asset_status(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] min_balance: T::Balance,
+			is_sufficient: bool,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+
+			Asset::<T, I>::try_mutate(id, |maybe_asset| {
+				let mut asset = maybe_asset.take().ok_or(Error::<T, I>::Unknown)?;
+				let owner = T::Lookup::lookup(owner)?;
+				if asset.owner != owner {
+					Self::move_deposits(id, &asset, &owner)?;
+					asset.owner = owner;
+				}
+				asset.issuer = T::Lookup::lookup(issuer)?;
+				asset.admin = T::Lookup::lookup(admin)?;
+				asset.freezer = T::Lookup::lookup(freezer)?;
+				asset.min_balance = min_balance;
+				asset.is_sufficient = is_sufficient;
+				asset.is_frozen = is_frozen;
+				*maybe_asset = Some(asset);
+
+				Self::deposit_event(Event::AssetStatusChanged(id));
+				Ok(())
+			})
+		}
+
 		/// Verify asset from a privileged origin.
 		///
 		/// The origin must conform to `ForceOrigin`.
@@ -1152,6 +1310,130 @@ pub mod pallet {
 			Self::deposit_event(Event::IdentitySet(id));
 			Ok(Some(T::WeightInfo::set_identity(extra_fields)).into())
 		}
+
+		/// Approve an amount of asset for transfer by a delegated third-party account.
+		///
+		/// Origin must be Signed.
+		///
+		/// Ensures that `ApprovalDeposit` worth of `Currency` is reserved from signing account
+		/// for the purpose of holding the approval. If some non-zero amount of assets is already
+		/// approved from signing account to `delegate`, then it is topped up to meet the right
+		/// value.
+		///
+		/// NOTE: The signing account does not need to own `amount` of assets at the point of
+		/// making this call.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`. If there is
+		/// already an approval in place, then this acts additively.
+		///
+		/// Emits `ApprovedTransfer` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_approve_transfer(id, &owner, &delegate, amount)
+		}
+
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be Signed and there must be an approval in place between signer and
+		/// `delegate`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_cancel_approval(id, &owner, &delegate)
+		}
+
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be either ForceOrigin or Signed origin with the signer being the Admin
+		/// account of the asset `id`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which approved the transfer.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_cancel_approval())]
+		pub fn force_cancel_approval(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			T::ForceOrigin::try_origin(origin)
+				.map(|_| ())
+				.or_else(|origin| -> DispatchResult {
+					let origin = ensure_signed(origin)?;
+					ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+					Ok(())
+				})?;
+
+			let owner = T::Lookup::lookup(owner)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_cancel_approval(id, &owner, &delegate)
+		}
+
+		/// Transfer some asset balance from a previously delegated account to some third-party
+		/// account.
+		///
+		/// Origin must be Signed and there must be an approval in place by the `owner` to the
+		/// signer.
+		///
+		/// If the entire amount approved for transfer is transferred, then any deposit previously
+		/// reserved by `approve_transfer` is unreserved.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which previously approved for a transfer of at least `amount`
+		/// and from which the asset balance will be withdrawn.
+		/// - `destination`: The account to which the asset balance of `amount` will be
+		/// transferred.
+		/// - `amount`: The amount of assets to transfer.
+		///
+		/// Emits `TransferredApproved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_approved())]
+		pub fn transfer_approved(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			destination: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let destination = T::Lookup::lookup(destination)?;
+			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount)
+		}
 	}
 }
 
@@ -1303,13 +1585,13 @@ impl<T: Config<I>, I: 'static> MultiCurrency<T::AccountId> for Pallet<T, I> {
 		if amount.is_zero() {
 			return Ok(());
 		}
-		let _new_balance = Self::free_balance(currency_id, who)
+		let new_balance = Self::free_balance(currency_id, who)
 			.checked_sub(&amount)
 			.ok_or(Error::<T, I>::BalanceLow)?;
-		// ensure!(
-		//     new_balance >= Self::account(who, currency_id).frozen(),
-		//     Error::<T>::LiquidityRestrictions
-		// );
+		ensure!(
+			new_balance >= Self::locked_balance(currency_id, who),
+			Error::<T, I>::LiquidityRestrictions
+		);
 		Ok(())
 	}
 
@@ -1465,79 +1747,74 @@ impl<T: Config<I>, I: 'static> MultiLockableCurrency<T::AccountId> for Pallet<T,
 	// Set a lock on the balance of `who` under `currency_id`.
 	// Is a no-op if lock amount is zero.
 	fn set_lock(
-		_lock_id: LockIdentifier,
-		_currency_id: Self::CurrencyId,
-		_who: &T::AccountId,
+		lock_id: LockIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(());
 		}
-		// let mut new_lock = Some(BalanceLock {
-		//     id: lock_id,
-		//     amount,
-		// });
-		// let mut locks = Self::locks(who, currency_id)
-		//     .into_iter()
-		//     .filter_map(|lock| {
-		//         if lock.id == lock_id {
-		//             new_lock.take()
-		//         } else {
-		//             Some(lock)
-		//         }
-		//     })
-		//     .collect::<Vec<_>>();
-		// if let Some(lock) = new_lock {
-		//     locks.push(lock)
-		// }
-		// Self::update_locks(currency_id, who, &locks[..])
+		let mut new_lock = Some(BalanceLock { id: lock_id, amount });
+		let mut locks = Self::locks(currency_id, who)
+			.into_iter()
+			.filter_map(|lock| if lock.id == lock_id { new_lock.take() } else { Some(lock) })
+			.collect::<Vec<_>>();
+		if let Some(lock) = new_lock {
+			locks.push(lock)
+		}
+		Self::update_locks(currency_id, who, &locks[..])?;
+		Self::deposit_event(Event::LockSet(lock_id, currency_id, who.clone(), amount));
 		Ok(())
 	}
 
 	// Extend a lock on the balance of `who` under `currency_id`.
 	// Is a no-op if lock amount is zero
 	fn extend_lock(
-		_lock_id: LockIdentifier,
-		_currency_id: Self::CurrencyId,
-		_who: &T::AccountId,
+		lock_id: LockIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(());
 		}
-		// let mut new_lock = Some(BalanceLock {
-		//     id: lock_id,
-		//     amount,
-		// });
-		// let mut locks = Self::locks(who, currency_id)
-		//     .into_iter()
-		//     .filter_map(|lock| {
-		//         if lock.id == lock_id {
-		//             new_lock.take().map(|nl| BalanceLock {
-		//                 id: lock.id,
-		//                 amount: lock.amount.max(nl.amount),
-		//             })
-		//         } else {
-		//             Some(lock)
-		//         }
-		//     })
-		//     .collect::<Vec<_>>();
-		// if let Some(lock) = new_lock {
-		//     locks.push(lock)
-		// }
-		// Self::update_locks(currency_id, who, &locks[..])
+		let mut new_lock = Some(BalanceLock { id: lock_id, amount });
+		let mut locks = Self::locks(currency_id, who)
+			.into_iter()
+			.filter_map(|lock| {
+				if lock.id == lock_id {
+					new_lock
+						.take()
+						.map(|nl| BalanceLock { id: lock.id, amount: lock.amount.max(nl.amount) })
+				} else {
+					Some(lock)
+				}
+			})
+			.collect::<Vec<_>>();
+		if let Some(lock) = new_lock {
+			locks.push(lock)
+		}
+		let amount =
+			locks.iter().find(|lock| lock.id == lock_id).map_or(amount, |lock| lock.amount);
+		Self::update_locks(currency_id, who, &locks[..])?;
+		Self::deposit_event(Event::LockSet(lock_id, currency_id, who.clone(), amount));
 		Ok(())
 	}
 
 	fn remove_lock(
-		_lock_id: LockIdentifier,
-		_currency_id: Self::CurrencyId,
-		_who: &T::AccountId,
+		lock_id: LockIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
 	) -> DispatchResult {
-		// let mut locks = Self::locks(who, currency_id);
-		// locks.retain(|lock| lock.id != lock_id);
-		// let locks_vec = locks.to_vec();
-		// Self::update_locks(currency_id, who, &locks_vec[..])
+		let mut locks = Self::locks(currency_id, who).into_inner();
+		let len = locks.len();
+		locks.retain(|lock| lock.id != lock_id);
+		if locks.len() == len {
+			return Ok(());
+		}
+		Self::update_locks(currency_id, who, &locks[..])?;
+		Self::deposit_event(Event::LockRemoved(lock_id, currency_id, who.clone()));
 		Ok(())
 	}
 }
@@ -1915,7 +2192,7 @@ where
 	GetCurrencyId: Get<T::AssetId>,
 {
 	type Moment = T::BlockNumber;
-	type MaxLocks = ();
+	type MaxLocks = T::MaxLocks;
 
 	fn set_lock(
 		id: LockIdentifier,
@@ -1940,19 +2217,39 @@ where
 	}
 }
 
-impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
+impl<T: Config<I>, I: 'static> TransferAll<T::AccountId, T::AssetId> for Pallet<T, I> {
 	#[transactional]
-	fn transfer_all(_source: &T::AccountId, _dest: &T::AccountId) -> DispatchResult {
-		// Account::<T>::iter_prefix(source).try_for_each(
-		//     |(currency_id, account_data)| -> DispatchResult {
-		//         <Self as MultiCurrency<T::AccountId>>::transfer(
-		//             currency_id,
-		//             source,
-		//             dest,
-		//             account_data.free,
-		//         )
-		//     },
-		// )
+	fn transfer_all(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		asset_ids: &[T::AssetId],
+		keep_alive: bool,
+	) -> DispatchResult {
+		if source == dest {
+			return Ok(());
+		}
+		for &id in asset_ids {
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			let account = Account::<T, I>::get(id, source);
+			// Frozen balances can not be moved, leave them with `source`.
+			if account.free.is_zero() || account.is_frozen || details.is_frozen {
+				continue;
+			}
+			// Reserved and locked funds stay with `source`, so it has to be kept alive.
+			let keep_alive = keep_alive ||
+				!account.reserved.is_zero() ||
+				!Self::locked_balance(id, source).is_zero();
+			let amount = Self::reducible_balance(id, source, keep_alive)?;
+			if amount.is_zero() {
+				continue;
+			}
+
+			let f = DebitFlags { keep_alive, best_effort: false };
+			let actual =
+				Self::decrease_balance(id, source, amount, f, Action::Withdraw, |_, _| Ok(()))?;
+			Self::increase_balance(id, dest, actual, |_| Ok(()))?;
+			Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), actual));
+		}
 		Ok(())
 	}
 }
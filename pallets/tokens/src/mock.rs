@@ -134,6 +134,7 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type Extra = ();
+	type MaxLocks = MaxLocks;
 }
 
 use std::{cell::RefCell, collections::HashMap};
@@ -638,3 +638,418 @@ fn lifecycle_should_work() {
 		assert_eq!(Account::<Runtime>::iter_prefix(1).count(), 0);
 	});
 }
+
+const ID_1: LockIdentifier = *b"1       ";
+const ID_2: LockIdentifier = *b"2       ";
+const ID_3: LockIdentifier = *b"3       ";
+
+#[test]
+fn set_lock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 10));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::LockSet(ID_1, 0, ALICE, 10)));
+		assert_eq!(BholdusTokens::locks(0, ALICE).len(), 1);
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 10);
+
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 50));
+		assert_eq!(BholdusTokens::locks(0, ALICE).len(), 1);
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 50);
+
+		assert_ok!(BholdusTokens::set_lock(ID_2, 0, &ALICE, 60));
+		assert_eq!(BholdusTokens::locks(0, ALICE).len(), 2);
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 60);
+	});
+}
+
+#[test]
+fn set_lock_should_not_exceed_max_locks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 10));
+		assert_ok!(BholdusTokens::set_lock(ID_2, 0, &ALICE, 10));
+		assert_noop!(
+			BholdusTokens::set_lock(ID_3, 0, &ALICE, 10),
+			Error::<Runtime>::MaxLocksExceeded
+		);
+	});
+}
+
+#[test]
+fn extend_lock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 10));
+		assert_ok!(BholdusTokens::extend_lock(ID_1, 0, &ALICE, 5));
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 10);
+
+		assert_ok!(BholdusTokens::extend_lock(ID_1, 0, &ALICE, 20));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::LockSet(ID_1, 0, ALICE, 20)));
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 20);
+
+		assert_ok!(BholdusTokens::extend_lock(ID_2, 0, &ALICE, 30));
+		assert_eq!(BholdusTokens::locks(0, ALICE).len(), 2);
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 30);
+	});
+}
+
+#[test]
+fn remove_lock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 10));
+		assert_ok!(BholdusTokens::set_lock(ID_2, 0, &ALICE, 20));
+		assert_ok!(BholdusTokens::remove_lock(ID_2, 0, &ALICE));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::LockRemoved(ID_2, 0, ALICE)));
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 10);
+
+		assert_ok!(BholdusTokens::remove_lock(ID_1, 0, &ALICE));
+		assert!(!Locks::<Runtime>::contains_key(0, ALICE));
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 0);
+	});
+}
+
+#[test]
+fn destroy_should_remove_locks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 10));
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &BOB, 10));
+
+		let w = Asset::<Runtime>::get(0).unwrap().destroy_witness();
+		assert_ok!(BholdusTokens::destroy(Origin::root(), 0, w));
+		assert_eq!(Locks::<Runtime>::iter_prefix(0).count(), 0);
+		assert_eq!(BholdusTokens::locked_balance(0, &ALICE), 0);
+	});
+}
+
+#[test]
+fn locked_balance_should_not_be_transferred() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 60));
+
+		assert_noop!(
+			BholdusTokens::transfer(Origin::signed(ALICE), 0, BOB, 50),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+		assert_noop!(
+			BholdusTokens::ensure_can_withdraw(0, &ALICE, 41),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+		assert_noop!(
+			BholdusTokens::reserve(0, &ALICE, 41),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+		assert_eq!(
+			<BholdusTokens as fungibles::Inspect<_>>::reducible_balance(0, &ALICE, false),
+			40
+		);
+
+		assert_ok!(BholdusTokens::transfer(Origin::signed(ALICE), 0, BOB, 40));
+		assert_eq!(BholdusTokens::free_balance(0, &ALICE), 60);
+
+		assert_ok!(BholdusTokens::remove_lock(ID_1, 0, &ALICE));
+		assert_ok!(BholdusTokens::transfer(Origin::signed(ALICE), 0, BOB, 50));
+		assert_eq!(BholdusTokens::free_balance(0, &BOB), 90);
+	});
+}
+
+#[test]
+fn transfer_all_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 1, ALICE, true, 1));
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 2, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 1, ALICE, 50));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 2, ALICE, 30));
+		assert_ok!(BholdusTokens::reserve(1, &ALICE, 10));
+		assert_ok!(BholdusTokens::freeze(Origin::signed(ALICE), 2, ALICE));
+
+		assert_ok!(<BholdusTokens as TransferAll<_, _>>::transfer_all(
+			&ALICE,
+			&BOB,
+			&[0, 1, 2],
+			false
+		));
+
+		// Everything is moved and the account is removed.
+		assert_eq!(BholdusTokens::free_balance(0, &BOB), 100);
+		assert!(!Account::<Runtime>::contains_key(0, ALICE));
+
+		// Reserved funds stay, so the minimum balance is kept alive.
+		assert_eq!(BholdusTokens::free_balance(1, &BOB), 39);
+		assert_eq!(BholdusTokens::free_balance(1, &ALICE), 1);
+		assert_eq!(BholdusTokens::reserved_balance(1, &ALICE), 10);
+
+		// Frozen accounts are not touched.
+		assert_eq!(BholdusTokens::free_balance(2, &BOB), 0);
+		assert_eq!(BholdusTokens::free_balance(2, &ALICE), 30);
+
+		assert_eq!(BholdusTokens::total_issuance(0), 100);
+		assert_eq!(BholdusTokens::total_issuance(1), 50);
+	});
+}
+
+#[test]
+fn transfer_all_should_respect_locks_and_keep_alive() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 1, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 1, ALICE, 50));
+		assert_ok!(BholdusTokens::set_lock(ID_1, 0, &ALICE, 60));
+
+		assert_ok!(<BholdusTokens as TransferAll<_, _>>::transfer_all(&ALICE, &BOB, &[0, 1], true));
+
+		assert_eq!(BholdusTokens::free_balance(0, &BOB), 40);
+		assert_eq!(BholdusTokens::free_balance(0, &ALICE), 60);
+		assert_eq!(BholdusTokens::free_balance(1, &BOB), 49);
+		assert_eq!(BholdusTokens::free_balance(1, &ALICE), 1);
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		Balances::make_free_balance_be(&ALICE, 1);
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::ApprovedTransfer(
+			0, ALICE, BOB, 50,
+		)));
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 1);
+		assert_eq!(Balances::reserved_balance(&ALICE), 1);
+
+		assert_ok!(BholdusTokens::transfer_approved(Origin::signed(BOB), 0, ALICE, EVE, 40));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::TransferredApproved(
+			0, ALICE, BOB, EVE, 40,
+		)));
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 1);
+		assert_eq!(Balances::reserved_balance(&ALICE), 1);
+
+		assert_ok!(BholdusTokens::transfer_approved(Origin::signed(BOB), 0, ALICE, EVE, 10));
+		assert_eq!(BholdusTokens::free_balance(0, &ALICE), 50);
+		assert_eq!(BholdusTokens::free_balance(0, &EVE), 50);
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert!(!Approvals::<Runtime>::contains_key((0, ALICE, BOB)));
+	});
+}
+
+#[test]
+fn approve_transfer_should_accumulate() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		Balances::make_free_balance_be(&ALICE, 1);
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(ALICE), 0, BOB, 20));
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(ALICE), 0, BOB, 30));
+		assert_eq!(Approvals::<Runtime>::get((0, ALICE, BOB)).unwrap().amount, 50);
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 1);
+		assert_eq!(Balances::reserved_balance(&ALICE), 1);
+	});
+}
+
+#[test]
+fn transfer_approved_should_not_exceed_approval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		Balances::make_free_balance_be(&ALICE, 1);
+		assert_noop!(
+			BholdusTokens::transfer_approved(Origin::signed(BOB), 0, ALICE, EVE, 10),
+			Error::<Runtime>::Unapproved
+		);
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+		assert_noop!(
+			BholdusTokens::transfer_approved(Origin::signed(BOB), 0, ALICE, EVE, 51),
+			Error::<Runtime>::Unapproved
+		);
+	});
+}
+
+#[test]
+fn cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100));
+		Balances::make_free_balance_be(&ALICE, 1);
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(ALICE), 0, BOB, 50));
+		assert_noop!(
+			BholdusTokens::cancel_approval(Origin::signed(ALICE), 0, EVE),
+			Error::<Runtime>::Unknown
+		);
+		assert_ok!(BholdusTokens::cancel_approval(Origin::signed(ALICE), 0, BOB));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::ApprovalCancelled(
+			0, ALICE, BOB,
+		)));
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert_noop!(
+			BholdusTokens::transfer_approved(Origin::signed(BOB), 0, ALICE, EVE, 10),
+			Error::<Runtime>::Unapproved
+		);
+	});
+}
+
+#[test]
+fn force_cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_ok!(BholdusTokens::mint(Origin::signed(ALICE), 0, BOB, 100));
+		Balances::make_free_balance_be(&BOB, 2);
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(BOB), 0, EVE, 50));
+		assert_ok!(BholdusTokens::approve_transfer(Origin::signed(BOB), 0, ALICE, 50));
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 2);
+
+		assert_noop!(
+			BholdusTokens::force_cancel_approval(Origin::signed(EVE), 0, BOB, EVE),
+			Error::<Runtime>::NoPermission
+		);
+		// Admin of the asset.
+		assert_ok!(BholdusTokens::force_cancel_approval(Origin::signed(ALICE), 0, BOB, EVE));
+		// ForceOrigin.
+		assert_ok!(BholdusTokens::force_cancel_approval(Origin::root(), 0, BOB, ALICE));
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&BOB), 0);
+	});
+}
+
+#[test]
+fn transfer_ownership_should_move_deposits() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&ALICE, 100);
+		Balances::make_free_balance_be(&BOB, 1);
+		assert_ok!(BholdusTokens::create_and_mint(
+			Origin::signed(ALICE),
+			ALICE,
+			b"BHO".to_vec(),
+			b"BHO".to_vec(),
+			12,
+			ALICE,
+			100,
+			1,
+		));
+		// Asset deposit plus metadata deposit.
+		assert_eq!(Balances::reserved_balance(&ALICE), 8);
+
+		assert_noop!(
+			BholdusTokens::transfer_ownership(Origin::signed(BOB), 0, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(BholdusTokens::transfer_ownership(Origin::signed(ALICE), 0, BOB));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::OwnerChanged(0, BOB)));
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert_eq!(Balances::reserved_balance(&BOB), 8);
+		assert_eq!(Asset::<Runtime>::get(0).unwrap().owner, BOB);
+
+		assert_noop!(
+			BholdusTokens::transfer_ownership(Origin::signed(ALICE), 0, ALICE),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(BholdusTokens::clear_metadata(Origin::signed(BOB), 0));
+		assert_eq!(Balances::reserved_balance(&BOB), 1);
+	});
+}
+
+#[test]
+fn destroy_after_transfer_ownership_should_keep_other_deposits() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&ALICE, 100);
+		Balances::make_free_balance_be(&BOB, 100);
+		assert_ok!(BholdusTokens::create(Origin::signed(ALICE), ALICE, 1));
+		assert_ok!(BholdusTokens::create(Origin::signed(BOB), BOB, 1));
+		assert_ok!(BholdusTokens::set_identity(Origin::signed(ALICE), 0, ten()));
+		assert_ok!(BholdusTokens::transfer_ownership(Origin::signed(ALICE), 0, BOB));
+		assert_eq!(Balances::reserved_balance(&BOB), 2);
+
+		let w = Asset::<Runtime>::get(0).unwrap().destroy_witness();
+		assert_ok!(BholdusTokens::destroy(Origin::signed(BOB), 0, w));
+		assert!(!IdentityOf::<Runtime>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(&BOB), 1);
+	});
+}
+
+#[test]
+fn set_team_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BholdusTokens::force_create(Origin::root(), 0, ALICE, true, 1));
+		assert_noop!(
+			BholdusTokens::set_team(Origin::signed(BOB), 0, BOB, BOB, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(BholdusTokens::set_team(Origin::signed(ALICE), 0, BOB, EVE, BOB));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::TeamChanged(0, BOB, EVE, BOB)));
+
+		assert_ok!(BholdusTokens::mint(Origin::signed(BOB), 0, ALICE, 100));
+		assert_noop!(
+			BholdusTokens::mint(Origin::signed(ALICE), 0, ALICE, 100),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(BholdusTokens::freeze(Origin::signed(BOB), 0, ALICE));
+		assert_ok!(BholdusTokens::thaw(Origin::signed(EVE), 0, ALICE));
+	});
+}
+
+#[test]
+fn force_asset_status_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&ALICE, 10);
+		assert_ok!(BholdusTokens::create(Origin::signed(ALICE), ALICE, 1));
+		assert_eq!(Balances::reserved_balance(&ALICE), 1);
+
+		assert_noop!(
+			BholdusTokens::force_asset_status(
+				Origin::signed(ALICE),
+				0,
+				BOB,
+				BOB,
+				BOB,
+				BOB,
+				1,
+				true,
+				true
+			),
+			BadOrigin
+		);
+		assert_ok!(BholdusTokens::force_asset_status(
+			Origin::root(),
+			0,
+			BOB,
+			EVE,
+			EVE,
+			EVE,
+			10,
+			true,
+			true,
+		));
+		System::assert_last_event(mock::Event::BholdusTokens(Event::AssetStatusChanged(0)));
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert_eq!(Balances::reserved_balance(&BOB), 1);
+
+		let details = Asset::<Runtime>::get(0).unwrap();
+		assert_eq!(details.owner, BOB);
+		assert_eq!(details.issuer, EVE);
+		assert_eq!(details.min_balance, 10);
+		assert!(details.is_sufficient);
+		assert!(details.is_frozen);
+
+		assert_noop!(
+			BholdusTokens::force_asset_status(Origin::root(), 1, BOB, BOB, BOB, BOB, 1, true, true),
+			Error::<Runtime>::Unknown
+		);
+	});
+}
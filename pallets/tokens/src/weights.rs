@@ -20,6 +20,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2021-12-15, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("phoenix-dev"), DB CACHE: 128
+//!
+//! NOTE: the weights of `approve_transfer`, `transfer_approved`, `cancel_approval`,
+//! `force_cancel_approval`, `transfer_ownership`, `set_team`, `force_asset_status` are placeholder
+//! estimates written by hand, not benchmark output. Regenerate this file with the command below.
 
 // Executed Command:
 
@@ -88,6 +92,20 @@ pub trait WeightInfo {
 	
 	fn verify_asset() -> Weight;
 	
+	fn approve_transfer() -> Weight;
+	
+	fn transfer_approved() -> Weight;
+	
+	fn cancel_approval() -> Weight;
+	
+	fn force_cancel_approval() -> Weight;
+	
+	fn transfer_ownership() -> Weight;
+	
+	fn set_team() -> Weight;
+	
+	fn force_asset_status() -> Weight;
+	
 }
 
 /// Weights for bholdus_tokens using the Substrate node and recommended hardware.
@@ -416,6 +434,144 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			
 	}
 	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn approve_transfer() -> Weight {
+		(2_598_442_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn transfer_approved() -> Weight {
+		(6_243_171_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn cancel_approval() -> Weight {
+		(2_517_362_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn force_cancel_approval() -> Weight {
+		(2_564_902_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Metadata (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	fn transfer_ownership() -> Weight {
+		(2_436_719_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	fn set_team() -> Weight {
+		(2_075_281_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Metadata (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	fn force_asset_status() -> Weight {
+		(2_381_006_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
 }
 
 // For backwards compatibility and tests
@@ -741,4 +897,142 @@ impl WeightInfo for () {
 			
 	}
 	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn approve_transfer() -> Weight {
+		(2_598_442_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Account (r:2 w:2)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn transfer_approved() -> Weight {
+		(6_243_171_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn cancel_approval() -> Weight {
+		(2_517_362_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Approvals (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	fn force_cancel_approval() -> Weight {
+		(2_564_902_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Metadata (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	fn transfer_ownership() -> Weight {
+		(2_436_719_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	fn set_team() -> Weight {
+		(2_075_281_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Tokens Asset (r:1 w:1)
+	
+	// Storage: Tokens Metadata (r:1 w:0)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	fn force_asset_status() -> Weight {
+		(2_381_006_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+	
 }
\ No newline at end of file
@@ -4,6 +4,8 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 pub use sp_runtime::OpaqueExtrinsic;
 use sp_runtime::{
 	generic,
@@ -84,3 +86,187 @@ pub type Rate = FixedU128;
 pub type NFTBalance = u128;
 /// An insert or duration in time.
 pub type EraIndex = u32;
+
+/// Exchange rate between two currencies.
+pub type ExchangeRate = FixedU128;
+
+/// A BHC20 token issued by `bholdus-tokens`.
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	PartialOrd,
+	Ord,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TokenInfo {
+	pub id: TokenId,
+}
+
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	PartialOrd,
+	Ord,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TokenSymbol {
+	/// A BHC20 token.
+	Token(TokenInfo),
+	/// The native currency (BHO).
+	Native,
+}
+
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	PartialOrd,
+	Ord,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CurrencyId {
+	Token(TokenSymbol),
+	/// Liquidity share of the DEX pool made of the two tokens.
+	DexShare(TokenSymbol, TokenSymbol),
+}
+
+impl CurrencyId {
+	pub fn is_token_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::Token(_))
+	}
+
+	pub fn is_native_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::Token(TokenSymbol::Native))
+	}
+
+	pub fn is_dex_share_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::DexShare(_, _))
+	}
+
+	/// Returns the BHC20 token id, if this currency is a BHC20 token.
+	pub fn token_id(&self) -> Option<TokenId> {
+		match self {
+			CurrencyId::Token(TokenSymbol::Token(TokenInfo { id })) => Some(*id),
+			_ => None,
+		}
+	}
+}
+
+impl From<TokenId> for CurrencyId {
+	fn from(id: TokenId) -> Self {
+		CurrencyId::Token(TokenSymbol::Token(TokenInfo { id }))
+	}
+}
+
+/// A pair of two different token currencies, sorted in ascending order.
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	PartialOrd,
+	Ord,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TradingPair(CurrencyId, CurrencyId);
+
+impl TradingPair {
+	pub fn from_currency_ids(currency_id_0: CurrencyId, currency_id_1: CurrencyId) -> Option<Self> {
+		if currency_id_0.is_token_currency_id() &&
+			currency_id_1.is_token_currency_id() &&
+			currency_id_0 != currency_id_1
+		{
+			if currency_id_0 > currency_id_1 {
+				Some(TradingPair(currency_id_1, currency_id_0))
+			} else {
+				Some(TradingPair(currency_id_0, currency_id_1))
+			}
+		} else {
+			None
+		}
+	}
+
+	pub fn first(&self) -> CurrencyId {
+		self.0
+	}
+
+	pub fn second(&self) -> CurrencyId {
+		self.1
+	}
+
+	pub fn dex_share_currency_id(&self) -> CurrencyId {
+		match (self.0, self.1) {
+			(CurrencyId::Token(symbol_0), CurrencyId::Token(symbol_1)) =>
+				CurrencyId::DexShare(symbol_0, symbol_1),
+			_ => unreachable!("trading pair is always made of token currencies; qed"),
+		}
+	}
+
+	/// Returns the trading pair backing a dex share currency.
+	pub fn from_dex_share_currency_id(currency_id: CurrencyId) -> Option<Self> {
+		match currency_id {
+			CurrencyId::DexShare(symbol_0, symbol_1) =>
+				Self::from_currency_ids(CurrencyId::Token(symbol_0), CurrencyId::Token(symbol_1)),
+			_ => None,
+		}
+	}
+}
+
+/// Parameters of a trading pair in `Provisioning` status.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProvisioningParameters<Balance> {
+	pub min_contribution: (Balance, Balance),
+	pub target_contribution: (Balance, Balance),
+	pub accumulated_contribution: (Balance, Balance),
+}
+
+/// Status of a trading pair.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TradingPairStatus<Balance> {
+	Disabled,
+	Provisioning(ProvisioningParameters<Balance>),
+	Enabled,
+}
+
+impl<Balance> Default for TradingPairStatus<Balance> {
+	fn default() -> Self {
+		Self::Disabled
+	}
+}
+
+/// Amount bounds of a swap.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SwapLimit<Balance> {
+	/// Swap an exact supply amount. \[supply_amount, min_target_amount\]
+	ExactSupply(Balance, Balance),
+	/// Swap to an exact target amount. \[max_supply_amount, target_amount\]
+	ExactTarget(Balance, Balance),
+}
@@ -82,10 +82,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 1_000_023,
+	spec_version: 1_000_028,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 6,
 	state_version: 1,
 };
 
@@ -182,6 +182,9 @@ construct_runtime!(
 		EVM: pallet_evm,
 		Ethereum: pallet_ethereum,
 		BaseFee: pallet_base_fee,
+
+		// Appended to keep the indices of the pallets above unchanged.
+		Dex: bholdus_dex,
 	}
 );
 
@@ -219,6 +222,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	bholdus_dex::ChargeFeeToken<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -434,6 +438,57 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bholdus_dex_rpc_runtime_api::DexApi<Block, AccountId> for Runtime {
+		fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+			Dex::get_liquidity(&currency_id_a, &currency_id_b)
+		}
+
+		fn get_target_amounts(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Result<Vec<Balance>, sp_runtime::DispatchError> {
+			Dex::get_target_amounts(&path, supply_amount)
+		}
+
+		fn get_supply_amounts(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Result<Vec<Balance>, sp_runtime::DispatchError> {
+			Dex::get_supply_amounts(&path, target_amount)
+		}
+
+		fn get_trading_pair_status(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> TradingPairStatus<Balance> {
+			Dex::get_trading_pair_status(&currency_id_a, &currency_id_b)
+		}
+
+		fn get_provision(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			who: AccountId,
+		) -> (Balance, Balance) {
+			Dex::get_provision(&currency_id_a, &currency_id_b, &who)
+		}
+
+		fn get_enabled_trading_pairs() -> Vec<TradingPair> {
+			Dex::get_enabled_trading_pairs()
+		}
+
+		fn get_trading_path_limit() -> u32 {
+			<Runtime as bholdus_dex::Config>::TradingPathLimit::get()
+		}
+
+		fn get_twap(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: BlockNumber,
+		) -> Option<ExchangeRate> {
+			Dex::get_twap(currency_id_a, currency_id_b, window)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -830,6 +885,8 @@ impl_runtime_apis! {
 
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 			list_benchmark!(list, extra, bholdus_tokens, TokensBench::<Runtime>);
+			list_benchmark!(list, extra, bholdus_currencies, Currencies);
+			list_benchmark!(list, extra, bholdus_dex, Dex);
 			list_benchmark!(list, extra, integration_tokens, IntegrationTokens::<Runtime>);
 			list_benchmark!(list, extra, bholdus_nft, NFTBench::<Runtime>);
 			list_benchmark!(list, extra, bholdus_bridge_native_transfer, BridgeNativeTransfer);
@@ -877,6 +934,8 @@ impl_runtime_apis! {
 
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, bholdus_tokens, TokensBench::<Runtime>);
+			add_benchmark!(params, batches, bholdus_currencies, Currencies);
+			add_benchmark!(params, batches, bholdus_dex, Dex);
 			add_benchmark!(params, batches, integration_tokens, IntegrationTokens::<Runtime>);
 			add_benchmark!(params, batches, bholdus_nft, NFTBench::<Runtime>);
 			add_benchmark!(params, batches, bholdus_bridge_native_transfer, BridgeNativeTransfer);
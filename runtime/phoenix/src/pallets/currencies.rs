@@ -2,8 +2,13 @@
 
 use crate::*;
 
+parameter_types! {
+	pub const MaxTransferAllCurrencies: u32 = 32;
+}
+
 impl bholdus_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
+	type MaxTransferAllCurrencies = MaxTransferAllCurrencies;
 	type WeightInfo = ();
 }
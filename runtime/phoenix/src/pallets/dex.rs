@@ -0,0 +1,23 @@
+#![allow(unused_imports)]
+use frame_support::{parameter_types, PalletId};
+
+use crate::*;
+
+parameter_types! {
+	pub const DexPalletId: PalletId = PalletId(*b"bhod/dex");
+	pub const ExchangeFee: (u32, u32) = (3, 1000); // 0.3%
+	pub const TradingPathLimit: u32 = 4;
+	pub const MaxTwapWindow: BlockNumber = 1 * HOURS;
+}
+
+impl bholdus_dex::Config for Runtime {
+	type Event = Event;
+	type ListingOrigin = EnsureRoot<AccountId>;
+	type PalletId = DexPalletId;
+	type ExchangeFee = ExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type Currency = Currencies;
+	type NativeCurrency = Balances;
+	type MaxTwapWindow = MaxTwapWindow;
+	type WeightInfo = bholdus_dex::weights::SubstrateWeight<Runtime>;
+}
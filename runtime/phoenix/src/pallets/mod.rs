@@ -103,6 +103,9 @@ pub use tokens::*;
 pub mod currencies;
 pub use currencies::*;
 
+pub mod dex;
+pub use dex::*;
+
 pub mod memo;
 pub use memo::*;
 
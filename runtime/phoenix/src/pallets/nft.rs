@@ -6,6 +6,9 @@ use crate::*;
 parameter_types! {
 	pub const MaxAttributesBytes: u32 = 2048;
 	pub const MaxQuantity: u32 = 100;
+	pub const MaxRoyaltyRecipients: u32 = 10;
+	pub const MaxRoyalty: u32 = 5_000;
+	pub const MaxClassMinters: u32 = 100;
 	pub const NftPalletId: PalletId = PalletId(*b"bho/bNFT");
 }
 
@@ -14,6 +17,10 @@ impl bholdus_nft::Config for Runtime {
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
 	type MaxQuantity = MaxQuantity;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxClassMinters = MaxClassMinters;
+	type Currency = Balances;
 	type WeightInfo = bholdus_nft::weights::SubstrateWeight<Runtime>;
 }
 
@@ -4,6 +4,9 @@ use crate::*;
 
 parameter_types! {
 	pub const RoyaltyValue: (u32, u32) = (10_000, 10_000);
+	pub const MinimumBidIncrement: (u32, u32) = (500, 10_000);
+	// One storage item; key size is 20+24+48 bytes; value is size 16+9+8+16 bytes = 49 bytes.
+	pub const OfferDeposit: Balance = deposit(1, 141);
 }
 
 impl bholdus_support_nft_marketplace::Config for Runtime {
@@ -14,4 +17,7 @@ impl bholdus_support_nft_marketplace::Config for Runtime {
 
 impl bholdus_nft_marketplace::Config for Runtime {
 	type Event = Event;
+	type MinimumBidIncrement = MinimumBidIncrement;
+	type OfferDeposit = OfferDeposit;
+	type WeightInfo = bholdus_nft_marketplace::weights::SubstrateWeight<Runtime>;
 }
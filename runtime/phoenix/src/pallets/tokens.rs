@@ -11,6 +11,7 @@ parameter_types! {
 	pub const MaxDecimals: u8 = 18;
 	pub const MetadataDepositBase: Balance = 10;
 	pub const MetadataDepositPerByte: Balance = 1;
+	pub const MaxTokenLocks: u32 = 50;
 }
 
 parameter_type_with_key! {
@@ -41,4 +42,5 @@ impl bholdus_tokens::Config for Runtime {
 	type Extra = ();
 	type WeightInfo = bholdus_tokens::weights::SubstrateWeight<Runtime>;
 	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = MaxTokenLocks;
 }
@@ -46,8 +46,10 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type Event = Event;
-	type OnChargeTransaction =
-		bholdus_memo::MemoOnChargeTransaction<CurrencyAdapter<Balances, DealWithFees>, Balances>;
+	type OnChargeTransaction = bholdus_memo::MemoOnChargeTransaction<
+		bholdus_dex::DexOnChargeTransaction<CurrencyAdapter<Balances, DealWithFees>>,
+		Balances,
+	>;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = fee::WeightToFee;
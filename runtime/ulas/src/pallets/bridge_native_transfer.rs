@@ -3,10 +3,24 @@ use frame_support::parameter_types;
 
 use crate::*;
 
+parameter_types! {
+	pub const BridgeBlocksPerDay: BlockNumber = DAYS;
+	pub const BridgeRefundServiceFee: bool = true;
+	pub const BridgeTransferWindow: u32 = 100;
+	pub const BridgeMaxRelayers: u32 = 20;
+}
+
 impl bholdus_bridge_native_transfer::Config for Runtime {
 	type Event = Event;
 	type AdminOrigin = EnsureRoot<Self::AccountId>;
 	type Currency = Balances;
+	// Ulas has no Currencies pallet, BHC20 tokens are moved by Tokens directly. Phoenix goes
+	// through Currencies like its Dex and marketplace, both settle on the same Tokens balances.
+	type MultiCurrency = Tokens;
 	type MinimumDeposit = ExistentialDeposit;
+	type BlocksPerDay = BridgeBlocksPerDay;
+	type RefundServiceFee = BridgeRefundServiceFee;
+	type TransferWindow = BridgeTransferWindow;
+	type MaxRelayers = BridgeMaxRelayers;
 	type WeightInfo = bholdus_bridge_native_transfer::weights::SubstrateWeight<Runtime>;
 }